@@ -13,8 +13,8 @@ import erc7730FFI
 
 fileprivate extension RustBuffer {
     // Allocate a new buffer, copying the contents of a `UInt8` array.
-    init(byteArray: [UInt8]) {
-        let rbuf = byteArray.withUnsafeBufferPointer { ptr in
+    init(bytes: [UInt8]) {
+        let rbuf = bytes.withUnsafeBufferPointer { ptr in
             RustBuffer.from(ptr)
         }
         self.init(capacity: rbuf.capacity, len: rbuf.len, data: rbuf.data)
@@ -209,7 +209,7 @@ extension FfiConverterRustBuffer {
     public static func lower(_ value: SwiftType) -> RustBuffer {
           var writer = createWriter()
           write(value, into: &writer)
-          return RustBuffer(byteArray: writer)
+          return RustBuffer(bytes: writer)
     }
 }
 // An error type for FFI errors. These errors occur at the UniFFI level, not
@@ -414,7 +414,13 @@ fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
 
 
 // Public interface members begin here.
-
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
@@ -432,6 +438,22 @@ fileprivate struct FfiConverterUInt8: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -448,6 +470,30 @@ fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterBool : FfiConverter {
+    typealias FfiType = Int8
+    typealias SwiftType = Bool
+
+    public static func lift(_ value: Int8) throws -> Bool {
+        return value != 0
+    }
+
+    public static func lower(_ value: Bool) -> Int8 {
+        return value ? 1 : 0
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Bool {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Bool, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -490,44 +536,213 @@ fileprivate struct FfiConverterString: FfiConverter {
 }
 
 
+
+
 /**
- * A single label+value pair for display.
+ * Address name provider implemented by the host app: contacts, a name service client.
  */
-public struct DisplayItem: Equatable, Hashable {
-    public var label: String
-    public var value: String
+public protocol AddressNameCallback: AnyObject, Sendable {
+    
+    /**
+     * Display name of `address` (lowercase `0x`-prefixed) on `chain_id`, if any.
+     */
+    func addressName(chainId: UInt64, address: String) async throws  -> AddressNameResult?
+    
+}
+/**
+ * Address name provider implemented by the host app: contacts, a name service client.
+ */
+open class AddressNameCallbackImpl: AddressNameCallback, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(label: String, value: String) {
-        self.label = label
-        self.value = value
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_erc7730_fn_clone_addressnamecallback(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_erc7730_fn_free_addressnamecallback(handle, $0) }
     }
 
     
 
     
+    /**
+     * Display name of `address` (lowercase `0x`-prefixed) on `chain_id`, if any.
+     */
+open func addressName(chainId: UInt64, address: String)async throws  -> AddressNameResult?  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_erc7730_fn_method_addressnamecallback_address_name(
+                    self.uniffiCloneHandle(),
+                    FfiConverterUInt64.lower(chainId),FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_erc7730_rust_future_poll_rust_buffer,
+            completeFunc: ffi_erc7730_rust_future_complete_rust_buffer,
+            freeFunc: ffi_erc7730_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterOptionTypeAddressNameResult.lift,
+            errorHandler: FfiConverterTypeCallbackError_lift
+        )
 }
+    
 
-#if compiler(>=6)
-extension DisplayItem: Sendable {}
-#endif
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceAddressNameCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceAddressNameCallback] = [UniffiVTableCallbackInterfaceAddressNameCallback(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeAddressNameCallback.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface AddressNameCallback: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeAddressNameCallback.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface AddressNameCallback: handle missing in uniffiClone")
+            }
+        },
+        addressName: { (
+            uniffiHandle: UInt64,
+            chainId: UInt64,
+            address: RustBuffer,
+            uniffiFutureCallback: @escaping UniffiForeignFutureCompleteRustBuffer,
+            uniffiCallbackData: UInt64,
+            uniffiOutDroppedCallback: UnsafeMutablePointer<UniffiForeignFutureDroppedCallbackStruct>
+        ) in
+            let makeCall = {
+                () async throws -> AddressNameResult? in
+                guard let uniffiObj = try? FfiConverterTypeAddressNameCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try await uniffiObj.addressName(
+                     chainId: try FfiConverterUInt64.lift(chainId),
+                     address: try FfiConverterString.lift(address)
+                )
+            }
+
+            let uniffiHandleSuccess = { (returnValue: AddressNameResult?) in
+                uniffiFutureCallback(
+                    uniffiCallbackData,
+                    UniffiForeignFutureResultRustBuffer(
+                        returnValue: FfiConverterOptionTypeAddressNameResult.lower(returnValue),
+                        callStatus: RustCallStatus()
+                    )
+                )
+            }
+            let uniffiHandleError = { (statusCode, errorBuf) in
+                uniffiFutureCallback(
+                    uniffiCallbackData,
+                    UniffiForeignFutureResultRustBuffer(
+                        returnValue: RustBuffer.empty(),
+                        callStatus: RustCallStatus(code: statusCode, errorBuf: errorBuf)
+                    )
+                )
+            }
+            uniffiTraitInterfaceCallAsyncWithError(
+                makeCall: makeCall,
+                handleSuccess: uniffiHandleSuccess,
+                handleError: uniffiHandleError,
+                lowerError: FfiConverterTypeCallbackError_lower,
+                droppedCallback: uniffiOutDroppedCallback
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitAddressNameCallback() {
+    uniffi_erc7730_fn_init_callback_vtable_addressnamecallback(UniffiCallbackInterfaceAddressNameCallback.vtable)
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDisplayItem: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DisplayItem {
-        return
-            try DisplayItem(
-                label: FfiConverterString.read(from: &buf), 
-                value: FfiConverterString.read(from: &buf)
-        )
+public struct FfiConverterTypeAddressNameCallback: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<AddressNameCallback>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = AddressNameCallback
+
+    public static func lift(_ handle: UInt64) throws -> AddressNameCallback {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return AddressNameCallbackImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
     }
 
-    public static func write(_ value: DisplayItem, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.label, into: &buf)
-        FfiConverterString.write(value.value, into: &buf)
+    public static func lower(_ value: AddressNameCallback) -> UInt64 {
+         if let rustImpl = value as? AddressNameCallbackImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AddressNameCallback {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: AddressNameCallback, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
 }
 
@@ -535,130 +750,172 @@ public struct FfiConverterTypeDisplayItem: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDisplayItem_lift(_ buf: RustBuffer) throws -> DisplayItem {
-    return try FfiConverterTypeDisplayItem.lift(buf)
+public func FfiConverterTypeAddressNameCallback_lift(_ handle: UInt64) throws -> AddressNameCallback {
+    return try FfiConverterTypeAddressNameCallback.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDisplayItem_lower(_ value: DisplayItem) -> RustBuffer {
-    return FfiConverterTypeDisplayItem.lower(value)
+public func FfiConverterTypeAddressNameCallback_lower(_ value: AddressNameCallback) -> UInt64 {
+    return FfiConverterTypeAddressNameCallback.lower(value)
 }
 
 
-/**
- * Output model for clear signing display.
- */
-public struct DisplayModel: Equatable, Hashable {
-    public var intent: String
-    public var interpolatedIntent: String?
-    public var entries: [DisplayEntry]
-    public var warnings: [String]
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(intent: String, interpolatedIntent: String?, entries: [DisplayEntry], warnings: [String]) {
-        self.intent = intent
-        self.interpolatedIntent = interpolatedIntent
-        self.entries = entries
-        self.warnings = warnings
-    }
 
-    
 
+
+/**
+ * Configured clear-signing engine.
+ */
+public protocol ClearSignerProtocol: AnyObject, Sendable {
+    
+    /**
+     * Resolve a descriptor for `to` and format contract calldata.
+     */
+    func formatCalldata(chainId: UInt64, to: String, calldataHex: String, valueHex: String?, fromAddress: String?) async throws  -> DisplayModel
+    
+    /**
+     * Resolve a descriptor for the verifying contract and format EIP-712 typed data.
+     */
+    func formatTypedData(typedDataJson: String) async throws  -> DisplayModel
     
 }
+/**
+ * Configured clear-signing engine.
+ */
+open class ClearSigner: ClearSignerProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
-#if compiler(>=6)
-extension DisplayModel: Sendable {}
-#endif
-
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDisplayModel: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DisplayModel {
-        return
-            try DisplayModel(
-                intent: FfiConverterString.read(from: &buf), 
-                interpolatedIntent: FfiConverterOptionString.read(from: &buf), 
-                entries: FfiConverterSequenceTypeDisplayEntry.read(from: &buf), 
-                warnings: FfiConverterSequenceString.read(from: &buf)
-        )
+    public struct NoHandle {
+        public init() {}
     }
 
-    public static func write(_ value: DisplayModel, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.intent, into: &buf)
-        FfiConverterOptionString.write(value.interpolatedIntent, into: &buf)
-        FfiConverterSequenceTypeDisplayEntry.write(value.entries, into: &buf)
-        FfiConverterSequenceString.write(value.warnings, into: &buf)
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDisplayModel_lift(_ buf: RustBuffer) throws -> DisplayModel {
-    return try FfiConverterTypeDisplayModel.lift(buf)
-}
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDisplayModel_lower(_ value: DisplayModel) -> RustBuffer {
-    return FfiConverterTypeDisplayModel.lower(value)
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_erc7730_fn_clone_clearsigner(self.handle, $0) }
+    }
+    /**
+     * Build a signer, parsing every configured descriptor, token and name up front.
+     */
+public convenience init(config: ClearSignerConfig)throws  {
+    let handle =
+        try rustCallWithError(FfiConverterTypeFfiError_lift) {
+    uniffi_erc7730_fn_constructor_clearsigner_new(
+        FfiConverterTypeClearSignerConfig_lower(config),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
 }
 
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
 
-public struct TokenMetaInput: Equatable, Hashable {
-    public var chainId: UInt64
-    public var address: String
-    public var symbol: String
-    public var decimals: UInt8
-    public var name: String
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(chainId: UInt64, address: String, symbol: String, decimals: UInt8, name: String) {
-        self.chainId = chainId
-        self.address = address
-        self.symbol = symbol
-        self.decimals = decimals
-        self.name = name
+        try! rustCall { uniffi_erc7730_fn_free_clearsigner(handle, $0) }
     }
 
     
 
     
+    /**
+     * Resolve a descriptor for `to` and format contract calldata.
+     */
+open func formatCalldata(chainId: UInt64, to: String, calldataHex: String, valueHex: String?, fromAddress: String?)async throws  -> DisplayModel  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_erc7730_fn_method_clearsigner_format_calldata(
+                    self.uniffiCloneHandle(),
+                    FfiConverterUInt64.lower(chainId),FfiConverterString.lower(to),FfiConverterString.lower(calldataHex),FfiConverterOptionString.lower(valueHex),FfiConverterOptionString.lower(fromAddress)
+                )
+            },
+            pollFunc: ffi_erc7730_rust_future_poll_rust_buffer,
+            completeFunc: ffi_erc7730_rust_future_complete_rust_buffer,
+            freeFunc: ffi_erc7730_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeDisplayModel_lift,
+            errorHandler: FfiConverterTypeFfiError_lift
+        )
+}
+    
+    /**
+     * Resolve a descriptor for the verifying contract and format EIP-712 typed data.
+     */
+open func formatTypedData(typedDataJson: String)async throws  -> DisplayModel  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_erc7730_fn_method_clearsigner_format_typed_data(
+                    self.uniffiCloneHandle(),
+                    FfiConverterString.lower(typedDataJson)
+                )
+            },
+            pollFunc: ffi_erc7730_rust_future_poll_rust_buffer,
+            completeFunc: ffi_erc7730_rust_future_complete_rust_buffer,
+            freeFunc: ffi_erc7730_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeDisplayModel_lift,
+            errorHandler: FfiConverterTypeFfiError_lift
+        )
+}
+    
+
+    
 }
 
-#if compiler(>=6)
-extension TokenMetaInput: Sendable {}
-#endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTokenMetaInput: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TokenMetaInput {
-        return
-            try TokenMetaInput(
-                chainId: FfiConverterUInt64.read(from: &buf), 
-                address: FfiConverterString.read(from: &buf), 
-                symbol: FfiConverterString.read(from: &buf), 
-                decimals: FfiConverterUInt8.read(from: &buf), 
-                name: FfiConverterString.read(from: &buf)
-        )
+public struct FfiConverterTypeClearSigner: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = ClearSigner
+
+    public static func lift(_ handle: UInt64) throws -> ClearSigner {
+        return ClearSigner(unsafeFromHandle: handle)
     }
 
-    public static func write(_ value: TokenMetaInput, into buf: inout [UInt8]) {
-        FfiConverterUInt64.write(value.chainId, into: &buf)
-        FfiConverterString.write(value.address, into: &buf)
-        FfiConverterString.write(value.symbol, into: &buf)
-        FfiConverterUInt8.write(value.decimals, into: &buf)
-        FfiConverterString.write(value.name, into: &buf)
+    public static func lower(_ value: ClearSigner) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ClearSigner {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: ClearSigner, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
 }
 
@@ -666,29 +923,2130 @@ public struct FfiConverterTypeTokenMetaInput: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTokenMetaInput_lift(_ buf: RustBuffer) throws -> TokenMetaInput {
-    return try FfiConverterTypeTokenMetaInput.lift(buf)
+public func FfiConverterTypeClearSigner_lift(_ handle: UInt64) throws -> ClearSigner {
+    return try FfiConverterTypeClearSigner.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTokenMetaInput_lower(_ value: TokenMetaInput) -> RustBuffer {
-    return FfiConverterTypeTokenMetaInput.lower(value)
+public func FfiConverterTypeClearSigner_lower(_ value: ClearSigner) -> UInt64 {
+    return FfiConverterTypeClearSigner.lower(value)
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+
+
+
+
+/**
+ * Descriptor provider implemented by the host app, e.g. over its own descriptor cache.
+ */
+public protocol DescriptorSourceCallback: AnyObject, Sendable {
+    
+    /**
+     * Descriptor JSON for contract calls to `address` on `chain_id`, if known.
+     */
+    func calldataDescriptor(chainId: UInt64, address: String) async throws  -> String?
+    
+    /**
+     * Descriptor JSON for EIP-712 messages verified by `address` on `chain_id`, if known.
+     */
+    func typedDescriptor(chainId: UInt64, address: String) async throws  -> String?
+    
+}
+/**
+ * Descriptor provider implemented by the host app, e.g. over its own descriptor cache.
+ */
+open class DescriptorSourceCallbackImpl: DescriptorSourceCallback, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_erc7730_fn_clone_descriptorsourcecallback(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_erc7730_fn_free_descriptorsourcecallback(handle, $0) }
+    }
+
+    
+
+    
+    /**
+     * Descriptor JSON for contract calls to `address` on `chain_id`, if known.
+     */
+open func calldataDescriptor(chainId: UInt64, address: String)async throws  -> String?  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_erc7730_fn_method_descriptorsourcecallback_calldata_descriptor(
+                    self.uniffiCloneHandle(),
+                    FfiConverterUInt64.lower(chainId),FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_erc7730_rust_future_poll_rust_buffer,
+            completeFunc: ffi_erc7730_rust_future_complete_rust_buffer,
+            freeFunc: ffi_erc7730_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterOptionString.lift,
+            errorHandler: FfiConverterTypeCallbackError_lift
+        )
+}
+    
+    /**
+     * Descriptor JSON for EIP-712 messages verified by `address` on `chain_id`, if known.
+     */
+open func typedDescriptor(chainId: UInt64, address: String)async throws  -> String?  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_erc7730_fn_method_descriptorsourcecallback_typed_descriptor(
+                    self.uniffiCloneHandle(),
+                    FfiConverterUInt64.lower(chainId),FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_erc7730_rust_future_poll_rust_buffer,
+            completeFunc: ffi_erc7730_rust_future_complete_rust_buffer,
+            freeFunc: ffi_erc7730_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterOptionString.lift,
+            errorHandler: FfiConverterTypeCallbackError_lift
+        )
+}
+    
+
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceDescriptorSourceCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceDescriptorSourceCallback] = [UniffiVTableCallbackInterfaceDescriptorSourceCallback(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeDescriptorSourceCallback.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface DescriptorSourceCallback: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeDescriptorSourceCallback.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface DescriptorSourceCallback: handle missing in uniffiClone")
+            }
+        },
+        calldataDescriptor: { (
+            uniffiHandle: UInt64,
+            chainId: UInt64,
+            address: RustBuffer,
+            uniffiFutureCallback: @escaping UniffiForeignFutureCompleteRustBuffer,
+            uniffiCallbackData: UInt64,
+            uniffiOutDroppedCallback: UnsafeMutablePointer<UniffiForeignFutureDroppedCallbackStruct>
+        ) in
+            let makeCall = {
+                () async throws -> String? in
+                guard let uniffiObj = try? FfiConverterTypeDescriptorSourceCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try await uniffiObj.calldataDescriptor(
+                     chainId: try FfiConverterUInt64.lift(chainId),
+                     address: try FfiConverterString.lift(address)
+                )
+            }
+
+            let uniffiHandleSuccess = { (returnValue: String?) in
+                uniffiFutureCallback(
+                    uniffiCallbackData,
+                    UniffiForeignFutureResultRustBuffer(
+                        returnValue: FfiConverterOptionString.lower(returnValue),
+                        callStatus: RustCallStatus()
+                    )
+                )
+            }
+            let uniffiHandleError = { (statusCode, errorBuf) in
+                uniffiFutureCallback(
+                    uniffiCallbackData,
+                    UniffiForeignFutureResultRustBuffer(
+                        returnValue: RustBuffer.empty(),
+                        callStatus: RustCallStatus(code: statusCode, errorBuf: errorBuf)
+                    )
+                )
+            }
+            uniffiTraitInterfaceCallAsyncWithError(
+                makeCall: makeCall,
+                handleSuccess: uniffiHandleSuccess,
+                handleError: uniffiHandleError,
+                lowerError: FfiConverterTypeCallbackError_lower,
+                droppedCallback: uniffiOutDroppedCallback
+            )
+        },
+        typedDescriptor: { (
+            uniffiHandle: UInt64,
+            chainId: UInt64,
+            address: RustBuffer,
+            uniffiFutureCallback: @escaping UniffiForeignFutureCompleteRustBuffer,
+            uniffiCallbackData: UInt64,
+            uniffiOutDroppedCallback: UnsafeMutablePointer<UniffiForeignFutureDroppedCallbackStruct>
+        ) in
+            let makeCall = {
+                () async throws -> String? in
+                guard let uniffiObj = try? FfiConverterTypeDescriptorSourceCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try await uniffiObj.typedDescriptor(
+                     chainId: try FfiConverterUInt64.lift(chainId),
+                     address: try FfiConverterString.lift(address)
+                )
+            }
+
+            let uniffiHandleSuccess = { (returnValue: String?) in
+                uniffiFutureCallback(
+                    uniffiCallbackData,
+                    UniffiForeignFutureResultRustBuffer(
+                        returnValue: FfiConverterOptionString.lower(returnValue),
+                        callStatus: RustCallStatus()
+                    )
+                )
+            }
+            let uniffiHandleError = { (statusCode, errorBuf) in
+                uniffiFutureCallback(
+                    uniffiCallbackData,
+                    UniffiForeignFutureResultRustBuffer(
+                        returnValue: RustBuffer.empty(),
+                        callStatus: RustCallStatus(code: statusCode, errorBuf: errorBuf)
+                    )
+                )
+            }
+            uniffiTraitInterfaceCallAsyncWithError(
+                makeCall: makeCall,
+                handleSuccess: uniffiHandleSuccess,
+                handleError: uniffiHandleError,
+                lowerError: FfiConverterTypeCallbackError_lower,
+                droppedCallback: uniffiOutDroppedCallback
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitDescriptorSourceCallback() {
+    uniffi_erc7730_fn_init_callback_vtable_descriptorsourcecallback(UniffiCallbackInterfaceDescriptorSourceCallback.vtable)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDescriptorSourceCallback: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<DescriptorSourceCallback>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = DescriptorSourceCallback
+
+    public static func lift(_ handle: UInt64) throws -> DescriptorSourceCallback {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return DescriptorSourceCallbackImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
+    }
+
+    public static func lower(_ value: DescriptorSourceCallback) -> UInt64 {
+         if let rustImpl = value as? DescriptorSourceCallbackImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DescriptorSourceCallback {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: DescriptorSourceCallback, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDescriptorSourceCallback_lift(_ handle: UInt64) throws -> DescriptorSourceCallback {
+    return try FfiConverterTypeDescriptorSourceCallback.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDescriptorSourceCallback_lower(_ value: DescriptorSourceCallback) -> UInt64 {
+    return FfiConverterTypeDescriptorSourceCallback.lower(value)
+}
+
+
+
+
+
+
+/**
+ * Log sink implemented by the host app, e.g. forwarding to `os_log` or `Logcat`.
+ */
+public protocol Logger: AnyObject, Sendable {
+    
+    /**
+     * Handle one record; `target` is the emitting module, e.g. `erc7730::ffi`.
+     */
+    func log(level: LogLevel, target: String, message: String) 
+    
+}
+/**
+ * Log sink implemented by the host app, e.g. forwarding to `os_log` or `Logcat`.
+ */
+open class LoggerImpl: Logger, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_erc7730_fn_clone_logger(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_erc7730_fn_free_logger(handle, $0) }
+    }
+
+    
+
+    
+    /**
+     * Handle one record; `target` is the emitting module, e.g. `erc7730::ffi`.
+     */
+open func log(level: LogLevel, target: String, message: String)  {try! rustCall() {
+    uniffi_erc7730_fn_method_logger_log(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeLogLevel_lower(level),
+        FfiConverterString.lower(target),
+        FfiConverterString.lower(message),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceLogger {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceLogger] = [UniffiVTableCallbackInterfaceLogger(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeLogger.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface Logger: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeLogger.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface Logger: handle missing in uniffiClone")
+            }
+        },
+        log: { (
+            uniffiHandle: UInt64,
+            level: RustBuffer,
+            target: RustBuffer,
+            message: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeLogger.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.log(
+                     level: try FfiConverterTypeLogLevel_lift(level),
+                     target: try FfiConverterString.lift(target),
+                     message: try FfiConverterString.lift(message)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitLogger() {
+    uniffi_erc7730_fn_init_callback_vtable_logger(UniffiCallbackInterfaceLogger.vtable)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLogger: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<Logger>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = Logger
+
+    public static func lift(_ handle: UInt64) throws -> Logger {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return LoggerImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
+    }
+
+    public static func lower(_ value: Logger) -> UInt64 {
+         if let rustImpl = value as? LoggerImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Logger {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: Logger, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogger_lift(_ handle: UInt64) throws -> Logger {
+    return try FfiConverterTypeLogger.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogger_lower(_ value: Logger) -> UInt64 {
+    return FfiConverterTypeLogger.lower(value)
+}
+
+
+
+
+
+
+/**
+ * Token metadata provider implemented by the host app, e.g. over its token cache.
+ *
+ * Called synchronously while rendering, so answer from memory rather than the network.
+ */
+public protocol TokenSourceCallback: AnyObject, Sendable {
+    
+    /**
+     * Metadata of the token at `address` (lowercase `0x`-prefixed) on `chain_id`.
+     * The returned `chain_id` and `address` are ignored.
+     */
+    func token(chainId: UInt64, address: String) throws  -> TokenMetaInput?
+    
+}
+/**
+ * Token metadata provider implemented by the host app, e.g. over its token cache.
+ *
+ * Called synchronously while rendering, so answer from memory rather than the network.
+ */
+open class TokenSourceCallbackImpl: TokenSourceCallback, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_erc7730_fn_clone_tokensourcecallback(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_erc7730_fn_free_tokensourcecallback(handle, $0) }
+    }
+
+    
+
+    
+    /**
+     * Metadata of the token at `address` (lowercase `0x`-prefixed) on `chain_id`.
+     * The returned `chain_id` and `address` are ignored.
+     */
+open func token(chainId: UInt64, address: String)throws  -> TokenMetaInput?  {
+    return try  FfiConverterOptionTypeTokenMetaInput.lift(try rustCallWithError(FfiConverterTypeCallbackError_lift) {
+    uniffi_erc7730_fn_method_tokensourcecallback_token(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(chainId),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceTokenSourceCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceTokenSourceCallback] = [UniffiVTableCallbackInterfaceTokenSourceCallback(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeTokenSourceCallback.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface TokenSourceCallback: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeTokenSourceCallback.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface TokenSourceCallback: handle missing in uniffiClone")
+            }
+        },
+        token: { (
+            uniffiHandle: UInt64,
+            chainId: UInt64,
+            address: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> TokenMetaInput? in
+                guard let uniffiObj = try? FfiConverterTypeTokenSourceCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try uniffiObj.token(
+                     chainId: try FfiConverterUInt64.lift(chainId),
+                     address: try FfiConverterString.lift(address)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterOptionTypeTokenMetaInput.lower($0) }
+            uniffiTraitInterfaceCallWithError(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn,
+                lowerError: FfiConverterTypeCallbackError_lower
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitTokenSourceCallback() {
+    uniffi_erc7730_fn_init_callback_vtable_tokensourcecallback(UniffiCallbackInterfaceTokenSourceCallback.vtable)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTokenSourceCallback: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<TokenSourceCallback>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = TokenSourceCallback
+
+    public static func lift(_ handle: UInt64) throws -> TokenSourceCallback {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return TokenSourceCallbackImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
+    }
+
+    public static func lower(_ value: TokenSourceCallback) -> UInt64 {
+         if let rustImpl = value as? TokenSourceCallbackImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TokenSourceCallback {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: TokenSourceCallback, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTokenSourceCallback_lift(_ handle: UInt64) throws -> TokenSourceCallback {
+    return try FfiConverterTypeTokenSourceCallback.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTokenSourceCallback_lower(_ value: TokenSourceCallback) -> UInt64 {
+    return FfiConverterTypeTokenSourceCallback.lower(value)
+}
+
+
+
+
+/**
+ * A caller-supplied address name.
+ */
+public struct AddressNameInput: Equatable, Hashable {
+    /**
+     * Chain the name applies to; `None` for a contact valid on every chain.
+     */
+    public var chainId: UInt64?
+    public var address: String
+    public var name: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Chain the name applies to; `None` for a contact valid on every chain.
+         */chainId: UInt64?, address: String, name: String) {
+        self.chainId = chainId
+        self.address = address
+        self.name = name
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension AddressNameInput: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAddressNameInput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AddressNameInput {
+        return
+            try AddressNameInput(
+                chainId: FfiConverterOptionUInt64.read(from: &buf), 
+                address: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AddressNameInput, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt64.write(value.chainId, into: &buf)
+        FfiConverterString.write(value.address, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAddressNameInput_lift(_ buf: RustBuffer) throws -> AddressNameInput {
+    return try FfiConverterTypeAddressNameInput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAddressNameInput_lower(_ value: AddressNameInput) -> RustBuffer {
+    return FfiConverterTypeAddressNameInput.lower(value)
+}
+
+
+/**
+ * An address name returned by an [`AddressNameCallback`].
+ */
+public struct AddressNameResult: Equatable, Hashable {
+    public var name: String
+    /**
+     * Provenance shown to the user. `Descriptor` is reserved for descriptor
+     * address books and is downgraded to `Trusted`.
+     */
+    public var source: NameSource
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, 
+        /**
+         * Provenance shown to the user. `Descriptor` is reserved for descriptor
+         * address books and is downgraded to `Trusted`.
+         */source: NameSource) {
+        self.name = name
+        self.source = source
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension AddressNameResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAddressNameResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AddressNameResult {
+        return
+            try AddressNameResult(
+                name: FfiConverterString.read(from: &buf), 
+                source: FfiConverterTypeNameSource.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AddressNameResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeNameSource.write(value.source, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAddressNameResult_lift(_ buf: RustBuffer) throws -> AddressNameResult {
+    return try FfiConverterTypeAddressNameResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAddressNameResult_lower(_ value: AddressNameResult) -> RustBuffer {
+    return FfiConverterTypeAddressNameResult.lower(value)
+}
+
+
+/**
+ * Configuration for a [`ClearSigner`].
+ *
+ * Descriptor sources are tried in order: in-memory `descriptors`, then
+ * `descriptor_source`, then `descriptor_directory`, then the registry at `registry_url`.
+ */
+public struct ClearSignerConfig {
+    /**
+     * Registry base URL (raw content URL of a registry repository).
+     * Requires the `github-registry` feature.
+     */
+    public var registryUrl: String?
+    /**
+     * Local directory of descriptor JSON files, searched recursively.
+     */
+    public var descriptorDirectory: String?
+    /**
+     * Descriptor JSON documents, indexed by their deployments.
+     */
+    public var descriptors: [String]
+    /**
+     * Token metadata, consulted before the built-in token list.
+     */
+    public var tokens: [TokenMetaInput]
+    /**
+     * Whether to fall back to the built-in well-known token list.
+     */
+    public var useWellKnownTokens: Bool
+    /**
+     * Trusted names and contacts; also used for address-poisoning detection.
+     */
+    public var addressNames: [AddressNameInput]
+    /**
+     * Chainlist-style JSON array of extra chains, consulted before the built-in list.
+     */
+    public var chainsJson: String?
+    /**
+     * App-implemented token provider, consulted after `tokens`.
+     */
+    public var tokenSource: TokenSourceCallback?
+    /**
+     * App-implemented descriptor provider, consulted after `descriptors`.
+     */
+    public var descriptorSource: DescriptorSourceCallback?
+    /**
+     * App-implemented name provider, consulted after `address_names`.
+     */
+    public var addressNameSource: AddressNameCallback?
+    /**
+     * Decoding limits and look-alike settings; `None` keeps the defaults.
+     */
+    public var options: SignerOptions?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Registry base URL (raw content URL of a registry repository).
+         * Requires the `github-registry` feature.
+         */registryUrl: String? = nil, 
+        /**
+         * Local directory of descriptor JSON files, searched recursively.
+         */descriptorDirectory: String? = nil, 
+        /**
+         * Descriptor JSON documents, indexed by their deployments.
+         */descriptors: [String] = [], 
+        /**
+         * Token metadata, consulted before the built-in token list.
+         */tokens: [TokenMetaInput] = [], 
+        /**
+         * Whether to fall back to the built-in well-known token list.
+         */useWellKnownTokens: Bool = true, 
+        /**
+         * Trusted names and contacts; also used for address-poisoning detection.
+         */addressNames: [AddressNameInput] = [], 
+        /**
+         * Chainlist-style JSON array of extra chains, consulted before the built-in list.
+         */chainsJson: String? = nil, 
+        /**
+         * App-implemented token provider, consulted after `tokens`.
+         */tokenSource: TokenSourceCallback? = nil, 
+        /**
+         * App-implemented descriptor provider, consulted after `descriptors`.
+         */descriptorSource: DescriptorSourceCallback? = nil, 
+        /**
+         * App-implemented name provider, consulted after `address_names`.
+         */addressNameSource: AddressNameCallback? = nil, 
+        /**
+         * Decoding limits and look-alike settings; `None` keeps the defaults.
+         */options: SignerOptions? = nil) {
+        self.registryUrl = registryUrl
+        self.descriptorDirectory = descriptorDirectory
+        self.descriptors = descriptors
+        self.tokens = tokens
+        self.useWellKnownTokens = useWellKnownTokens
+        self.addressNames = addressNames
+        self.chainsJson = chainsJson
+        self.tokenSource = tokenSource
+        self.descriptorSource = descriptorSource
+        self.addressNameSource = addressNameSource
+        self.options = options
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension ClearSignerConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeClearSignerConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ClearSignerConfig {
+        return
+            try ClearSignerConfig(
+                registryUrl: FfiConverterOptionString.read(from: &buf), 
+                descriptorDirectory: FfiConverterOptionString.read(from: &buf), 
+                descriptors: FfiConverterSequenceString.read(from: &buf), 
+                tokens: FfiConverterSequenceTypeTokenMetaInput.read(from: &buf), 
+                useWellKnownTokens: FfiConverterBool.read(from: &buf), 
+                addressNames: FfiConverterSequenceTypeAddressNameInput.read(from: &buf), 
+                chainsJson: FfiConverterOptionString.read(from: &buf), 
+                tokenSource: FfiConverterOptionTypeTokenSourceCallback.read(from: &buf), 
+                descriptorSource: FfiConverterOptionTypeDescriptorSourceCallback.read(from: &buf), 
+                addressNameSource: FfiConverterOptionTypeAddressNameCallback.read(from: &buf), 
+                options: FfiConverterOptionTypeSignerOptions.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ClearSignerConfig, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.registryUrl, into: &buf)
+        FfiConverterOptionString.write(value.descriptorDirectory, into: &buf)
+        FfiConverterSequenceString.write(value.descriptors, into: &buf)
+        FfiConverterSequenceTypeTokenMetaInput.write(value.tokens, into: &buf)
+        FfiConverterBool.write(value.useWellKnownTokens, into: &buf)
+        FfiConverterSequenceTypeAddressNameInput.write(value.addressNames, into: &buf)
+        FfiConverterOptionString.write(value.chainsJson, into: &buf)
+        FfiConverterOptionTypeTokenSourceCallback.write(value.tokenSource, into: &buf)
+        FfiConverterOptionTypeDescriptorSourceCallback.write(value.descriptorSource, into: &buf)
+        FfiConverterOptionTypeAddressNameCallback.write(value.addressNameSource, into: &buf)
+        FfiConverterOptionTypeSignerOptions.write(value.options, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClearSignerConfig_lift(_ buf: RustBuffer) throws -> ClearSignerConfig {
+    return try FfiConverterTypeClearSignerConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClearSignerConfig_lower(_ value: ClearSignerConfig) -> RustBuffer {
+    return FfiConverterTypeClearSignerConfig.lower(value)
+}
+
+
+/**
+ * A single label+value pair for display.
+ */
+public struct DisplayItem: Equatable, Hashable {
+    public var label: String
+    public var value: String
+    /**
+     * Provenance of the name shown for an `addressName` field; `None` when the
+     * value is not a resolved name.
+     */
+    public var nameSource: NameSource?
+    /**
+     * Warnings about this field's value, also listed in [`DisplayModel::warnings`].
+     */
+    public var warnings: [FieldWarning]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(label: String, value: String, 
+        /**
+         * Provenance of the name shown for an `addressName` field; `None` when the
+         * value is not a resolved name.
+         */nameSource: NameSource?, 
+        /**
+         * Warnings about this field's value, also listed in [`DisplayModel::warnings`].
+         */warnings: [FieldWarning]) {
+        self.label = label
+        self.value = value
+        self.nameSource = nameSource
+        self.warnings = warnings
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension DisplayItem: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDisplayItem: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DisplayItem {
+        return
+            try DisplayItem(
+                label: FfiConverterString.read(from: &buf), 
+                value: FfiConverterString.read(from: &buf), 
+                nameSource: FfiConverterOptionTypeNameSource.read(from: &buf), 
+                warnings: FfiConverterSequenceTypeFieldWarning.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DisplayItem, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.label, into: &buf)
+        FfiConverterString.write(value.value, into: &buf)
+        FfiConverterOptionTypeNameSource.write(value.nameSource, into: &buf)
+        FfiConverterSequenceTypeFieldWarning.write(value.warnings, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDisplayItem_lift(_ buf: RustBuffer) throws -> DisplayItem {
+    return try FfiConverterTypeDisplayItem.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDisplayItem_lower(_ value: DisplayItem) -> RustBuffer {
+    return FfiConverterTypeDisplayItem.lower(value)
+}
+
+
+/**
+ * Output model for clear signing display.
+ */
+public struct DisplayModel: Equatable, Hashable {
+    public var intent: String
+    public var interpolatedIntent: String?
+    public var entries: [DisplayEntry]
+    public var warnings: [String]
+    /**
+     * Approval risks found in the transaction or signature.
+     */
+    public var risks: [RiskFinding]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(intent: String, interpolatedIntent: String?, entries: [DisplayEntry], warnings: [String], 
+        /**
+         * Approval risks found in the transaction or signature.
+         */risks: [RiskFinding]) {
+        self.intent = intent
+        self.interpolatedIntent = interpolatedIntent
+        self.entries = entries
+        self.warnings = warnings
+        self.risks = risks
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension DisplayModel: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDisplayModel: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DisplayModel {
+        return
+            try DisplayModel(
+                intent: FfiConverterString.read(from: &buf), 
+                interpolatedIntent: FfiConverterOptionString.read(from: &buf), 
+                entries: FfiConverterSequenceTypeDisplayEntry.read(from: &buf), 
+                warnings: FfiConverterSequenceString.read(from: &buf), 
+                risks: FfiConverterSequenceTypeRiskFinding.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DisplayModel, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.intent, into: &buf)
+        FfiConverterOptionString.write(value.interpolatedIntent, into: &buf)
+        FfiConverterSequenceTypeDisplayEntry.write(value.entries, into: &buf)
+        FfiConverterSequenceString.write(value.warnings, into: &buf)
+        FfiConverterSequenceTypeRiskFinding.write(value.risks, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDisplayModel_lift(_ buf: RustBuffer) throws -> DisplayModel {
+    return try FfiConverterTypeDisplayModel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDisplayModel_lower(_ value: DisplayModel) -> RustBuffer {
+    return FfiConverterTypeDisplayModel.lower(value)
+}
+
+
+/**
+ * A warning attached to a single display item.
+ */
+public struct FieldWarning: Equatable, Hashable {
+    public var kind: FieldWarningKind
+    public var severity: WarningSeverity
+    public var message: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: FieldWarningKind, severity: WarningSeverity, message: String) {
+        self.kind = kind
+        self.severity = severity
+        self.message = message
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension FieldWarning: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFieldWarning: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FieldWarning {
+        return
+            try FieldWarning(
+                kind: FfiConverterTypeFieldWarningKind.read(from: &buf), 
+                severity: FfiConverterTypeWarningSeverity.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FieldWarning, into buf: inout [UInt8]) {
+        FfiConverterTypeFieldWarningKind.write(value.kind, into: &buf)
+        FfiConverterTypeWarningSeverity.write(value.severity, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldWarning_lift(_ buf: RustBuffer) throws -> FieldWarning {
+    return try FfiConverterTypeFieldWarning.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldWarning_lower(_ value: FieldWarning) -> RustBuffer {
+    return FfiConverterTypeFieldWarning.lower(value)
+}
+
+
+/**
+ * A risk found in a transaction or signature.
+ */
+public struct RiskFinding: Equatable, Hashable {
+    public var kind: RiskKind
+    public var severity: WarningSeverity
+    public var message: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: RiskKind, severity: WarningSeverity, message: String) {
+        self.kind = kind
+        self.severity = severity
+        self.message = message
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension RiskFinding: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRiskFinding: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RiskFinding {
+        return
+            try RiskFinding(
+                kind: FfiConverterTypeRiskKind.read(from: &buf), 
+                severity: FfiConverterTypeWarningSeverity.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RiskFinding, into buf: inout [UInt8]) {
+        FfiConverterTypeRiskKind.write(value.kind, into: &buf)
+        FfiConverterTypeWarningSeverity.write(value.severity, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRiskFinding_lift(_ buf: RustBuffer) throws -> RiskFinding {
+    return try FfiConverterTypeRiskFinding.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRiskFinding_lower(_ value: RiskFinding) -> RustBuffer {
+    return FfiConverterTypeRiskFinding.lower(value)
+}
+
+
+/**
+ * Decoding and address-poisoning settings for a [`ClearSigner`]; unset values
+ * keep the library defaults.
+ */
+public struct SignerOptions: Equatable, Hashable {
+    /**
+     * Whether non-canonical calldata is rejected or rendered with warnings.
+     */
+    public var decodeMode: DecodeMode
+    /**
+     * Maximum nesting of arrays and tuples in calldata.
+     */
+    public var maxDecodeDepth: UInt32?
+    /**
+     * Maximum number of decoded calldata values.
+     */
+    public var maxDecodedElements: UInt64?
+    /**
+     * Maximum number of bytes copied into decoded calldata values.
+     */
+    public var maxDecodedBytes: UInt64?
+    /**
+     * Leading and trailing hex characters an address must share with a known
+     * address to be flagged as a look-alike.
+     */
+    public var lookalikeChars: UInt32?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Whether non-canonical calldata is rejected or rendered with warnings.
+         */decodeMode: DecodeMode, 
+        /**
+         * Maximum nesting of arrays and tuples in calldata.
+         */maxDecodeDepth: UInt32? = nil, 
+        /**
+         * Maximum number of decoded calldata values.
+         */maxDecodedElements: UInt64? = nil, 
+        /**
+         * Maximum number of bytes copied into decoded calldata values.
+         */maxDecodedBytes: UInt64? = nil, 
+        /**
+         * Leading and trailing hex characters an address must share with a known
+         * address to be flagged as a look-alike.
+         */lookalikeChars: UInt32? = nil) {
+        self.decodeMode = decodeMode
+        self.maxDecodeDepth = maxDecodeDepth
+        self.maxDecodedElements = maxDecodedElements
+        self.maxDecodedBytes = maxDecodedBytes
+        self.lookalikeChars = lookalikeChars
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension SignerOptions: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSignerOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SignerOptions {
+        return
+            try SignerOptions(
+                decodeMode: FfiConverterTypeDecodeMode.read(from: &buf), 
+                maxDecodeDepth: FfiConverterOptionUInt32.read(from: &buf), 
+                maxDecodedElements: FfiConverterOptionUInt64.read(from: &buf), 
+                maxDecodedBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                lookalikeChars: FfiConverterOptionUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SignerOptions, into buf: inout [UInt8]) {
+        FfiConverterTypeDecodeMode.write(value.decodeMode, into: &buf)
+        FfiConverterOptionUInt32.write(value.maxDecodeDepth, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxDecodedElements, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxDecodedBytes, into: &buf)
+        FfiConverterOptionUInt32.write(value.lookalikeChars, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignerOptions_lift(_ buf: RustBuffer) throws -> SignerOptions {
+    return try FfiConverterTypeSignerOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignerOptions_lower(_ value: SignerOptions) -> RustBuffer {
+    return FfiConverterTypeSignerOptions.lower(value)
+}
+
+
+public struct TokenMetaInput: Equatable, Hashable {
+    public var chainId: UInt64
+    public var address: String
+    public var symbol: String
+    public var decimals: UInt8
+    public var name: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(chainId: UInt64, address: String, symbol: String, decimals: UInt8, name: String) {
+        self.chainId = chainId
+        self.address = address
+        self.symbol = symbol
+        self.decimals = decimals
+        self.name = name
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension TokenMetaInput: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTokenMetaInput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TokenMetaInput {
+        return
+            try TokenMetaInput(
+                chainId: FfiConverterUInt64.read(from: &buf), 
+                address: FfiConverterString.read(from: &buf), 
+                symbol: FfiConverterString.read(from: &buf), 
+                decimals: FfiConverterUInt8.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TokenMetaInput, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.chainId, into: &buf)
+        FfiConverterString.write(value.address, into: &buf)
+        FfiConverterString.write(value.symbol, into: &buf)
+        FfiConverterUInt8.write(value.decimals, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTokenMetaInput_lift(_ buf: RustBuffer) throws -> TokenMetaInput {
+    return try FfiConverterTypeTokenMetaInput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTokenMetaInput_lower(_ value: TokenMetaInput) -> RustBuffer {
+    return FfiConverterTypeTokenMetaInput.lower(value)
+}
+
+
+/**
+ * Error raised by a foreign callback.
+ */
+public enum CallbackError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+
+    
+    
+    /**
+     * The callback failed; `reason` is shown in warnings and errors.
+     */
+    case Failed(reason: String
+    )
+    /**
+     * The callback threw an exception it did not declare.
+     */
+    case Unexpected(reason: String
+    )
+
+    
+
+    
+
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
+}
+
+#if compiler(>=6)
+extension CallbackError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCallbackError: FfiConverterRustBuffer {
+    typealias SwiftType = CallbackError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CallbackError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Failed(
+            reason: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .Unexpected(
+            reason: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CallbackError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .Failed(reason):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(reason, into: &buf)
+            
+        
+        case let .Unexpected(reason):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(reason, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCallbackError_lift(_ buf: RustBuffer) throws -> CallbackError {
+    return try FfiConverterTypeCallbackError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCallbackError_lower(_ value: CallbackError) -> RustBuffer {
+    return FfiConverterTypeCallbackError.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How [`decode_calldata_with_mode`] treats encodings a canonical ABI encoder never
+ * produces: dirty address bytes, bools other than 0 or 1, out-of-range integers,
+ * non-zero padding, misplaced tail offsets and trailing bytes. Contracts may read
+ * such calldata differently from how it is displayed.
+ */
+
+public enum DecodeMode: Equatable, Hashable {
+    
+    /**
+     * Decode anyway and record each problem in [`DecodedArguments::warnings`].
+     */
+    case lenient
+    /**
+     * Fail with the first problem.
+     */
+    case strict
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension DecodeMode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDecodeMode: FfiConverterRustBuffer {
+    typealias SwiftType = DecodeMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DecodeMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .lenient
+        
+        case 2: return .strict
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DecodeMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .lenient:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .strict:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDecodeMode_lift(_ buf: RustBuffer) throws -> DecodeMode {
+    return try FfiConverterTypeDecodeMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDecodeMode_lower(_ value: DecodeMode) -> RustBuffer {
+    return FfiConverterTypeDecodeMode.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A display entry — either a flat item or a group of items.
+ */
+
+public enum DisplayEntry: Equatable, Hashable {
+    
+    case item(DisplayItem
+    )
+    case group(label: String, iteration: GroupIteration, items: [DisplayItem]
+    )
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension DisplayEntry: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDisplayEntry: FfiConverterRustBuffer {
+    typealias SwiftType = DisplayEntry
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DisplayEntry {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .item(try FfiConverterTypeDisplayItem.read(from: &buf)
+        )
+        
+        case 2: return .group(label: try FfiConverterString.read(from: &buf), iteration: try FfiConverterTypeGroupIteration.read(from: &buf), items: try FfiConverterSequenceTypeDisplayItem.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DisplayEntry, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .item(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeDisplayItem.write(v1, into: &buf)
+            
+        
+        case let .group(label,iteration,items):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(label, into: &buf)
+            FfiConverterTypeGroupIteration.write(iteration, into: &buf)
+            FfiConverterSequenceTypeDisplayItem.write(items, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDisplayEntry_lift(_ buf: RustBuffer) throws -> DisplayEntry {
+    return try FfiConverterTypeDisplayEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDisplayEntry_lower(_ value: DisplayEntry) -> RustBuffer {
+    return FfiConverterTypeDisplayEntry.lower(value)
+}
+
+
+
+/**
+ * Errors surfaced across the FFI boundary.
+ *
+ * Flat: foreign bindings receive the variant and its display message, so the
+ * Kotlin `FfiException` and Swift `FfiError` carry a readable `message`.
+ */
+public enum FfiError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+
+    
+    
+    case InvalidDescriptorJson(message: String)
+    
+    case InvalidTypedDataJson(message: String)
+    
+    case InvalidCalldataHex(message: String)
+    
+    case InvalidValueHex(message: String)
+    
+    case Decode(message: String)
+    
+    case Descriptor(message: String)
+    
+    case Resolve(message: String)
+    
+    case TokenRegistry(message: String)
+    
+    case Render(message: String)
+    
+
+    
+
+    
+
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
+}
+
+#if compiler(>=6)
+extension FfiError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFfiError: FfiConverterRustBuffer {
+    typealias SwiftType = FfiError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .InvalidDescriptorJson(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .InvalidTypedDataJson(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .InvalidCalldataHex(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .InvalidValueHex(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 5: return .Decode(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 6: return .Descriptor(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 7: return .Resolve(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 8: return .TokenRegistry(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 9: return .Render(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FfiError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        case .InvalidDescriptorJson(_ /* message is ignored*/):
+            writeInt(&buf, Int32(1))
+        case .InvalidTypedDataJson(_ /* message is ignored*/):
+            writeInt(&buf, Int32(2))
+        case .InvalidCalldataHex(_ /* message is ignored*/):
+            writeInt(&buf, Int32(3))
+        case .InvalidValueHex(_ /* message is ignored*/):
+            writeInt(&buf, Int32(4))
+        case .Decode(_ /* message is ignored*/):
+            writeInt(&buf, Int32(5))
+        case .Descriptor(_ /* message is ignored*/):
+            writeInt(&buf, Int32(6))
+        case .Resolve(_ /* message is ignored*/):
+            writeInt(&buf, Int32(7))
+        case .TokenRegistry(_ /* message is ignored*/):
+            writeInt(&buf, Int32(8))
+        case .Render(_ /* message is ignored*/):
+            writeInt(&buf, Int32(9))
+
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiError_lift(_ buf: RustBuffer) throws -> FfiError {
+    return try FfiConverterTypeFfiError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFfiError_lower(_ value: FfiError) -> RustBuffer {
+    return FfiConverterTypeFfiError.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * What a [`FieldWarning`] is about.
+ */
+
+public enum FieldWarningKind: Equatable, Hashable {
+    
+    /**
+     * The address is a different kind than the `addressName` `types` param accepts.
+     */
+    case addressTypeMismatch
+    /**
+     * The address shares its leading and trailing characters with a known address
+     * it is not — a likely address-poisoning attempt.
+     */
+    case lookAlikeAddress(
+        /**
+         * Checksummed known address it imitates.
+         */knownAddress: String, knownLabel: String
+    )
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension FieldWarningKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFieldWarningKind: FfiConverterRustBuffer {
+    typealias SwiftType = FieldWarningKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FieldWarningKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .addressTypeMismatch
+        
+        case 2: return .lookAlikeAddress(knownAddress: try FfiConverterString.read(from: &buf), knownLabel: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FieldWarningKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .addressTypeMismatch:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .lookAlikeAddress(knownAddress,knownLabel):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(knownAddress, into: &buf)
+            FfiConverterString.write(knownLabel, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldWarningKind_lift(_ buf: RustBuffer) throws -> FieldWarningKind {
+    return try FfiConverterTypeFieldWarningKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldWarningKind_lower(_ value: FieldWarningKind) -> RustBuffer {
+    return FfiConverterTypeFieldWarningKind.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum GroupIteration: Equatable, Hashable {
+    
+    case sequential
+    case bundled
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension GroupIteration: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGroupIteration: FfiConverterRustBuffer {
+    typealias SwiftType = GroupIteration
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GroupIteration {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .sequential
+        
+        case 2: return .bundled
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: GroupIteration, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .sequential:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .bundled:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGroupIteration_lift(_ buf: RustBuffer) throws -> GroupIteration {
+    return try FfiConverterTypeGroupIteration.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGroupIteration_lower(_ value: GroupIteration) -> RustBuffer {
+    return FfiConverterTypeGroupIteration.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Severity of a log record, from most to least severe.
+ */
+
+public enum LogLevel: Equatable, Hashable {
+    
+    case error
+    case warn
+    case info
+    case debug
+    case trace
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension LogLevel: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLogLevel: FfiConverterRustBuffer {
+    typealias SwiftType = LogLevel
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LogLevel {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .error
+        
+        case 2: return .warn
+        
+        case 3: return .info
+        
+        case 4: return .debug
+        
+        case 5: return .trace
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LogLevel, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .error:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .warn:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .info:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .debug:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .trace:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogLevel_lift(_ buf: RustBuffer) throws -> LogLevel {
+    return try FfiConverterTypeLogLevel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogLevel_lower(_ value: LogLevel) -> RustBuffer {
+    return FfiConverterTypeLogLevel.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * A display entry — either a flat item or a group of items.
+ * Where an address name came from, from most to least curated.
  */
 
-public enum DisplayEntry: Equatable, Hashable {
+public enum NameSource: Equatable, Hashable {
     
-    case item(DisplayItem
-    )
-    case group(label: String, iteration: GroupIteration, items: [DisplayItem]
-    )
+    /**
+     * The descriptor's address book or deployments.
+     */
+    case descriptor
+    /**
+     * The user's own contacts.
+     */
+    case contact
+    /**
+     * A trusted name list supplied by the wallet.
+     */
+    case trusted
+    /**
+     * ENS reverse resolution, verified against forward resolution.
+     */
+    case ens
 
 
 
@@ -697,44 +3055,50 @@ public enum DisplayEntry: Equatable, Hashable {
 }
 
 #if compiler(>=6)
-extension DisplayEntry: Sendable {}
+extension NameSource: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDisplayEntry: FfiConverterRustBuffer {
-    typealias SwiftType = DisplayEntry
+public struct FfiConverterTypeNameSource: FfiConverterRustBuffer {
+    typealias SwiftType = NameSource
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DisplayEntry {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NameSource {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .item(try FfiConverterTypeDisplayItem.read(from: &buf)
-        )
+        case 1: return .descriptor
         
-        case 2: return .group(label: try FfiConverterString.read(from: &buf), iteration: try FfiConverterTypeGroupIteration.read(from: &buf), items: try FfiConverterSequenceTypeDisplayItem.read(from: &buf)
-        )
+        case 2: return .contact
+        
+        case 3: return .trusted
+        
+        case 4: return .ens
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: DisplayEntry, into buf: inout [UInt8]) {
+    public static func write(_ value: NameSource, into buf: inout [UInt8]) {
         switch value {
         
         
-        case let .item(v1):
+        case .descriptor:
             writeInt(&buf, Int32(1))
-            FfiConverterTypeDisplayItem.write(v1, into: &buf)
-            
         
-        case let .group(label,iteration,items):
+        
+        case .contact:
             writeInt(&buf, Int32(2))
-            FfiConverterString.write(label, into: &buf)
-            FfiConverterTypeGroupIteration.write(iteration, into: &buf)
-            FfiConverterSequenceTypeDisplayItem.write(items, into: &buf)
-            
+        
+        
+        case .trusted:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .ens:
+            writeInt(&buf, Int32(4))
+        
         }
     }
 }
@@ -743,244 +3107,460 @@ public struct FfiConverterTypeDisplayEntry: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDisplayEntry_lift(_ buf: RustBuffer) throws -> DisplayEntry {
-    return try FfiConverterTypeDisplayEntry.lift(buf)
+public func FfiConverterTypeNameSource_lift(_ buf: RustBuffer) throws -> NameSource {
+    return try FfiConverterTypeNameSource.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDisplayEntry_lower(_ value: DisplayEntry) -> RustBuffer {
-    return FfiConverterTypeDisplayEntry.lower(value)
+public func FfiConverterTypeNameSource_lower(_ value: NameSource) -> RustBuffer {
+    return FfiConverterTypeNameSource.lower(value)
 }
 
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Category of an approval risk.
+ */
 
-public enum FfiError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
-
-    
+public enum RiskKind: Equatable, Hashable {
     
-    case InvalidDescriptorJson(String
-    )
-    case InvalidTypedDataJson(String
-    )
-    case InvalidCalldataHex(String
-    )
-    case InvalidValueHex(String
-    )
-    case Decode(String
-    )
-    case Descriptor(String
-    )
-    case Resolve(String
-    )
-    case TokenRegistry(String
-    )
-    case Render(String
-    )
+    /**
+     * The allowance is the maximum value (or close to it).
+     */
+    case unlimitedAllowance
+    /**
+     * The allowance is at least [`LARGE_ALLOWANCE_TOKENS`] whole tokens, assuming
+     * [`FALLBACK_ALLOWANCE_DECIMALS`] when the token's decimals are unknown.
+     */
+    case largeAllowance
+    /**
+     * An operator is approved for every token of a collection.
+     */
+    case approvalForAll
+    /**
+     * The spender is an externally owned account rather than a contract.
+     */
+    case spenderIsEoa
+    /**
+     * The spender is in neither the descriptor nor the caller's address book.
+     */
+    case unknownSpender
+    /**
+     * The permit stays valid longer than [`MAX_PERMIT_LIFETIME_SECS`].
+     */
+    case longLivedPermit
+
+
 
-    
 
-    
 
-    
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
-    
 }
 
 #if compiler(>=6)
-extension FfiError: Sendable {}
+extension RiskKind: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFfiError: FfiConverterRustBuffer {
-    typealias SwiftType = FfiError
+public struct FfiConverterTypeRiskKind: FfiConverterRustBuffer {
+    typealias SwiftType = RiskKind
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FfiError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RiskKind {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-
         
-
+        case 1: return .unlimitedAllowance
         
-        case 1: return .InvalidDescriptorJson(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .InvalidTypedDataJson(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .InvalidCalldataHex(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .InvalidValueHex(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 5: return .Decode(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 6: return .Descriptor(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 7: return .Resolve(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 8: return .TokenRegistry(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 9: return .Render(
-            try FfiConverterString.read(from: &buf)
-            )
-
-         default: throw UniffiInternalError.unexpectedEnumCase
+        case 2: return .largeAllowance
+        
+        case 3: return .approvalForAll
+        
+        case 4: return .spenderIsEoa
+        
+        case 5: return .unknownSpender
+        
+        case 6: return .longLivedPermit
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: FfiError, into buf: inout [UInt8]) {
+    public static func write(_ value: RiskKind, into buf: inout [UInt8]) {
         switch value {
-
-        
-
         
         
-        case let .InvalidDescriptorJson(v1):
+        case .unlimitedAllowance:
             writeInt(&buf, Int32(1))
-            FfiConverterString.write(v1, into: &buf)
-            
         
-        case let .InvalidTypedDataJson(v1):
+        
+        case .largeAllowance:
             writeInt(&buf, Int32(2))
-            FfiConverterString.write(v1, into: &buf)
-            
         
-        case let .InvalidCalldataHex(v1):
+        
+        case .approvalForAll:
             writeInt(&buf, Int32(3))
-            FfiConverterString.write(v1, into: &buf)
-            
         
-        case let .InvalidValueHex(v1):
+        
+        case .spenderIsEoa:
             writeInt(&buf, Int32(4))
-            FfiConverterString.write(v1, into: &buf)
-            
         
-        case let .Decode(v1):
+        
+        case .unknownSpender:
             writeInt(&buf, Int32(5))
-            FfiConverterString.write(v1, into: &buf)
-            
         
-        case let .Descriptor(v1):
+        
+        case .longLivedPermit:
             writeInt(&buf, Int32(6))
-            FfiConverterString.write(v1, into: &buf)
-            
         
-        case let .Resolve(v1):
-            writeInt(&buf, Int32(7))
-            FfiConverterString.write(v1, into: &buf)
-            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRiskKind_lift(_ buf: RustBuffer) throws -> RiskKind {
+    return try FfiConverterTypeRiskKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRiskKind_lower(_ value: RiskKind) -> RustBuffer {
+    return FfiConverterTypeRiskKind.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How strongly a [`FieldWarning`] should be surfaced.
+ */
+
+public enum WarningSeverity: Equatable, Hashable {
+    
+    case info
+    case warning
+    /**
+     * The transaction likely does not do what the user expects.
+     */
+    case danger
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension WarningSeverity: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWarningSeverity: FfiConverterRustBuffer {
+    typealias SwiftType = WarningSeverity
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WarningSeverity {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
         
-        case let .TokenRegistry(v1):
-            writeInt(&buf, Int32(8))
-            FfiConverterString.write(v1, into: &buf)
-            
+        case 1: return .info
         
-        case let .Render(v1):
-            writeInt(&buf, Int32(9))
-            FfiConverterString.write(v1, into: &buf)
-            
+        case 2: return .warning
+        
+        case 3: return .danger
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: WarningSeverity, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .info:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .warning:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .danger:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWarningSeverity_lift(_ buf: RustBuffer) throws -> WarningSeverity {
+    return try FfiConverterTypeWarningSeverity.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWarningSeverity_lower(_ value: WarningSeverity) -> RustBuffer {
+    return FfiConverterTypeWarningSeverity.lower(value)
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiError_lift(_ buf: RustBuffer) throws -> FfiError {
-    return try FfiConverterTypeFfiError.lift(buf)
+fileprivate struct FfiConverterOptionTypeAddressNameCallback: FfiConverterRustBuffer {
+    typealias SwiftType = AddressNameCallback?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeAddressNameCallback.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeAddressNameCallback.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFfiError_lower(_ value: FfiError) -> RustBuffer {
-    return FfiConverterTypeFfiError.lower(value)
-}
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-
-public enum GroupIteration: Equatable, Hashable {
-    
-    case sequential
-    case bundled
+fileprivate struct FfiConverterOptionTypeDescriptorSourceCallback: FfiConverterRustBuffer {
+    typealias SwiftType = DescriptorSourceCallback?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDescriptorSourceCallback.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDescriptorSourceCallback.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeLogger: FfiConverterRustBuffer {
+    typealias SwiftType = Logger?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeLogger.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeLogger.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-#if compiler(>=6)
-extension GroupIteration: Sendable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+fileprivate struct FfiConverterOptionTypeTokenSourceCallback: FfiConverterRustBuffer {
+    typealias SwiftType = TokenSourceCallback?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTokenSourceCallback.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTokenSourceCallback.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeGroupIteration: FfiConverterRustBuffer {
-    typealias SwiftType = GroupIteration
+fileprivate struct FfiConverterOptionTypeAddressNameResult: FfiConverterRustBuffer {
+    typealias SwiftType = AddressNameResult?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GroupIteration {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .sequential
-        
-        case 2: return .bundled
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeAddressNameResult.write(value, into: &buf)
     }
 
-    public static func write(_ value: GroupIteration, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .sequential:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .bundled:
-            writeInt(&buf, Int32(2))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeAddressNameResult.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeGroupIteration_lift(_ buf: RustBuffer) throws -> GroupIteration {
-    return try FfiConverterTypeGroupIteration.lift(buf)
+fileprivate struct FfiConverterOptionTypeSignerOptions: FfiConverterRustBuffer {
+    typealias SwiftType = SignerOptions?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSignerOptions.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSignerOptions.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeGroupIteration_lower(_ value: GroupIteration) -> RustBuffer {
-    return FfiConverterTypeGroupIteration.lower(value)
-}
+fileprivate struct FfiConverterOptionTypeTokenMetaInput: FfiConverterRustBuffer {
+    typealias SwiftType = TokenMetaInput?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTokenMetaInput.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTokenMetaInput.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterOptionTypeNameSource: FfiConverterRustBuffer {
+    typealias SwiftType = NameSource?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -988,13 +3568,13 @@ fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
+        FfiConverterTypeNameSource.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
+        case 1: return try FfiConverterTypeNameSource.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -1025,6 +3605,31 @@ fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeAddressNameInput: FfiConverterRustBuffer {
+    typealias SwiftType = [AddressNameInput]
+
+    public static func write(_ value: [AddressNameInput], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeAddressNameInput.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [AddressNameInput] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [AddressNameInput]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeAddressNameInput.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -1050,6 +3655,56 @@ fileprivate struct FfiConverterSequenceTypeDisplayItem: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFieldWarning: FfiConverterRustBuffer {
+    typealias SwiftType = [FieldWarning]
+
+    public static func write(_ value: [FieldWarning], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFieldWarning.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FieldWarning] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FieldWarning]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFieldWarning.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRiskFinding: FfiConverterRustBuffer {
+    typealias SwiftType = [RiskFinding]
+
+    public static func write(_ value: [RiskFinding], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRiskFinding.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [RiskFinding] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [RiskFinding]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRiskFinding.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -1147,6 +3802,96 @@ fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: In
         print("uniffiFutureContinuationCallback invalid handle")
     }
 }
+private func uniffiTraitInterfaceCallAsync<T>(
+    makeCall: @escaping () async throws -> T,
+    handleSuccess: @escaping (T) -> (),
+    handleError: @escaping (Int8, RustBuffer) -> (),
+    droppedCallback: UnsafeMutablePointer<UniffiForeignFutureDroppedCallbackStruct>
+) {
+    let task = Task {
+        // Note: it's important we call either `handleSuccess` or `handleError` exactly once.  Each
+        // call consumes an Arc reference, which means there should be no possibility of a double
+        // call.  The following code is structured so that will will never call both `handleSuccess`
+        // and `handleError`, even in the face of weird errors.
+        //
+        // On platforms that need extra machinery to make C-ABI calls, like JNA or ctypes, it's
+        // possible that we fail to make either call.  However, it doesn't seem like this is
+        // possible on Swift since swift can just make the C call directly.
+        var callResult: T
+        do {
+            callResult = try await makeCall()
+        } catch {
+            handleError(CALL_UNEXPECTED_ERROR, FfiConverterString.lower(String(describing: error)))
+            return
+        }
+        handleSuccess(callResult)
+    }
+    let handle = UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.insert(obj: task)
+    droppedCallback.pointee = UniffiForeignFutureDroppedCallbackStruct(
+        handle: handle,
+        free: uniffiForeignFutureDroppedCallback
+    )
+}
+
+private func uniffiTraitInterfaceCallAsyncWithError<T, E>(
+    makeCall: @escaping () async throws -> T,
+    handleSuccess: @escaping (T) -> (),
+    handleError: @escaping (Int8, RustBuffer) -> (),
+    lowerError: @escaping (E) -> RustBuffer,
+    droppedCallback: UnsafeMutablePointer<UniffiForeignFutureDroppedCallbackStruct>
+) {
+    let task = Task {
+        // See the note in uniffiTraitInterfaceCallAsync for details on `handleSuccess` and
+        // `handleError`.
+        var callResult: T
+        do {
+            callResult = try await makeCall()
+        } catch let error as E {
+            handleError(CALL_ERROR, lowerError(error))
+            return
+        } catch {
+            handleError(CALL_UNEXPECTED_ERROR, FfiConverterString.lower(String(describing: error)))
+            return
+        }
+        handleSuccess(callResult)
+    }
+    let handle = UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.insert(obj: task)
+    droppedCallback.pointee = UniffiForeignFutureDroppedCallbackStruct(
+        handle: handle,
+        free: uniffiForeignFutureDroppedCallback
+    )
+}
+
+// Borrow the callback handle map implementation to store foreign future handles
+// TODO: consolidate the handle-map code (https://github.com/mozilla/uniffi-rs/pull/1823)
+fileprivate let UNIFFI_FOREIGN_FUTURE_HANDLE_MAP = UniffiHandleMap<UniffiForeignFutureTask>()
+
+// Protocol for tasks that handle foreign futures.
+//
+// Defining a protocol allows all tasks to be stored in the same handle map.  This can't be done
+// with the task object itself, since has generic parameters.
+fileprivate protocol UniffiForeignFutureTask {
+    func cancel()
+}
+
+extension Task: UniffiForeignFutureTask {}
+
+private func uniffiForeignFutureDroppedCallback(handle: UInt64) {
+    do {
+        let task = try UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.remove(handle: handle)
+        // Set the cancellation flag on the task.  If it's still running, the code can check the
+        // cancellation flag or call `Task.checkCancellation()`.  If the task has completed, this is
+        // a no-op.
+        task.cancel()
+    } catch {
+        print("uniffiForeignFutureDroppedCallback: handle missing from handlemap")
+    }
+}
+
+// For testing
+public func uniffiForeignFutureHandleCountErc7730() -> Int {
+    UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.count
+}
 /**
  * High-level: resolve descriptor from GitHub registry, then format calldata.
  *
@@ -1207,6 +3952,19 @@ public func erc7730FormatTypedData(descriptorJson: String, typedDataJson: String
     )
 })
 }
+/**
+ * Route library logs at `max_level` and above to `logger`; `None` silences them.
+ *
+ * Installs the bridge as the process-wide [`log`] logger on first use. If the host
+ * process already installed another Rust logger, records go to that one instead.
+ */
+public func erc7730SetLogger(logger: Logger?, maxLevel: LogLevel)  {try! rustCall() {
+    uniffi_erc7730_fn_func_erc7730_set_logger(
+        FfiConverterOptionTypeLogger.lower(logger),
+        FfiConverterTypeLogLevel_lower(maxLevel),$0
+    )
+}
+}
 
 private enum InitializationResult {
     case ok
@@ -1235,7 +3993,38 @@ private let initializationResult: InitializationResult = {
     if (uniffi_erc7730_checksum_func_erc7730_format_typed_data() != 31759) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_erc7730_checksum_func_erc7730_set_logger() != 31766) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_erc7730_checksum_method_addressnamecallback_address_name() != 34119) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_erc7730_checksum_method_descriptorsourcecallback_calldata_descriptor() != 59588) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_erc7730_checksum_method_descriptorsourcecallback_typed_descriptor() != 52493) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_erc7730_checksum_method_tokensourcecallback_token() != 57587) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_erc7730_checksum_method_logger_log() != 39147) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_erc7730_checksum_method_clearsigner_format_calldata() != 8792) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_erc7730_checksum_method_clearsigner_format_typed_data() != 56541) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_erc7730_checksum_constructor_clearsigner_new() != 7811) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
+    uniffiCallbackInitAddressNameCallback()
+    uniffiCallbackInitDescriptorSourceCallback()
+    uniffiCallbackInitLogger()
+    uniffiCallbackInitTokenSourceCallback()
     return InitializationResult.ok
 }()
 
@@ -242,6 +242,185 @@ typedef struct UniffiForeignFutureResultVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureResultVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_ADDRESS_NAME_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_ADDRESS_NAME_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceAddressNameCallbackMethod0)(uint64_t, uint64_t, RustBuffer, UniffiForeignFutureCompleteRustBuffer _Nonnull, uint64_t, UniffiForeignFutureDroppedCallbackStruct* _Nonnull
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_DESCRIPTOR_SOURCE_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_DESCRIPTOR_SOURCE_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceDescriptorSourceCallbackMethod0)(uint64_t, uint64_t, RustBuffer, UniffiForeignFutureCompleteRustBuffer _Nonnull, uint64_t, UniffiForeignFutureDroppedCallbackStruct* _Nonnull
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_DESCRIPTOR_SOURCE_CALLBACK_METHOD1
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_DESCRIPTOR_SOURCE_CALLBACK_METHOD1
+typedef void (*UniffiCallbackInterfaceDescriptorSourceCallbackMethod1)(uint64_t, uint64_t, RustBuffer, UniffiForeignFutureCompleteRustBuffer _Nonnull, uint64_t, UniffiForeignFutureDroppedCallbackStruct* _Nonnull
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_TOKEN_SOURCE_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_TOKEN_SOURCE_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceTokenSourceCallbackMethod0)(uint64_t, uint64_t, RustBuffer, RustBuffer* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_LOGGER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_LOGGER_METHOD0
+typedef void (*UniffiCallbackInterfaceLoggerMethod0)(uint64_t, RustBuffer, RustBuffer, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_ADDRESS_NAME_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_ADDRESS_NAME_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceAddressNameCallback {
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+    UniffiCallbackInterfaceClone _Nonnull uniffiClone;
+    UniffiCallbackInterfaceAddressNameCallbackMethod0 _Nonnull addressName;
+} UniffiVTableCallbackInterfaceAddressNameCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_DESCRIPTOR_SOURCE_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_DESCRIPTOR_SOURCE_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceDescriptorSourceCallback {
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+    UniffiCallbackInterfaceClone _Nonnull uniffiClone;
+    UniffiCallbackInterfaceDescriptorSourceCallbackMethod0 _Nonnull calldataDescriptor;
+    UniffiCallbackInterfaceDescriptorSourceCallbackMethod1 _Nonnull typedDescriptor;
+} UniffiVTableCallbackInterfaceDescriptorSourceCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_TOKEN_SOURCE_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_TOKEN_SOURCE_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceTokenSourceCallback {
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+    UniffiCallbackInterfaceClone _Nonnull uniffiClone;
+    UniffiCallbackInterfaceTokenSourceCallbackMethod0 _Nonnull token;
+} UniffiVTableCallbackInterfaceTokenSourceCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_LOGGER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_LOGGER
+typedef struct UniffiVTableCallbackInterfaceLogger {
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+    UniffiCallbackInterfaceClone _Nonnull uniffiClone;
+    UniffiCallbackInterfaceLoggerMethod0 _Nonnull log;
+} UniffiVTableCallbackInterfaceLogger;
+
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_ADDRESSNAMECALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_ADDRESSNAMECALLBACK
+uint64_t uniffi_erc7730_fn_clone_addressnamecallback(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_ADDRESSNAMECALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_ADDRESSNAMECALLBACK
+void uniffi_erc7730_fn_free_addressnamecallback(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_INIT_CALLBACK_VTABLE_ADDRESSNAMECALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_INIT_CALLBACK_VTABLE_ADDRESSNAMECALLBACK
+void uniffi_erc7730_fn_init_callback_vtable_addressnamecallback(const UniffiVTableCallbackInterfaceAddressNameCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_ADDRESSNAMECALLBACK_ADDRESS_NAME
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_ADDRESSNAMECALLBACK_ADDRESS_NAME
+uint64_t uniffi_erc7730_fn_method_addressnamecallback_address_name(uint64_t ptr, uint64_t chain_id, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_DESCRIPTORSOURCECALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_DESCRIPTORSOURCECALLBACK
+uint64_t uniffi_erc7730_fn_clone_descriptorsourcecallback(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_DESCRIPTORSOURCECALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_DESCRIPTORSOURCECALLBACK
+void uniffi_erc7730_fn_free_descriptorsourcecallback(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_INIT_CALLBACK_VTABLE_DESCRIPTORSOURCECALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_INIT_CALLBACK_VTABLE_DESCRIPTORSOURCECALLBACK
+void uniffi_erc7730_fn_init_callback_vtable_descriptorsourcecallback(const UniffiVTableCallbackInterfaceDescriptorSourceCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_DESCRIPTORSOURCECALLBACK_CALLDATA_DESCRIPTOR
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_DESCRIPTORSOURCECALLBACK_CALLDATA_DESCRIPTOR
+uint64_t uniffi_erc7730_fn_method_descriptorsourcecallback_calldata_descriptor(uint64_t ptr, uint64_t chain_id, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_DESCRIPTORSOURCECALLBACK_TYPED_DESCRIPTOR
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_DESCRIPTORSOURCECALLBACK_TYPED_DESCRIPTOR
+uint64_t uniffi_erc7730_fn_method_descriptorsourcecallback_typed_descriptor(uint64_t ptr, uint64_t chain_id, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_TOKENSOURCECALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_TOKENSOURCECALLBACK
+uint64_t uniffi_erc7730_fn_clone_tokensourcecallback(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_TOKENSOURCECALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_TOKENSOURCECALLBACK
+void uniffi_erc7730_fn_free_tokensourcecallback(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_INIT_CALLBACK_VTABLE_TOKENSOURCECALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_INIT_CALLBACK_VTABLE_TOKENSOURCECALLBACK
+void uniffi_erc7730_fn_init_callback_vtable_tokensourcecallback(const UniffiVTableCallbackInterfaceTokenSourceCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_TOKENSOURCECALLBACK_TOKEN
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_TOKENSOURCECALLBACK_TOKEN
+RustBuffer uniffi_erc7730_fn_method_tokensourcecallback_token(uint64_t ptr, uint64_t chain_id, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_LOGGER
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_LOGGER
+uint64_t uniffi_erc7730_fn_clone_logger(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_LOGGER
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_LOGGER
+void uniffi_erc7730_fn_free_logger(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_INIT_CALLBACK_VTABLE_LOGGER
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_INIT_CALLBACK_VTABLE_LOGGER
+void uniffi_erc7730_fn_init_callback_vtable_logger(const UniffiVTableCallbackInterfaceLogger* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_LOGGER_LOG
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_LOGGER_LOG
+void uniffi_erc7730_fn_method_logger_log(uint64_t ptr, RustBuffer level, RustBuffer target, RustBuffer message, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_CLEARSIGNER
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CLONE_CLEARSIGNER
+uint64_t uniffi_erc7730_fn_clone_clearsigner(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_CLEARSIGNER
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FREE_CLEARSIGNER
+void uniffi_erc7730_fn_free_clearsigner(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CONSTRUCTOR_CLEARSIGNER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_CONSTRUCTOR_CLEARSIGNER_NEW
+uint64_t uniffi_erc7730_fn_constructor_clearsigner_new(RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_CLEARSIGNER_FORMAT_CALLDATA
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_CLEARSIGNER_FORMAT_CALLDATA
+uint64_t uniffi_erc7730_fn_method_clearsigner_format_calldata(uint64_t ptr, uint64_t chain_id, RustBuffer to, RustBuffer calldata_hex, RustBuffer value_hex, RustBuffer from_address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_CLEARSIGNER_FORMAT_TYPED_DATA
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_METHOD_CLEARSIGNER_FORMAT_TYPED_DATA
+uint64_t uniffi_erc7730_fn_method_clearsigner_format_typed_data(uint64_t ptr, RustBuffer typed_data_json
+);
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FUNC_ERC7730_FORMAT
 #define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FUNC_ERC7730_FORMAT
@@ -263,6 +442,11 @@ uint64_t uniffi_erc7730_fn_func_erc7730_format_typed(RustBuffer typed_data_json,
 RustBuffer uniffi_erc7730_fn_func_erc7730_format_typed_data(RustBuffer descriptor_json, RustBuffer typed_data_json, RustBuffer tokens, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FUNC_ERC7730_SET_LOGGER
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_FN_FUNC_ERC7730_SET_LOGGER
+void uniffi_erc7730_fn_func_erc7730_set_logger(RustBuffer logger, RustBuffer max_level, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_FFI_ERC7730_RUSTBUFFER_ALLOC
 #define UNIFFI_FFIDEF_FFI_ERC7730_RUSTBUFFER_ALLOC
 RustBuffer ffi_erc7730_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
@@ -545,6 +729,60 @@ uint16_t uniffi_erc7730_checksum_func_erc7730_format_typed(void
 #define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_FUNC_ERC7730_FORMAT_TYPED_DATA
 uint16_t uniffi_erc7730_checksum_func_erc7730_format_typed_data(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_FUNC_ERC7730_SET_LOGGER
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_FUNC_ERC7730_SET_LOGGER
+uint16_t uniffi_erc7730_checksum_func_erc7730_set_logger(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_ADDRESSNAMECALLBACK_ADDRESS_NAME
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_ADDRESSNAMECALLBACK_ADDRESS_NAME
+uint16_t uniffi_erc7730_checksum_method_addressnamecallback_address_name(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_DESCRIPTORSOURCECALLBACK_CALLDATA_DESCRIPTOR
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_DESCRIPTORSOURCECALLBACK_CALLDATA_DESCRIPTOR
+uint16_t uniffi_erc7730_checksum_method_descriptorsourcecallback_calldata_descriptor(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_DESCRIPTORSOURCECALLBACK_TYPED_DESCRIPTOR
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_DESCRIPTORSOURCECALLBACK_TYPED_DESCRIPTOR
+uint16_t uniffi_erc7730_checksum_method_descriptorsourcecallback_typed_descriptor(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_TOKENSOURCECALLBACK_TOKEN
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_TOKENSOURCECALLBACK_TOKEN
+uint16_t uniffi_erc7730_checksum_method_tokensourcecallback_token(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_LOGGER_LOG
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_LOGGER_LOG
+uint16_t uniffi_erc7730_checksum_method_logger_log(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_CLEARSIGNER_FORMAT_CALLDATA
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_CLEARSIGNER_FORMAT_CALLDATA
+uint16_t uniffi_erc7730_checksum_method_clearsigner_format_calldata(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_CLEARSIGNER_FORMAT_TYPED_DATA
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_METHOD_CLEARSIGNER_FORMAT_TYPED_DATA
+uint16_t uniffi_erc7730_checksum_method_clearsigner_format_typed_data(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_CONSTRUCTOR_CLEARSIGNER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ERC7730_CHECKSUM_CONSTRUCTOR_CLEARSIGNER_NEW
+uint16_t uniffi_erc7730_checksum_constructor_clearsigner_new(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_ERC7730_UNIFFI_CONTRACT_VERSION
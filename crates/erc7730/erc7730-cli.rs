@@ -0,0 +1,162 @@
+//! Command-line tools for ERC-7730 descriptors.
+//!
+//! `erc7730-cli compact` compiles descriptor formats to the compact binary form
+//! (see `erc7730::compact`) and reports how large they are.
+
+use std::path::Path;
+use std::process::ExitCode;
+
+use erc7730::compact::CompactDescriptor;
+use erc7730::resolver::load_descriptor;
+use erc7730::{Descriptor, TokenMeta};
+
+const USAGE: &str = "usage: erc7730-cli compact <descriptor.json> [<format>] [options]
+
+Reports the size of the compact binary encoding of <format>, or of every
+format in the descriptor when <format> is omitted.
+
+options:
+  --chain <id>        keep only deployments and tokens on this chain
+  --token <chain>:<address>:<symbol>:<decimals>[:<name>]
+                      token metadata to embed (repeatable)
+  --out <file>        write the encoded <format> to <file>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("compact") => compact(&args[1..]),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+struct CompactArgs {
+    descriptor: String,
+    format: Option<String>,
+    chain: Option<u64>,
+    tokens: Vec<(u64, String, TokenMeta)>,
+    out: Option<String>,
+}
+
+fn parse_compact_args(args: &[String]) -> Result<CompactArgs, String> {
+    let mut positional = Vec::new();
+    let mut parsed = CompactArgs {
+        descriptor: String::new(),
+        format: None,
+        chain: None,
+        tokens: Vec::new(),
+        out: None,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("{arg} needs a value"))
+        };
+        match arg.as_str() {
+            "--chain" => {
+                let chain = value()?;
+                parsed.chain = Some(chain.parse().map_err(|_| format!("bad chain id {chain}"))?);
+            }
+            "--token" => parsed.tokens.push(parse_token(&value()?)?),
+            "--out" => parsed.out = Some(value()?),
+            flag if flag.starts_with("--") => return Err(format!("unknown option {flag}")),
+            _ => positional.push(arg.clone()),
+        }
+    }
+    let mut positional = positional.into_iter();
+    parsed.descriptor = positional.next().ok_or(USAGE)?;
+    parsed.format = positional.next();
+    if positional.next().is_some() {
+        return Err(USAGE.to_string());
+    }
+    if parsed.out.is_some() && parsed.format.is_none() {
+        return Err("--out needs a <format>".to_string());
+    }
+    Ok(parsed)
+}
+
+/// `<chain>:<address>:<symbol>:<decimals>[:<name>]`; the name defaults to the symbol.
+fn parse_token(spec: &str) -> Result<(u64, String, TokenMeta), String> {
+    let bad =
+        || format!("bad token {spec}, expected <chain>:<address>:<symbol>:<decimals>[:<name>]");
+    let parts: Vec<&str> = spec.splitn(5, ':').collect();
+    let [chain, address, symbol, decimals, rest @ ..] = parts.as_slice() else {
+        return Err(bad());
+    };
+    let meta = TokenMeta {
+        symbol: symbol.to_string(),
+        decimals: decimals.parse().map_err(|_| bad())?,
+        name: rest.first().unwrap_or(symbol).to_string(),
+        logo_uri: None,
+    };
+    Ok((chain.parse().map_err(|_| bad())?, address.to_string(), meta))
+}
+
+fn compact(args: &[String]) -> Result<(), String> {
+    let args = parse_compact_args(args)?;
+    let descriptor = load_descriptor(Path::new(&args.descriptor)).map_err(|e| e.to_string())?;
+    let json_size = serde_json::to_vec(&descriptor)
+        .map_err(|e| e.to_string())?
+        .len();
+
+    let Some(format) = &args.format else {
+        let mut keys: Vec<&String> = descriptor.display.formats.keys().collect();
+        keys.sort();
+        println!("descriptor  {json_size} bytes (minified JSON)");
+        println!("{:>8}  {:>6}  format", "compact", "ratio");
+        for key in keys {
+            let size = build(&descriptor, key, &args)?.encode().len();
+            println!("{size:>8}  {:>6}  {key}", percent(size, json_size));
+        }
+        return Ok(());
+    };
+
+    let compiled = build(&descriptor, format, &args)?;
+    let bytes = compiled.encode();
+    println!("format      {}", compiled.format_key);
+    println!("descriptor  {json_size} bytes (minified JSON)");
+    println!(
+        "compact     {} bytes ({} of the descriptor)",
+        bytes.len(),
+        percent(bytes.len(), json_size)
+    );
+    for (section, size) in compiled.section_sizes() {
+        println!("  {section:<9} {size:>6}");
+    }
+    if let Some(out) = &args.out {
+        std::fs::write(out, &bytes).map_err(|e| format!("write {out}: {e}"))?;
+        println!("wrote {out}");
+    }
+    Ok(())
+}
+
+fn build(
+    descriptor: &Descriptor,
+    format: &str,
+    args: &CompactArgs,
+) -> Result<CompactDescriptor, String> {
+    let mut compiled = CompactDescriptor::compile(descriptor, format).map_err(|e| e.to_string())?;
+    for (chain_id, address, meta) in &args.tokens {
+        compiled.insert_token(*chain_id, address, meta.clone());
+    }
+    if let Some(chain_id) = args.chain {
+        compiled.retain_chain(chain_id);
+    }
+    Ok(compiled)
+}
+
+fn percent(part: usize, whole: usize) -> String {
+    format!("{:.1}%", part as f64 * 100.0 / whole.max(1) as f64)
+}
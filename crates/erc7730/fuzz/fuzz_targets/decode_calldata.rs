@@ -0,0 +1,38 @@
+#![no_main]
+
+//! Input: a signature, a newline, then the calldata after the selector.
+
+use erc7730::decoder::{
+    decode_calldata, decode_calldata_with_mode, encode_arguments, parse_signature, DecodeMode,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some(newline) = data.iter().position(|b| *b == b'\n') else {
+        return;
+    };
+    let Ok(sig) = core::str::from_utf8(&data[..newline]) else {
+        return;
+    };
+    let Ok(sig) = parse_signature(sig) else {
+        return;
+    };
+    let args = &data[newline + 1..];
+    let mut calldata = sig.selector.to_vec();
+    calldata.extend_from_slice(args);
+
+    let lenient = decode_calldata(&sig, &calldata);
+    let Ok(strict) = decode_calldata_with_mode(&sig, &calldata, DecodeMode::Strict) else {
+        return;
+    };
+    // Canonical calldata decodes the same either way and re-encodes to itself
+    assert!(strict.warnings.is_empty());
+    let lenient = lenient.expect("strict decoding succeeded");
+    assert!(lenient.warnings.is_empty());
+    let values: Vec<_> = strict.args.into_iter().map(|arg| arg.value).collect();
+    let lenient_values: Vec<_> = lenient.args.into_iter().map(|arg| arg.value).collect();
+    assert_eq!(values, lenient_values);
+    if let Ok(encoded) = encode_arguments(&sig.params, &values) {
+        assert_eq!(encoded, args);
+    }
+});
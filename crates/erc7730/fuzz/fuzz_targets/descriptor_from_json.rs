@@ -0,0 +1,17 @@
+#![no_main]
+
+use erc7730::Descriptor;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(json) = core::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(descriptor) = Descriptor::from_json(json) else {
+        return;
+    };
+    let _ = descriptor.validate();
+    // Whatever parses serializes and parses again
+    let json = descriptor.to_json().expect("descriptor serializes");
+    Descriptor::from_json(&json).expect("serialized descriptor parses");
+});
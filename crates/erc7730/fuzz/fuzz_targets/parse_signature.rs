@@ -0,0 +1,17 @@
+#![no_main]
+
+use erc7730::decoder::parse_signature;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(sig) = core::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(parsed) = parse_signature(sig) else {
+        return;
+    };
+    // The canonical form parses back to the same signature
+    let reparsed = parse_signature(&parsed.canonical).expect("canonical signature parses");
+    assert_eq!(reparsed.params, parsed.params);
+    assert_eq!(reparsed.selector, parsed.selector);
+});
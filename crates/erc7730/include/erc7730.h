@@ -0,0 +1,93 @@
+#ifndef ERC7730_H
+#define ERC7730_H
+
+/* Generated by cbindgen from src/capi.rs; do not edit. Regenerate with scripts/generate-c-header.sh. */
+
+#include <stdint.h>
+
+// Opaque clear-signing engine: a descriptor index plus the built-in token list.
+typedef struct Erc7730Engine Erc7730Engine;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Create an engine from descriptor JSON: one descriptor object, or an array of
+// them. Descriptors are indexed by their deployments.
+//
+// Returns `NULL` on error. Free the engine with [`erc7730_engine_free`].
+//
+// # Safety
+//
+// `descriptor_json` must be `NULL` or a valid NUL-terminated string.
+struct Erc7730Engine *erc7730_engine_from_json(const char *descriptor_json);
+
+// Create an engine from every descriptor JSON file under `path`, recursively.
+//
+// Returns `NULL` on error. Free the engine with [`erc7730_engine_free`].
+//
+// # Safety
+//
+// `path` must be `NULL` or a valid NUL-terminated string.
+struct Erc7730Engine *erc7730_engine_from_directory(const char *path);
+
+// Free an engine. `NULL` is ignored.
+//
+// # Safety
+//
+// `engine` must be `NULL` or a handle returned by an `erc7730_engine_*`
+// constructor that has not been freed yet.
+void erc7730_engine_free(struct Erc7730Engine *engine);
+
+// Format contract calldata into a display model JSON string.
+//
+// `calldata_hex` and `value_hex` are hex with or without `0x`; `value_hex` and
+// `from` may be `NULL`. Without a matching descriptor, standard token calls use
+// the built-in templates and anything else a raw preview.
+//
+// Returns `NULL` on error. Free the string with [`erc7730_string_free`].
+//
+// # Safety
+//
+// `engine` must be a live engine handle, and every string argument `NULL` or a
+// valid NUL-terminated string.
+char *erc7730_format_calldata(const struct Erc7730Engine *engine,
+                              uint64_t chain_id,
+                              const char *to,
+                              const char *calldata_hex,
+                              const char *value_hex,
+                              const char *from);
+
+// Format EIP-712 typed data (JSON) into a display model JSON string.
+//
+// Without a matching descriptor, Permit2 signatures use the built-in formatter
+// and anything else a raw preview.
+//
+// Returns `NULL` on error. Free the string with [`erc7730_string_free`].
+//
+// # Safety
+//
+// `engine` must be a live engine handle, and `typed_data_json` `NULL` or a
+// valid NUL-terminated string.
+char *erc7730_format_typed_data(const struct Erc7730Engine *engine, const char *typed_data_json);
+
+// Free a string returned by this library. `NULL` is ignored.
+//
+// # Safety
+//
+// `string` must be `NULL` or a string returned by an `erc7730_format_*`
+// function that has not been freed yet.
+void erc7730_string_free(char *string);
+
+// Message of the last error on the calling thread, or `NULL` if the last call
+// succeeded.
+//
+// The string is owned by the library and stays valid until the next call into
+// it on the same thread; do not free it.
+const char *erc7730_last_error(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* ERC7730_H */
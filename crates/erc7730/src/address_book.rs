@@ -1,7 +1,6 @@
 //! Address-to-label resolution from descriptor metadata and deployment context.
 
-use std::collections::HashMap;
-
+use crate::prelude::*;
 use crate::types::context::DescriptorContext;
 use crate::types::metadata::Metadata;
 
@@ -20,14 +19,17 @@ impl AddressBook {
         // Add deployment addresses with contract name as label
         if let Some(ref name) = metadata.contract_name {
             for deployment in context.deployments() {
-                let addr = deployment.address.to_lowercase();
-                entries.insert(addr, name.clone());
+                if let Some(addr) = normalize(&deployment.address) {
+                    entries.insert(addr, name.clone());
+                }
             }
         }
 
         // Merge metadata address book entries
         for (addr, label) in &metadata.address_book {
-            entries.insert(addr.to_lowercase(), label.clone());
+            if let Some(addr) = normalize(addr) {
+                entries.insert(addr, label.clone());
+            }
         }
 
         Self { entries }
@@ -42,14 +44,45 @@ impl AddressBook {
 
     /// Look up a label for an address.
     pub fn resolve(&self, address: &str) -> Option<&str> {
-        self.entries
-            .get(&address.to_lowercase())
-            .map(|s| s.as_str())
+        self.entries.get(&normalize(address)?).map(|s| s.as_str())
     }
 
-    /// Add or override an entry.
-    pub fn insert(&mut self, address: String, label: String) {
-        self.entries.insert(address.to_lowercase(), label);
+    /// Add or override an entry. Returns `false`, leaving the book unchanged,
+    /// when `address` is not 40 hex characters with a `0x` prefix.
+    pub fn insert(&mut self, address: String, label: String) -> bool {
+        match normalize(&address) {
+            Some(address) => {
+                self.entries.insert(address, label);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Find a different address sharing the first and last `chars` hex characters
+    /// with `address` — the pattern used by address-poisoning vanity addresses.
+    ///
+    /// Returns the matching `(address, label)`; ties resolve to the lowest address.
+    /// `chars` below 1 is treated as 1.
+    pub fn find_lookalike(&self, address: &str, chars: usize) -> Option<(&str, &str)> {
+        let chars = chars.max(1);
+        let target = normalize(address)?;
+        let target_hex = &target[2..];
+        if target_hex.len() < chars * 2 {
+            return None;
+        }
+        self.entries
+            .iter()
+            .filter(|(known, _)| {
+                // Entries are normalized on insertion, so the slices are in bounds
+                let known_hex = &known[2..];
+                known_hex != target_hex
+                    && known_hex[..chars] == target_hex[..chars]
+                    && known_hex[known_hex.len() - chars..]
+                        == target_hex[target_hex.len() - chars..]
+            })
+            .min_by(|a, b| a.0.cmp(b.0))
+            .map(|(known, label)| (known.as_str(), label.as_str()))
     }
 
     /// Merge entries from another address book.
@@ -62,6 +95,15 @@ impl AddressBook {
     }
 }
 
+/// Lowercase `0x`-prefixed form of a 20-byte hex address, or `None` if it is not one.
+fn normalize(address: &str) -> Option<String> {
+    let hex_part = address
+        .strip_prefix("0x")
+        .or_else(|| address.strip_prefix("0X"))?;
+    (hex_part.len() == 40 && hex_part.bytes().all(|b| b.is_ascii_hexdigit()))
+        .then(|| format!("0x{}", hex_part.to_ascii_lowercase()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,17 +125,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_lookalike() {
+        let mut book = AddressBook::empty();
+        book.insert(
+            "0xd8dA6BF26964aF9D7eEd9e10C46273d7B3b2C03A".to_string(),
+            "Vitalik".to_string(),
+        );
+        let poisoned = "0xd8da000000000000000000000000000000b2c03a";
+        assert_eq!(
+            book.find_lookalike(poisoned, 4),
+            Some(("0xd8da6bf26964af9d7eed9e10c46273d7b3b2c03a", "Vitalik"))
+        );
+        // The known address itself and unrelated addresses are not look-alikes
+        assert!(book
+            .find_lookalike("0xD8DA6BF26964AF9D7EED9E10C46273D7B3B2C03A", 4)
+            .is_none());
+        assert!(book
+            .find_lookalike("0xd8da00000000000000000000000000000000c03b", 4)
+            .is_none());
+        assert!(book.find_lookalike(poisoned, 6).is_none());
+    }
+
+    #[test]
+    fn test_find_lookalike_with_invalid_input() {
+        let mut book = AddressBook::empty();
+        // Short and non-ASCII entries are rejected rather than stored
+        assert!(!book.insert("0xabc".to_string(), "Short".to_string()));
+        assert!(!book.insert(format!("0x{}é", "a".repeat(39)), "Accented".to_string()));
+        assert!(book.insert(
+            "0xd8dA6BF26964aF9D7eEd9e10C46273d7B3b2C03A".to_string(),
+            "Vitalik".to_string(),
+        ));
+        assert!(book.resolve("0xabc").is_none());
+        assert!(book
+            .find_lookalike(&format!("0xd8{}é", "0".repeat(37)), 4)
+            .is_none());
+
+        // Zero characters is treated as one, not as "every address"
+        assert!(book
+            .find_lookalike("0x1000000000000000000000000000000000000000", 0)
+            .is_none());
+        assert_eq!(
+            book.find_lookalike("0xd00000000000000000000000000000000000000a", 0),
+            Some(("0xd8da6bf26964af9d7eed9e10c46273d7b3b2c03a", "Vitalik"))
+        );
+    }
+
     #[test]
     fn test_merge_no_overwrite() {
+        let a = "0x00000000000000000000000000000000000000aa";
+        let b = "0x00000000000000000000000000000000000000bb";
         let mut book1 = AddressBook::empty();
-        book1.insert("0xabc".to_string(), "Original".to_string());
+        book1.insert(a.to_string(), "Original".to_string());
 
         let mut book2 = AddressBook::empty();
-        book2.insert("0xabc".to_string(), "Override".to_string());
-        book2.insert("0xdef".to_string(), "New".to_string());
+        book2.insert(a.to_string(), "Override".to_string());
+        book2.insert(b.to_string(), "New".to_string());
 
         book1.merge(&book2);
-        assert_eq!(book1.resolve("0xabc"), Some("Original"));
-        assert_eq!(book1.resolve("0xdef"), Some("New"));
+        assert_eq!(book1.resolve(a), Some("Original"));
+        assert_eq!(book1.resolve(b), Some("New"));
     }
 }
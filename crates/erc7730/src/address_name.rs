@@ -0,0 +1,445 @@
+//! External address names (contacts, trusted lists, ENS) via the [`AddressNameSource`]
+//! trait. Every name records its [`NameSource`] so wallets can show how far to trust it.
+
+use core::future::Future;
+use core::pin::Pin;
+
+use serde::Serialize;
+use tiny_keccak::{Hasher, Keccak};
+
+use crate::error::ResolveError;
+use crate::prelude::*;
+use crate::rpc::{decode_string_return, EthCallTransport};
+use crate::types::display::AddressType;
+
+/// ENS registry, deployed at the same address on Ethereum mainnet and testnets.
+pub const ENS_REGISTRY: &str = "0x00000000000c2e074ec69a0dfb2997ba6c7d2e1e";
+
+/// Selector of the registry's `resolver(bytes32)`.
+const RESOLVER_SELECTOR: [u8; 4] = [0x01, 0x78, 0xb8, 0xbf];
+/// Selector of the resolver's `name(bytes32)`.
+const NAME_SELECTOR: [u8; 4] = [0x69, 0x1f, 0x34, 0x31];
+/// Selector of the resolver's `addr(bytes32)`.
+const ADDR_SELECTOR: [u8; 4] = [0x3b, 0x3b, 0x57, 0xde];
+
+/// Where an address name came from, from most to least curated.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NameSource {
+    /// The descriptor's address book or deployments.
+    Descriptor,
+    /// The user's own contacts.
+    Contact,
+    /// A trusted name list supplied by the wallet.
+    Trusted,
+    /// ENS reverse resolution, verified against forward resolution.
+    Ens,
+}
+
+impl NameSource {
+    /// Key matched against the `addressName` format's `sources` param.
+    pub fn key(&self) -> &'static str {
+        match self {
+            NameSource::Descriptor => "descriptor",
+            NameSource::Contact => "local",
+            NameSource::Trusted => "trusted",
+            NameSource::Ens => "ens",
+        }
+    }
+}
+
+/// A display name for an address and its provenance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressName {
+    pub name: String,
+    pub source: NameSource,
+    /// Kind of the named address, when the source knows it.
+    pub kind: Option<AddressType>,
+}
+
+/// Trait for resolving display names for addresses.
+pub trait AddressNameSource: Send + Sync {
+    /// Return the name of `address` (lowercase `0x`-prefixed) on `chain_id`, if any.
+    fn resolve_name<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AddressName>, ResolveError>> + Send + 'a>>;
+}
+
+/// Composite name source that tries each source in order, returning the first match.
+///
+/// A failing source does not hide names from later ones; its error is only
+/// returned when no source has a name.
+pub struct CompositeAddressNameSource {
+    sources: Vec<Box<dyn AddressNameSource>>,
+}
+
+impl CompositeAddressNameSource {
+    pub fn new(sources: Vec<Box<dyn AddressNameSource>>) -> Self {
+        Self { sources }
+    }
+}
+
+impl AddressNameSource for CompositeAddressNameSource {
+    fn resolve_name<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AddressName>, ResolveError>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut first_error = None;
+            for source in &self.sources {
+                match source.resolve_name(chain_id, address).await {
+                    Ok(Some(name)) => return Ok(Some(name)),
+                    Ok(None) => {}
+                    Err(e) => {
+                        first_error.get_or_insert(e);
+                    }
+                }
+            }
+            first_error.map_or(Ok(None), Err)
+        })
+    }
+}
+
+/// In-memory per-chain names, e.g. a wallet's trusted name list.
+#[derive(Debug, Clone)]
+pub struct StaticAddressNameSource {
+    source: NameSource,
+    names: HashMap<String, (String, Option<AddressType>)>,
+}
+
+impl StaticAddressNameSource {
+    /// Create an empty list whose names are reported as [`NameSource::Trusted`].
+    pub fn new() -> Self {
+        Self::with_source(NameSource::Trusted)
+    }
+
+    /// Create an empty list whose names are reported as coming from `source`.
+    pub fn with_source(source: NameSource) -> Self {
+        Self {
+            source,
+            names: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, chain_id: u64, address: &str, name: &str) {
+        self.names.insert(
+            format!("{chain_id}:{}", address.to_lowercase()),
+            (name.to_string(), None),
+        );
+    }
+
+    /// Add a name whose address kind is known.
+    pub fn insert_typed(&mut self, chain_id: u64, address: &str, name: &str, kind: AddressType) {
+        self.names.insert(
+            format!("{chain_id}:{}", address.to_lowercase()),
+            (name.to_string(), Some(kind)),
+        );
+    }
+}
+
+impl Default for StaticAddressNameSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AddressNameSource for StaticAddressNameSource {
+    fn resolve_name<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AddressName>, ResolveError>> + Send + 'a>> {
+        let name = self
+            .names
+            .get(&format!("{chain_id}:{}", address.to_lowercase()))
+            .map(|(name, kind)| AddressName {
+                name: name.clone(),
+                source: self.source,
+                kind: *kind,
+            });
+        Box::pin(async move { Ok(name) })
+    }
+}
+
+/// The user's contacts. Contacts are chain-agnostic: a saved address is named on every chain.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsAddressNameSource {
+    contacts: HashMap<String, String>,
+}
+
+impl ContactsAddressNameSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, address: &str, name: &str) {
+        self.contacts
+            .insert(address.to_lowercase(), name.to_string());
+    }
+}
+
+impl AddressNameSource for ContactsAddressNameSource {
+    fn resolve_name<'a>(
+        &'a self,
+        _chain_id: u64,
+        address: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AddressName>, ResolveError>> + Send + 'a>> {
+        let name = self
+            .contacts
+            .get(&address.to_lowercase())
+            .map(|name| AddressName {
+                name: name.clone(),
+                source: NameSource::Contact,
+                kind: None,
+            });
+        Box::pin(async move { Ok(name) })
+    }
+}
+
+/// ENS primary names, read from Ethereum mainnet through an [`EthCallTransport`].
+///
+/// The reverse record (`{addr}.addr.reverse`) is only trusted when the name's
+/// forward resolution points back at the same address, since anyone can set a
+/// reverse record to any name.
+pub struct EnsAddressNameSource {
+    transport: Box<dyn EthCallTransport>,
+    chain_id: u64,
+}
+
+impl EnsAddressNameSource {
+    pub fn new(transport: Box<dyn EthCallTransport>) -> Self {
+        Self {
+            transport,
+            chain_id: 1,
+        }
+    }
+
+    /// Resolve on another chain hosting the ENS registry (e.g. Sepolia).
+    pub fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    async fn reverse(&self, address: &str) -> Result<Option<String>, ResolveError> {
+        let node = namehash(&format!(
+            "{}.addr.reverse",
+            address.trim_start_matches("0x")
+        ));
+        let Some(resolver) = self.resolver(&node).await? else {
+            return Ok(None);
+        };
+        let output = self.call(&resolver, NAME_SELECTOR, &node).await?;
+        Ok(decode_string_return(&output).filter(|name| !name.is_empty()))
+    }
+
+    async fn forward(&self, name: &str) -> Result<Option<String>, ResolveError> {
+        let node = namehash(name);
+        let Some(resolver) = self.resolver(&node).await? else {
+            return Ok(None);
+        };
+        let output = self.call(&resolver, ADDR_SELECTOR, &node).await?;
+        Ok(decode_address_word(&output))
+    }
+
+    async fn resolver(&self, node: &[u8; 32]) -> Result<Option<String>, ResolveError> {
+        let output = self.call(ENS_REGISTRY, RESOLVER_SELECTOR, node).await?;
+        Ok(decode_address_word(&output))
+    }
+
+    async fn call(
+        &self,
+        to: &str,
+        selector: [u8; 4],
+        node: &[u8; 32],
+    ) -> Result<Vec<u8>, ResolveError> {
+        let mut data = selector.to_vec();
+        data.extend_from_slice(node);
+        self.transport.eth_call(self.chain_id, to, data).await
+    }
+}
+
+impl AddressNameSource for EnsAddressNameSource {
+    fn resolve_name<'a>(
+        &'a self,
+        _chain_id: u64,
+        address: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AddressName>, ResolveError>> + Send + 'a>> {
+        Box::pin(async move {
+            let address = address.to_lowercase();
+            let Some(name) = self.reverse(&address).await? else {
+                return Ok(None);
+            };
+            if self.forward(&name).await?.as_deref() != Some(address.as_str()) {
+                return Ok(None);
+            }
+            Ok(Some(AddressName {
+                name,
+                source: NameSource::Ens,
+                kind: None,
+            }))
+        })
+    }
+}
+
+/// ENS namehash of a dot-separated name.
+pub fn namehash(name: &str) -> [u8; 32] {
+    let mut node = [0u8; 32];
+    if name.is_empty() {
+        return node;
+    }
+    for label in name.rsplit('.') {
+        let label_hash = keccak256(label.as_bytes());
+        let mut buf = [0u8; 64];
+        buf[..32].copy_from_slice(&node);
+        buf[32..].copy_from_slice(&label_hash);
+        node = keccak256(&buf);
+    }
+    node
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    hash
+}
+
+/// Decode an ABI `address` word; `None` for short output or the zero address.
+fn decode_address_word(output: &[u8]) -> Option<String> {
+    let word = output.get(..32)?;
+    if word[..12].iter().any(|b| *b != 0) || word[12..].iter().all(|b| *b == 0) {
+        return None;
+    }
+    Some(format!("0x{}", hex::encode(&word[12..])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::{encode_string_return, StaticEthCallTransport};
+
+    const VITALIK: &str = "0xd8da6bf26964af9d7eed9e10c46273d7b3b2c03a";
+    const RESOLVER: &str = "0x231b0ee14048e9dccd1d247744d114a4eb5e8e63";
+
+    fn address_word(address: &str) -> Vec<u8> {
+        let mut word = vec![0u8; 12];
+        word.extend(hex::decode(address.trim_start_matches("0x")).unwrap());
+        word
+    }
+
+    fn calldata(selector: [u8; 4], node: [u8; 32]) -> Vec<u8> {
+        let mut data = selector.to_vec();
+        data.extend_from_slice(&node);
+        data
+    }
+
+    /// ENS state for `address` with reverse record `name`, whose forward record is `forward`.
+    fn ens_transport(address: &str, name: &str, forward: &str) -> StaticEthCallTransport {
+        let reverse_node = namehash(&format!("{}.addr.reverse", &address[2..]));
+        let forward_node = namehash(name);
+        let mut transport = StaticEthCallTransport::new();
+        for node in [reverse_node, forward_node] {
+            transport.insert(
+                1,
+                ENS_REGISTRY,
+                &calldata(RESOLVER_SELECTOR, node),
+                address_word(RESOLVER),
+            );
+        }
+        transport.insert(
+            1,
+            RESOLVER,
+            &calldata(NAME_SELECTOR, reverse_node),
+            encode_string_return(name),
+        );
+        transport.insert(
+            1,
+            RESOLVER,
+            &calldata(ADDR_SELECTOR, forward_node),
+            address_word(forward),
+        );
+        transport
+    }
+
+    #[test]
+    fn test_namehash() {
+        assert_eq!(namehash(""), [0u8; 32]);
+        assert_eq!(
+            hex::encode(namehash("eth")),
+            "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"
+        );
+        assert_eq!(
+            hex::encode(namehash("foo.eth")),
+            "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ens_reverse_requires_forward_match() {
+        let ens =
+            EnsAddressNameSource::new(Box::new(ens_transport(VITALIK, "vitalik.eth", VITALIK)));
+        let name = ens.resolve_name(10, VITALIK).await.unwrap().unwrap();
+        assert_eq!(name.name, "vitalik.eth");
+        assert_eq!(name.source, NameSource::Ens);
+
+        // A reverse record claiming a name that resolves elsewhere is ignored
+        let spoofed = EnsAddressNameSource::new(Box::new(ens_transport(
+            VITALIK,
+            "vitalik.eth",
+            "0x0000000000000000000000000000000000000bad",
+        )));
+        assert!(spoofed.resolve_name(1, VITALIK).await.unwrap().is_none());
+
+        let empty = EnsAddressNameSource::new(Box::new(StaticEthCallTransport::new()));
+        assert!(empty.resolve_name(1, VITALIK).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_composite_prefers_earlier_sources() {
+        let mut contacts = ContactsAddressNameSource::new();
+        contacts.insert(
+            "0xd8dA6BF26964aF9D7eEd9e10C46273d7B3b2C03A",
+            "Vitalik (work)",
+        );
+        let mut trusted = StaticAddressNameSource::new();
+        trusted.insert(1, VITALIK, "Vitalik Buterin");
+        trusted.insert_typed(
+            1,
+            "0x00000000000000000000000000000000000000aa",
+            "Treasury",
+            AddressType::Wallet,
+        );
+        let source = CompositeAddressNameSource::new(vec![
+            Box::new(contacts),
+            Box::new(trusted),
+            Box::new(EnsAddressNameSource::new(Box::new(ens_transport(
+                VITALIK,
+                "vitalik.eth",
+                VITALIK,
+            )))),
+        ]);
+
+        let name = source.resolve_name(1, VITALIK).await.unwrap().unwrap();
+        assert_eq!(
+            (name.name.as_str(), name.source),
+            ("Vitalik (work)", NameSource::Contact)
+        );
+        let name = source
+            .resolve_name(1, "0x00000000000000000000000000000000000000aa")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(name.source, NameSource::Trusted);
+        assert_eq!(name.kind, Some(AddressType::Wallet));
+        assert!(source
+            .resolve_name(1, "0x00000000000000000000000000000000000000bb")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
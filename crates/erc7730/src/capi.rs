@@ -0,0 +1,376 @@
+//! Stable C ABI for consumers that cannot use the UniFFI scaffolding (`capi` feature).
+//!
+//! An [`Erc7730Engine`] is an opaque handle owning the descriptors to resolve
+//! against; formatting returns the display model as a JSON string with the same
+//! camelCase shape as the WebAssembly bindings. Functions signal failure by
+//! returning `NULL`; [`erc7730_last_error`] then describes what went wrong.
+//!
+//! The header `include/erc7730.h` is generated from this module by
+//! `scripts/generate-c-header.sh`.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::pin::pin;
+use std::ptr;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use crate::eip712::TypedData;
+use crate::resolver::{DescriptorSource, FilesystemSource, StaticSource};
+use crate::token::WellKnownTokenSource;
+use crate::types::descriptor::Descriptor;
+use crate::DisplayModel;
+
+/// Opaque clear-signing engine: a descriptor index plus the built-in token list.
+pub struct Erc7730Engine {
+    source: Box<dyn DescriptorSource>,
+    tokens: WellKnownTokenSource,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Create an engine from descriptor JSON: one descriptor object, or an array of
+/// them. Descriptors are indexed by their deployments.
+///
+/// Returns `NULL` on error. Free the engine with [`erc7730_engine_free`].
+///
+/// # Safety
+///
+/// `descriptor_json` must be `NULL` or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn erc7730_engine_from_json(
+    descriptor_json: *const c_char,
+) -> *mut Erc7730Engine {
+    ffi_call(ptr::null_mut(), || {
+        let json = str_arg(descriptor_json, "descriptor_json")?;
+        let descriptors = if json.trim_start().starts_with('[') {
+            serde_json::from_str::<Vec<Descriptor>>(json)
+        } else {
+            Descriptor::from_json(json).map(|descriptor| vec![descriptor])
+        }
+        .map_err(|e| format!("invalid descriptor JSON: {e}"))?;
+
+        let mut source = StaticSource::new();
+        for descriptor in descriptors {
+            source.add_descriptor(descriptor);
+        }
+        Ok(new_engine(Box::new(source)))
+    })
+}
+
+/// Create an engine from every descriptor JSON file under `path`, recursively.
+///
+/// Returns `NULL` on error. Free the engine with [`erc7730_engine_free`].
+///
+/// # Safety
+///
+/// `path` must be `NULL` or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn erc7730_engine_from_directory(path: *const c_char) -> *mut Erc7730Engine {
+    ffi_call(ptr::null_mut(), || {
+        let path = str_arg(path, "path")?;
+        let source = FilesystemSource::from_directory(Path::new(path))
+            .map_err(|e| format!("failed to load descriptors from {path}: {e}"))?;
+        Ok(new_engine(Box::new(source)))
+    })
+}
+
+/// Free an engine. `NULL` is ignored.
+///
+/// # Safety
+///
+/// `engine` must be `NULL` or a handle returned by an `erc7730_engine_*`
+/// constructor that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn erc7730_engine_free(engine: *mut Erc7730Engine) {
+    if !engine.is_null() {
+        drop(Box::from_raw(engine));
+    }
+}
+
+/// Format contract calldata into a display model JSON string.
+///
+/// `calldata_hex` and `value_hex` are hex with or without `0x`; `value_hex` and
+/// `from` may be `NULL`. Without a matching descriptor, standard token calls use
+/// the built-in templates and anything else a raw preview.
+///
+/// Returns `NULL` on error. Free the string with [`erc7730_string_free`].
+///
+/// # Safety
+///
+/// `engine` must be a live engine handle, and every string argument `NULL` or a
+/// valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn erc7730_format_calldata(
+    engine: *const Erc7730Engine,
+    chain_id: u64,
+    to: *const c_char,
+    calldata_hex: *const c_char,
+    value_hex: *const c_char,
+    from: *const c_char,
+) -> *mut c_char {
+    ffi_call(ptr::null_mut(), || {
+        let engine = engine_arg(engine)?;
+        let to = str_arg(to, "to")?;
+        let calldata = decode_hex(str_arg(calldata_hex, "calldata_hex")?, "calldata")?;
+        let value = opt_str_arg(value_hex, "value_hex")?
+            .map(|value| decode_hex(value, "value"))
+            .transpose()?;
+        let from = opt_str_arg(from, "from")?;
+
+        let model = block_on(crate::format_with_from(
+            chain_id,
+            to,
+            &calldata,
+            value.as_deref(),
+            from,
+            engine.source.as_ref(),
+            &engine.tokens,
+        ))
+        .map_err(|e| e.to_string())?;
+        to_json(&model)
+    })
+}
+
+/// Format EIP-712 typed data (JSON) into a display model JSON string.
+///
+/// Without a matching descriptor, Permit2 signatures use the built-in formatter
+/// and anything else a raw preview.
+///
+/// Returns `NULL` on error. Free the string with [`erc7730_string_free`].
+///
+/// # Safety
+///
+/// `engine` must be a live engine handle, and `typed_data_json` `NULL` or a
+/// valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn erc7730_format_typed_data(
+    engine: *const Erc7730Engine,
+    typed_data_json: *const c_char,
+) -> *mut c_char {
+    ffi_call(ptr::null_mut(), || {
+        let engine = engine_arg(engine)?;
+        let typed_data: TypedData =
+            serde_json::from_str(str_arg(typed_data_json, "typed_data_json")?)
+                .map_err(|e| format!("invalid typed data JSON: {e}"))?;
+
+        let model = block_on(crate::format_typed(
+            &typed_data,
+            engine.source.as_ref(),
+            &engine.tokens,
+        ))
+        .map_err(|e| e.to_string())?;
+        to_json(&model)
+    })
+}
+
+/// Free a string returned by this library. `NULL` is ignored.
+///
+/// # Safety
+///
+/// `string` must be `NULL` or a string returned by an `erc7730_format_*`
+/// function that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn erc7730_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Message of the last error on the calling thread, or `NULL` if the last call
+/// succeeded.
+///
+/// The string is owned by the library and stays valid until the next call into
+/// it on the same thread; do not free it.
+#[no_mangle]
+pub extern "C" fn erc7730_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Run one ABI call: record its error, and never let a panic unwind into C.
+fn ffi_call<T>(on_error: T, call: impl FnOnce() -> Result<T, String>) -> T {
+    set_last_error(None);
+    let result = catch_unwind(AssertUnwindSafe(call))
+        .unwrap_or_else(|_| Err("internal error: panic in erc7730".to_string()));
+    result.unwrap_or_else(|message| {
+        set_last_error(Some(message));
+        on_error
+    })
+}
+
+fn set_last_error(message: Option<String>) {
+    // Messages come from Rust strings; interior NULs only appear in quoted input
+    let message = message
+        .map(|message| CString::new(message.replace('\0', "\\0")).expect("NULs were escaped"));
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+}
+
+fn new_engine(source: Box<dyn DescriptorSource>) -> *mut Erc7730Engine {
+    Box::into_raw(Box::new(Erc7730Engine {
+        source,
+        tokens: WellKnownTokenSource::new(),
+    }))
+}
+
+unsafe fn engine_arg<'a>(engine: *const Erc7730Engine) -> Result<&'a Erc7730Engine, String> {
+    engine.as_ref().ok_or_else(|| "engine is NULL".to_string())
+}
+
+unsafe fn str_arg<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, String> {
+    opt_str_arg(ptr, name)?.ok_or_else(|| format!("{name} is NULL"))
+}
+
+unsafe fn opt_str_arg<'a>(ptr: *const c_char, name: &str) -> Result<Option<&'a str>, String> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map(Some)
+        .map_err(|_| format!("{name} is not valid UTF-8"))
+}
+
+fn decode_hex(input: &str, what: &str) -> Result<Vec<u8>, String> {
+    crate::decoder::decode_hex(input).map_err(|e| format!("invalid {what} hex: {e}"))
+}
+
+fn to_json(model: &DisplayModel) -> Result<*mut c_char, String> {
+    let json = serde_json::to_string(model).map_err(|e| e.to_string())?;
+    CString::new(json)
+        .map(CString::into_raw)
+        .map_err(|e| e.to_string())
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drive a future to completion on the calling thread. Engine sources resolve
+/// from memory or disk, so this never waits on an I/O reactor.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDT: &str = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+
+    fn c(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    fn last_error() -> Option<String> {
+        let ptr = erc7730_last_error();
+        (!ptr.is_null()).then(|| unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string())
+    }
+
+    unsafe fn take_string(ptr: *mut c_char) -> String {
+        assert!(!ptr.is_null(), "unexpected error: {:?}", last_error());
+        let s = CStr::from_ptr(ptr).to_str().unwrap().to_string();
+        erc7730_string_free(ptr);
+        s
+    }
+
+    fn descriptor_json() -> String {
+        serde_json::json!({
+            "context": { "contract": { "deployments": [{ "chainId": 1, "address": USDT }] } },
+            "metadata": { "owner": "test", "contractName": "Tether USD" },
+            "display": { "formats": { "transfer(address,uint256)": {
+                "intent": "Send",
+                "fields": [
+                    { "path": "@.0", "label": "To", "format": "address" },
+                    { "path": "@.1", "label": "Amount", "format": "tokenAmount",
+                      "params": { "tokenPath": "@.to" } }
+                ]
+            } } }
+        })
+        .to_string()
+    }
+
+    fn transfer_calldata() -> String {
+        format!("0xa9059cbb{:0>64}{:064x}", "b0", 2_500_000u64)
+    }
+
+    #[test]
+    fn test_format_calldata_returns_model_json() {
+        unsafe {
+            let engine = erc7730_engine_from_json(c(&format!("[{}]", descriptor_json())).as_ptr());
+            assert!(!engine.is_null(), "{:?}", last_error());
+
+            let json = take_string(erc7730_format_calldata(
+                engine,
+                1,
+                c(USDT).as_ptr(),
+                c(&transfer_calldata()).as_ptr(),
+                ptr::null(),
+                ptr::null(),
+            ));
+            let model: serde_json::Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(model["intent"], "Send");
+            assert_eq!(model["entries"][1]["type"], "item");
+            assert_eq!(model["entries"][1]["value"], "2.5 USDT");
+            assert_eq!(last_error(), None);
+
+            erc7730_engine_free(engine);
+        }
+    }
+
+    #[test]
+    fn test_errors_are_reported_per_thread() {
+        unsafe {
+            assert!(erc7730_engine_from_json(c("{").as_ptr()).is_null());
+            assert!(last_error().unwrap().starts_with("invalid descriptor JSON"));
+
+            let engine = erc7730_engine_from_json(c(&descriptor_json()).as_ptr());
+            assert_eq!(last_error(), None);
+            let result = erc7730_format_calldata(
+                engine,
+                1,
+                c(USDT).as_ptr(),
+                c("0xzz").as_ptr(),
+                ptr::null(),
+                ptr::null(),
+            );
+            assert!(result.is_null());
+            assert!(last_error().unwrap().starts_with("invalid calldata hex"));
+
+            // Other threads see their own error state
+            thread::spawn(|| assert_eq!(last_error(), None))
+                .join()
+                .unwrap();
+
+            assert!(erc7730_format_typed_data(engine, ptr::null()).is_null());
+            assert_eq!(last_error().as_deref(), Some("typed_data_json is NULL"));
+            assert!(erc7730_format_typed_data(ptr::null(), c("{}").as_ptr()).is_null());
+            assert_eq!(last_error().as_deref(), Some("engine is NULL"));
+
+            erc7730_engine_free(engine);
+            erc7730_engine_free(ptr::null_mut());
+            erc7730_string_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Chain metadata (name, native currency, explorer) via the [`ChainSource`] trait.
+//! The embedded dataset follows the ethereum-lists/chainlist entry shape.
+
+use once_cell::race::OnceBox;
+
+use serde::Deserialize;
+
+use crate::error::Error;
+use crate::prelude::*;
+use crate::token::TokenMeta;
+
+/// Native currency of a chain.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct NativeCurrency {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl NativeCurrency {
+    /// The currency as token metadata, for amount formatting.
+    pub fn to_token_meta(&self) -> TokenMeta {
+        TokenMeta {
+            symbol: self.symbol.clone(),
+            decimals: self.decimals,
+            name: self.name.clone(),
+            logo_uri: None,
+        }
+    }
+}
+
+/// Chain metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainInfo {
+    pub chain_id: u64,
+    pub name: String,
+    pub native_currency: NativeCurrency,
+    /// Block explorer base URL (EIP-3091), if known.
+    pub explorer: Option<String>,
+}
+
+/// Trait for chain metadata providers.
+pub trait ChainSource: Send + Sync {
+    fn chain(&self, chain_id: u64) -> Option<ChainInfo>;
+
+    /// Display name of a chain, `"Chain {id}"` when unknown.
+    fn chain_name(&self, chain_id: u64) -> String {
+        self.chain(chain_id)
+            .map(|c| c.name)
+            .unwrap_or_else(|| format!("Chain {chain_id}"))
+    }
+
+    /// Native currency of a chain, `None` when unknown.
+    fn native_currency(&self, chain_id: u64) -> Option<NativeCurrency> {
+        self.chain(chain_id).map(|c| c.native_currency)
+    }
+}
+
+/// Embedded chain dataset for mainstream EVM chains and testnets.
+pub struct WellKnownChainSource {
+    chains: HashMap<u64, ChainInfo>,
+}
+
+impl WellKnownChainSource {
+    pub fn new() -> Self {
+        let json_str = include_str!("assets/chains.json");
+        let source = StaticChainSource::from_json(json_str.as_bytes())
+            .expect("embedded chains.json is valid");
+        Self {
+            chains: source.chains,
+        }
+    }
+
+    /// Shared instance of the embedded dataset.
+    pub fn shared() -> &'static Self {
+        static SHARED: OnceBox<WellKnownChainSource> = OnceBox::new();
+        SHARED.get_or_init(|| Box::new(Self::new()))
+    }
+}
+
+impl Default for WellKnownChainSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChainSource for WellKnownChainSource {
+    fn chain(&self, chain_id: u64) -> Option<ChainInfo> {
+        self.chains.get(&chain_id).cloned()
+    }
+}
+
+/// Composite chain source that chains multiple sources, returning the first match.
+///
+/// Put caller-supplied sources before [`WellKnownChainSource`] to extend or override it.
+pub struct CompositeChainSource {
+    sources: Vec<Box<dyn ChainSource + Send + Sync>>,
+}
+
+impl CompositeChainSource {
+    pub fn new(sources: Vec<Box<dyn ChainSource + Send + Sync>>) -> Self {
+        Self { sources }
+    }
+}
+
+impl ChainSource for CompositeChainSource {
+    fn chain(&self, chain_id: u64) -> Option<ChainInfo> {
+        self.sources
+            .iter()
+            .find_map(|source| source.chain(chain_id))
+    }
+}
+
+/// In-memory chain source for caller-supplied chains.
+#[derive(Debug, Clone, Default)]
+pub struct StaticChainSource {
+    chains: HashMap<u64, ChainInfo>,
+}
+
+impl StaticChainSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a JSON array of chainlist-style entries
+    /// (`name`, `chainId`, `nativeCurrency`, optional `explorers`).
+    pub fn from_json(bytes: &[u8]) -> Result<Self, Error> {
+        let entries: Vec<ChainListEntry> = serde_json::from_slice(bytes)
+            .map_err(|e| Error::Descriptor(format!("invalid chain list: {e}")))?;
+        let mut source = Self::new();
+        for entry in entries {
+            source.insert(ChainInfo {
+                chain_id: entry.chain_id,
+                name: entry.name,
+                native_currency: entry.native_currency,
+                explorer: entry.explorers.into_iter().next().map(|e| e.url),
+            });
+        }
+        Ok(source)
+    }
+
+    pub fn insert(&mut self, chain: ChainInfo) {
+        self.chains.insert(chain.chain_id, chain);
+    }
+}
+
+impl ChainSource for StaticChainSource {
+    fn chain(&self, chain_id: u64) -> Option<ChainInfo> {
+        self.chains.get(&chain_id).cloned()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChainListEntry {
+    name: String,
+    chain_id: u64,
+    native_currency: NativeCurrency,
+    #[serde(default)]
+    explorers: Vec<ChainListExplorer>,
+}
+
+#[derive(Deserialize)]
+struct ChainListExplorer {
+    url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_known_chains() {
+        let source = WellKnownChainSource::new();
+        assert_eq!(source.chain_name(1), "Ethereum");
+        assert_eq!(source.chain_name(99999), "Chain 99999");
+
+        let gnosis = source.chain(100).unwrap();
+        assert_eq!(gnosis.native_currency.symbol, "XDAI");
+        assert_eq!(gnosis.explorer.as_deref(), Some("https://gnosisscan.io"));
+        assert_eq!(source.native_currency(42220).unwrap().symbol, "CELO");
+        assert_eq!(source.native_currency(5000).unwrap().symbol, "MNT");
+        assert!(source.native_currency(99999).is_none());
+    }
+
+    #[test]
+    fn test_composite_overrides_and_extends() {
+        let custom = StaticChainSource::from_json(
+            br#"[
+                {"name": "My L3", "chainId": 99999, "nativeCurrency": {"name": "Gas", "symbol": "GAS", "decimals": 6}},
+                {"name": "Mainnet", "chainId": 1, "nativeCurrency": {"name": "Ether", "symbol": "ETH", "decimals": 18}}
+            ]"#,
+        )
+        .unwrap();
+        let source = CompositeChainSource::new(vec![
+            Box::new(custom),
+            Box::new(WellKnownChainSource::new()),
+        ]);
+        assert_eq!(source.chain_name(1), "Mainnet");
+        assert_eq!(source.native_currency(99999).unwrap().decimals, 6);
+        assert_eq!(source.chain_name(10), "Optimism");
+    }
+}
@@ -0,0 +1,1407 @@
+//! Compact binary descriptors for secure-element signers.
+//!
+//! [`CompactDescriptor::compile`] keeps what rendering one function or message type
+//! needs: its display format with `$ref`s inlined, the enums, maps and constants it
+//! references, deployments, address labels, and caller-supplied token metadata.
+//! [`encode`](CompactDescriptor::encode) produces a deterministic byte string and
+//! [`decode`](CompactDescriptor::decode) reads it back. The decoder never allocates
+//! ahead of the input and bounds nesting depth, so it is safe on untrusted bytes.
+//!
+//! Layout (version 1): the magic `77 30`, a version byte, then five sections.
+//! Integers are unsigned LEB128 varints, strings are a length-prefixed UTF-8 byte
+//! string, addresses are 20 raw bytes and maps are sorted by key.
+//!
+//! | section    | contents                                                     |
+//! |------------|--------------------------------------------------------------|
+//! | `header`   | kind (`0` calldata, `1` typed data), format key              |
+//! | `context`  | deployments, contract name, address book                     |
+//! | `format`   | intent, interpolated intent, fields                          |
+//! | `metadata` | enums, maps, constants                                       |
+//! | `tokens`   | `(chain id, address)` → symbol, decimals, name               |
+
+use alloc::collections::BTreeMap;
+
+use crate::error::{CompactError, Error};
+use crate::prelude::*;
+use crate::token::{TokenLookupKey, TokenMeta, TokenSource};
+use crate::types::context::{
+    ContractContext, ContractInfo, Deployment, DescriptorContext, Eip712Context, Eip712Info,
+};
+use crate::types::descriptor::Descriptor;
+use crate::types::display::{
+    AddressType, DescriptorDisplay, DisplayField, DisplayFormat, EncryptionParams, FieldFormat,
+    FieldGroup, FormatParams, Iteration, VisibleCondition, VisibleRule,
+};
+use crate::types::metadata::{MapDefinition, Metadata};
+
+/// Leading bytes of every encoded descriptor.
+pub const MAGIC: [u8; 2] = [0x77, 0x30];
+
+/// Encoding version written by [`CompactDescriptor::encode`].
+pub const VERSION: u8 = 1;
+
+/// Deepest `$ref` chain inlined by [`CompactDescriptor::compile`], and deepest field
+/// group or constant nesting accepted by [`CompactDescriptor::decode`].
+const MAX_DEPTH: usize = 16;
+
+const CONSTANT_PREFIX: &str = "$.metadata.constants.";
+const ENUM_PREFIX: &str = "$.metadata.enums.";
+const DEFINITION_PREFIX: &str = "#/definitions/";
+
+/// What the compiled format renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompactKind {
+    Calldata,
+    TypedData,
+}
+
+/// One descriptor format compiled for a signing device.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactDescriptor {
+    pub kind: CompactKind,
+    /// Format key as written in the source descriptor.
+    pub format_key: String,
+    /// `(chain id, address)` pairs, sorted.
+    pub deployments: Vec<(u64, [u8; 20])>,
+    pub contract_name: Option<String>,
+    /// Address labels; empty unless the format has an `addressName` field.
+    pub address_book: BTreeMap<[u8; 20], String>,
+    /// The format with references inlined; `$id` and `excluded` are dropped.
+    pub format: DisplayFormat,
+    pub enums: BTreeMap<String, BTreeMap<String, String>>,
+    pub maps: BTreeMap<String, BTreeMap<String, String>>,
+    pub constants: BTreeMap<String, serde_json::Value>,
+    /// Token metadata; logos are not carried.
+    pub tokens: BTreeMap<(u64, [u8; 20]), TokenMeta>,
+}
+
+impl CompactDescriptor {
+    /// Compile the format `format_key` of `descriptor`.
+    ///
+    /// For calldata descriptors a signature whose selector matches a format key,
+    /// such as `"supply(address,uint256,address,uint16)"`, selects that format.
+    pub fn compile(descriptor: &Descriptor, format_key: &str) -> Result<Self, Error> {
+        let kind = if descriptor.context.is_contract() {
+            CompactKind::Calldata
+        } else {
+            CompactKind::TypedData
+        };
+        let (key, format) = find_format(descriptor, kind, format_key).ok_or_else(|| {
+            Error::Descriptor(format!("no display format matches '{format_key}'"))
+        })?;
+
+        let fields = inline_fields(&format.fields, descriptor, kind, 0);
+        let mut refs = References::default();
+        refs.collect(&fields);
+
+        let metadata = &descriptor.metadata;
+        let enums = refs
+            .enums
+            .iter()
+            .filter_map(|name| Some((name.clone(), sorted(metadata.enums.get(name)?))))
+            .collect();
+        let maps = refs
+            .maps
+            .iter()
+            .filter_map(|name| Some((name.clone(), sorted(&metadata.maps.get(name)?.entries))))
+            .collect();
+        let constants = refs
+            .constants
+            .iter()
+            .filter_map(|name| Some((name.clone(), metadata.constants.get(name)?.clone())))
+            .collect();
+
+        let mut deployments: Vec<(u64, [u8; 20])> = descriptor
+            .context
+            .deployments()
+            .iter()
+            .filter_map(|d| Some((d.chain_id, parse_address(&d.address)?)))
+            .collect();
+        deployments.sort_unstable();
+        deployments.dedup();
+
+        Ok(Self {
+            kind,
+            format_key: key.clone(),
+            deployments,
+            contract_name: metadata.contract_name.clone(),
+            // Labels are only shown by `addressName` fields
+            address_book: if refs.address_names {
+                metadata
+                    .address_book
+                    .iter()
+                    .filter_map(|(address, label)| Some((parse_address(address)?, label.clone())))
+                    .collect()
+            } else {
+                BTreeMap::new()
+            },
+            format: DisplayFormat {
+                id: None,
+                intent: format.intent.clone(),
+                interpolated_intent: format.interpolated_intent.clone(),
+                fields,
+                excluded: Vec::new(),
+            },
+            enums,
+            maps,
+            constants,
+            tokens: BTreeMap::new(),
+        })
+    }
+
+    /// Add metadata for a token the format may display.
+    pub fn insert_token(&mut self, chain_id: u64, address: &str, meta: TokenMeta) {
+        if let Some(address) = parse_address(address) {
+            let meta = TokenMeta {
+                logo_uri: None,
+                ..meta
+            };
+            self.tokens.insert((chain_id, address), meta);
+        }
+    }
+
+    /// Drop deployments and tokens on chains other than `chain_id`.
+    pub fn retain_chain(&mut self, chain_id: u64) {
+        self.deployments.retain(|(chain, _)| *chain == chain_id);
+        self.tokens.retain(|(chain, _), _| *chain == chain_id);
+    }
+
+    /// Serialize to the compact binary form.
+    pub fn encode(&self) -> Vec<u8> {
+        self.encode_sections()
+            .into_iter()
+            .flat_map(|(_, bytes)| bytes)
+            .collect()
+    }
+
+    /// Encoded size of each section, in layout order.
+    pub fn section_sizes(&self) -> Vec<(&'static str, usize)> {
+        self.encode_sections()
+            .into_iter()
+            .map(|(name, bytes)| (name, bytes.len()))
+            .collect()
+    }
+
+    /// Parse bytes produced by [`encode`](Self::encode).
+    ///
+    /// Only the canonical encoding is accepted, so `decode(bytes)?.encode() == bytes`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, CompactError> {
+        let mut r = Reader { bytes, pos: 0 };
+        if r.take(2)? != MAGIC {
+            return Err(CompactError::BadMagic);
+        }
+        let version = r.u8()?;
+        if version != VERSION {
+            return Err(CompactError::UnsupportedVersion(version));
+        }
+        let kind = match r.u8()? {
+            0 => CompactKind::Calldata,
+            1 => CompactKind::TypedData,
+            tag => return Err(CompactError::InvalidTag { what: "kind", tag }),
+        };
+        let format_key = r.string()?;
+
+        let mut deployments = Vec::new();
+        for _ in 0..r.varint()? {
+            deployments.push((r.varint()?, r.address()?));
+        }
+        if !deployments.windows(2).all(|w| w[0] < w[1]) {
+            return Err(CompactError::NonCanonical("deployments"));
+        }
+        let contract_name = r.option(Reader::string)?;
+        let address_book = r.map(Reader::address, Reader::string, "address book")?;
+
+        let format = DisplayFormat {
+            id: None,
+            intent: r.option(Reader::string)?,
+            interpolated_intent: r.option(Reader::string)?,
+            fields: r.fields(0)?,
+            excluded: Vec::new(),
+        };
+
+        let enums = r.map(Reader::string, Reader::string_map, "enums")?;
+        let maps = r.map(Reader::string, Reader::string_map, "maps")?;
+        let constants = r.map(Reader::string, |r| r.value(0), "constants")?;
+        let tokens = r.map(
+            |r| Ok((r.varint()?, r.address()?)),
+            |r| {
+                Ok(TokenMeta {
+                    symbol: r.string()?,
+                    decimals: r.u8()?,
+                    name: r.string()?,
+                    logo_uri: None,
+                })
+            },
+            "tokens",
+        )?;
+
+        let remaining = bytes.len() - r.pos;
+        if remaining != 0 {
+            return Err(CompactError::TrailingBytes(remaining));
+        }
+        Ok(Self {
+            kind,
+            format_key,
+            deployments,
+            contract_name,
+            address_book,
+            format,
+            enums,
+            maps,
+            constants,
+            tokens,
+        })
+    }
+
+    /// A descriptor holding just this format, for the regular rendering entry points.
+    pub fn to_descriptor(&self) -> Descriptor {
+        let deployments = self
+            .deployments
+            .iter()
+            .map(|(chain_id, address)| Deployment {
+                chain_id: *chain_id,
+                address: format!("0x{}", hex::encode(address)),
+            })
+            .collect();
+        let context = match self.kind {
+            CompactKind::Calldata => DescriptorContext::Contract(ContractContext {
+                id: None,
+                contract: ContractInfo {
+                    deployments,
+                    factory: None,
+                    address_matcher: None,
+                },
+            }),
+            CompactKind::TypedData => DescriptorContext::Eip712(Eip712Context {
+                id: None,
+                eip712: Eip712Info {
+                    deployments,
+                    domain: None,
+                },
+            }),
+        };
+        let owned = |entries: &BTreeMap<String, String>| -> HashMap<String, String> {
+            entries
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect()
+        };
+        let mut formats = HashMap::new();
+        formats.insert(self.format_key.clone(), self.format.clone());
+        Descriptor {
+            schema: None,
+            includes: None,
+            context,
+            metadata: Metadata {
+                owner: None,
+                info: None,
+                token: None,
+                enums: self
+                    .enums
+                    .iter()
+                    .map(|(name, entries)| (name.clone(), owned(entries)))
+                    .collect(),
+                constants: self
+                    .constants
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.clone()))
+                    .collect(),
+                address_book: self
+                    .address_book
+                    .iter()
+                    .map(|(address, label)| (format!("0x{}", hex::encode(address)), label.clone()))
+                    .collect(),
+                contract_name: self.contract_name.clone(),
+                maps: self
+                    .maps
+                    .iter()
+                    .map(|(name, entries)| {
+                        let map = MapDefinition {
+                            entries: owned(entries),
+                        };
+                        (name.clone(), map)
+                    })
+                    .collect(),
+            },
+            display: DescriptorDisplay {
+                definitions: HashMap::new(),
+                formats,
+            },
+        }
+    }
+
+    fn encode_sections(&self) -> [(&'static str, Vec<u8>); 5] {
+        let mut header = Vec::new();
+        header.extend_from_slice(&MAGIC);
+        header.push(VERSION);
+        header.push(match self.kind {
+            CompactKind::Calldata => 0,
+            CompactKind::TypedData => 1,
+        });
+        put_str(&mut header, &self.format_key);
+
+        let mut context = Vec::new();
+        put_varint(&mut context, self.deployments.len() as u64);
+        for (chain_id, address) in &self.deployments {
+            put_varint(&mut context, *chain_id);
+            context.extend_from_slice(address);
+        }
+        put_option(&mut context, self.contract_name.as_deref(), put_str);
+        put_varint(&mut context, self.address_book.len() as u64);
+        for (address, label) in &self.address_book {
+            context.extend_from_slice(address);
+            put_str(&mut context, label);
+        }
+
+        let mut format = Vec::new();
+        put_option(&mut format, self.format.intent.as_deref(), put_str);
+        put_option(
+            &mut format,
+            self.format.interpolated_intent.as_deref(),
+            put_str,
+        );
+        put_fields(&mut format, &self.format.fields);
+
+        let mut metadata = Vec::new();
+        for tables in [&self.enums, &self.maps] {
+            put_varint(&mut metadata, tables.len() as u64);
+            for (name, entries) in tables {
+                put_str(&mut metadata, name);
+                put_varint(&mut metadata, entries.len() as u64);
+                for (key, label) in entries {
+                    put_str(&mut metadata, key);
+                    put_str(&mut metadata, label);
+                }
+            }
+        }
+        put_varint(&mut metadata, self.constants.len() as u64);
+        for (name, value) in &self.constants {
+            put_str(&mut metadata, name);
+            put_value(&mut metadata, value);
+        }
+
+        let mut tokens = Vec::new();
+        put_varint(&mut tokens, self.tokens.len() as u64);
+        for ((chain_id, address), meta) in &self.tokens {
+            put_varint(&mut tokens, *chain_id);
+            tokens.extend_from_slice(address);
+            put_str(&mut tokens, &meta.symbol);
+            tokens.push(meta.decimals);
+            put_str(&mut tokens, &meta.name);
+        }
+
+        [
+            ("header", header),
+            ("context", context),
+            ("format", format),
+            ("metadata", metadata),
+            ("tokens", tokens),
+        ]
+    }
+}
+
+impl TokenSource for CompactDescriptor {
+    fn lookup(&self, key: &TokenLookupKey) -> Option<TokenMeta> {
+        let address = parse_address(key.address()?)?;
+        self.tokens.get(&(key.chain_id()?, address)).cloned()
+    }
+}
+
+fn find_format<'a>(
+    descriptor: &'a Descriptor,
+    kind: CompactKind,
+    format_key: &str,
+) -> Option<(&'a String, &'a DisplayFormat)> {
+    let formats = &descriptor.display.formats;
+    if let Some(found) = formats.get_key_value(format_key) {
+        return Some(found);
+    }
+    if kind != CompactKind::Calldata {
+        return None;
+    }
+    let selector = crate::decoder::parse_signature(format_key).ok()?.selector;
+    let mut matches: Vec<_> = formats
+        .iter()
+        .filter(|(key, _)| {
+            crate::decoder::parse_signature(key).is_ok_and(|parsed| parsed.selector == selector)
+        })
+        .collect();
+    matches.sort_by(|a, b| a.0.cmp(b.0));
+    matches.into_iter().next()
+}
+
+/// Replace resolvable `$ref`s with their definitions, as the renderers would look
+/// them up: typed data also accepts a bare definition name.
+fn inline_fields(
+    fields: &[DisplayField],
+    descriptor: &Descriptor,
+    kind: CompactKind,
+    depth: usize,
+) -> Vec<DisplayField> {
+    fields
+        .iter()
+        .map(|field| match field {
+            DisplayField::Reference { reference } if depth < MAX_DEPTH => {
+                let name = match kind {
+                    CompactKind::Calldata => reference.strip_prefix(DEFINITION_PREFIX),
+                    CompactKind::TypedData => Some(
+                        reference
+                            .strip_prefix(DEFINITION_PREFIX)
+                            .unwrap_or(reference),
+                    ),
+                };
+                match name.and_then(|name| descriptor.display.definitions.get(name)) {
+                    Some(definition) => inline_fields(
+                        core::slice::from_ref(definition),
+                        descriptor,
+                        kind,
+                        depth + 1,
+                    )
+                    .remove(0),
+                    None => field.clone(),
+                }
+            }
+            DisplayField::Group { field_group } => DisplayField::Group {
+                field_group: FieldGroup {
+                    fields: inline_fields(&field_group.fields, descriptor, kind, depth + 1),
+                    ..field_group.clone()
+                },
+            },
+            _ => field.clone(),
+        })
+        .collect()
+}
+
+/// Metadata entries the inlined fields refer to.
+#[derive(Default)]
+struct References {
+    enums: Vec<String>,
+    maps: Vec<String>,
+    constants: Vec<String>,
+    /// Whether an `addressName` field may show an address book label.
+    address_names: bool,
+}
+
+impl References {
+    fn collect(&mut self, fields: &[DisplayField]) {
+        for field in fields {
+            match field {
+                DisplayField::Simple {
+                    path,
+                    format,
+                    params,
+                    ..
+                } => {
+                    self.constant(path);
+                    if *format == Some(FieldFormat::AddressName) {
+                        self.address_names = true;
+                    }
+                    let Some(params) = params else { continue };
+                    for path in [
+                        &params.token_path,
+                        &params.native_currency_address,
+                        &params.chain_id_path,
+                        &params.collection_path,
+                        &params.threshold,
+                    ]
+                    .into_iter()
+                    .flatten()
+                    {
+                        self.constant(path);
+                    }
+                    if let Some(name) = &params.enum_path {
+                        self.enums.push(name.clone());
+                    }
+                    if let Some(name) = params
+                        .ref_path
+                        .as_deref()
+                        .and_then(|path| path.strip_prefix(ENUM_PREFIX))
+                    {
+                        self.enums.push(name.to_string());
+                    }
+                    if let Some(name) = &params.map_reference {
+                        self.maps.push(name.clone());
+                    }
+                }
+                DisplayField::Group { field_group } => self.collect(&field_group.fields),
+                DisplayField::Reference { .. } => {}
+            }
+        }
+    }
+
+    fn constant(&mut self, path: &str) {
+        if let Some(name) = path.strip_prefix(CONSTANT_PREFIX) {
+            self.constants.push(name.to_string());
+        }
+    }
+}
+
+fn sorted(entries: &HashMap<String, String>) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
+fn parse_address(address: &str) -> Option<[u8; 20]> {
+    let hex_str = address
+        .strip_prefix("0x")
+        .or_else(|| address.strip_prefix("0X"))?;
+    let mut out = [0u8; 20];
+    hex::decode_to_slice(hex_str, &mut out).ok()?;
+    Some(out)
+}
+
+// ---------------------------------------------------------------------------
+// Encoding
+// ---------------------------------------------------------------------------
+
+fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    put_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn put_str(out: &mut Vec<u8>, s: &str) {
+    put_bytes(out, s.as_bytes());
+}
+
+fn put_option<T: ?Sized>(out: &mut Vec<u8>, value: Option<&T>, put: fn(&mut Vec<u8>, &T)) {
+    match value {
+        Some(value) => {
+            out.push(1);
+            put(out, value);
+        }
+        None => out.push(0),
+    }
+}
+
+fn put_fields(out: &mut Vec<u8>, fields: &[DisplayField]) {
+    put_varint(out, fields.len() as u64);
+    for field in fields {
+        match field {
+            DisplayField::Simple {
+                path,
+                label,
+                format,
+                params,
+                visible,
+            } => {
+                out.push(0);
+                put_str(out, path);
+                put_str(out, label);
+                out.push(format.as_ref().map_or(0, |f| field_format_tag(f) + 1));
+                put_option(out, params.as_ref(), put_params);
+                put_visible(out, visible);
+            }
+            DisplayField::Group { field_group } => {
+                out.push(1);
+                put_str(out, &field_group.label);
+                out.push(match field_group.iteration {
+                    Iteration::Sequential => 0,
+                    Iteration::Bundled => 1,
+                });
+                put_fields(out, &field_group.fields);
+            }
+            DisplayField::Reference { reference } => {
+                out.push(2);
+                put_str(out, reference);
+            }
+        }
+    }
+}
+
+/// Params are a presence bitmask followed by the present values in field order.
+fn put_params(out: &mut Vec<u8>, params: &FormatParams) {
+    let strings = [
+        &params.token_path,
+        &params.native_currency_address,
+        &params.chain_id_path,
+        &params.collection_path,
+        &params.enum_path,
+        &params.ref_path,
+        &params.map_reference,
+        &params.threshold,
+        &params.message,
+        &params.base,
+    ];
+    let mut mask = 0u64;
+    for (bit, value) in strings.iter().enumerate() {
+        if value.is_some() {
+            mask |= 1 << bit;
+        }
+    }
+    let others = [
+        params.chain_id.is_some(),
+        params.types.is_some(),
+        params.sources.is_some(),
+        params.decimals.is_some(),
+        params.prefix.is_some(),
+        params.encryption.is_some(),
+    ];
+    for (bit, present) in others.iter().enumerate() {
+        if *present {
+            mask |= 1 << (strings.len() + bit);
+        }
+    }
+    put_varint(out, mask);
+
+    for value in strings.into_iter().flatten() {
+        put_str(out, value);
+    }
+    if let Some(chain_id) = params.chain_id {
+        put_varint(out, chain_id);
+    }
+    if let Some(types) = &params.types {
+        put_varint(out, types.len() as u64);
+        for kind in types {
+            out.push(address_type_tag(*kind));
+        }
+    }
+    if let Some(sources) = &params.sources {
+        put_varint(out, sources.len() as u64);
+        for source in sources {
+            put_str(out, source);
+        }
+    }
+    if let Some(decimals) = params.decimals {
+        out.push(decimals);
+    }
+    if let Some(prefix) = params.prefix {
+        out.push(prefix as u8);
+    }
+    if let Some(encryption) = &params.encryption {
+        put_option(out, encryption.fallback_label.as_deref(), put_str);
+    }
+}
+
+fn put_visible(out: &mut Vec<u8>, visible: &VisibleRule) {
+    match visible {
+        VisibleRule::Always => out.push(0),
+        VisibleRule::Bool(value) => {
+            out.push(1);
+            out.push(*value as u8);
+        }
+        VisibleRule::Named(name) => {
+            out.push(2);
+            put_str(out, name);
+        }
+        VisibleRule::Condition(condition) => {
+            out.push(3);
+            put_option(out, condition.if_not_in.as_deref(), put_values);
+            put_option(out, condition.must_be.as_deref(), put_values);
+        }
+    }
+}
+
+fn put_values(out: &mut Vec<u8>, values: &[serde_json::Value]) {
+    put_varint(out, values.len() as u64);
+    for value in values {
+        put_value(out, value);
+    }
+}
+
+/// JSON values; lowercase `0x` hex strings are stored as raw bytes.
+fn put_value(out: &mut Vec<u8>, value: &serde_json::Value) {
+    use serde_json::Value;
+    match value {
+        Value::Null => out.push(0),
+        Value::Bool(false) => out.push(1),
+        Value::Bool(true) => out.push(2),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                out.push(3);
+                put_varint(out, n);
+            } else if let Some(n) = n.as_i64() {
+                out.push(4);
+                put_varint(out, !(n as u64));
+            } else {
+                out.push(5);
+                let n = n.as_f64().unwrap_or_default();
+                out.extend_from_slice(&n.to_bits().to_le_bytes());
+            }
+        }
+        Value::String(s) => match compact_hex(s) {
+            Some(bytes) => {
+                out.push(7);
+                put_bytes(out, &bytes);
+            }
+            None => {
+                out.push(6);
+                put_str(out, s);
+            }
+        },
+        Value::Array(items) => {
+            out.push(8);
+            put_values(out, items);
+        }
+        Value::Object(object) => {
+            out.push(9);
+            let mut entries: Vec<_> = object.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            put_varint(out, entries.len() as u64);
+            for (key, value) in entries {
+                put_str(out, key);
+                put_value(out, value);
+            }
+        }
+    }
+}
+
+/// Bytes of `s` if it is `0x` followed by lowercase hex of whole bytes.
+fn compact_hex(s: &str) -> Option<Vec<u8>> {
+    let digits = s.strip_prefix("0x")?;
+    let canonical = digits.len() % 2 == 0
+        && digits
+            .bytes()
+            .all(|c| c.is_ascii_digit() || (b'a'..=b'f').contains(&c));
+    canonical.then(|| hex::decode(digits).ok()).flatten()
+}
+
+fn field_format_tag(format: &FieldFormat) -> u8 {
+    match format {
+        FieldFormat::TokenAmount => 0,
+        FieldFormat::Amount => 1,
+        FieldFormat::Date => 2,
+        FieldFormat::Enum => 3,
+        FieldFormat::Address => 4,
+        FieldFormat::AddressName => 5,
+        FieldFormat::Number => 6,
+        FieldFormat::Raw => 7,
+        FieldFormat::TokenTicker => 8,
+        FieldFormat::ChainId => 9,
+        FieldFormat::Calldata => 10,
+        FieldFormat::NftName => 11,
+        FieldFormat::Duration => 12,
+        FieldFormat::Unit => 13,
+    }
+}
+
+fn field_format_from_tag(tag: u8) -> Option<FieldFormat> {
+    Some(match tag {
+        0 => FieldFormat::TokenAmount,
+        1 => FieldFormat::Amount,
+        2 => FieldFormat::Date,
+        3 => FieldFormat::Enum,
+        4 => FieldFormat::Address,
+        5 => FieldFormat::AddressName,
+        6 => FieldFormat::Number,
+        7 => FieldFormat::Raw,
+        8 => FieldFormat::TokenTicker,
+        9 => FieldFormat::ChainId,
+        10 => FieldFormat::Calldata,
+        11 => FieldFormat::NftName,
+        12 => FieldFormat::Duration,
+        13 => FieldFormat::Unit,
+        _ => return None,
+    })
+}
+
+fn address_type_tag(kind: AddressType) -> u8 {
+    match kind {
+        AddressType::Eoa => 0,
+        AddressType::Contract => 1,
+        AddressType::Token => 2,
+        AddressType::Wallet => 3,
+        AddressType::Collection => 4,
+    }
+}
+
+fn address_type_from_tag(tag: u8) -> Option<AddressType> {
+    Some(match tag {
+        0 => AddressType::Eoa,
+        1 => AddressType::Contract,
+        2 => AddressType::Token,
+        3 => AddressType::Wallet,
+        4 => AddressType::Collection,
+        _ => return None,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Decoding
+// ---------------------------------------------------------------------------
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CompactError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(CompactError::Truncated)?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, CompactError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn bool(&mut self, what: &'static str) -> Result<bool, CompactError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(CompactError::InvalidTag { what, tag }),
+        }
+    }
+
+    /// LEB128; overlong encodings and values past `u64::MAX` are rejected.
+    fn varint(&mut self) -> Result<u64, CompactError> {
+        let mut value = 0u64;
+        for i in 0..10 {
+            let byte = self.u8()?;
+            let bits = u64::from(byte & 0x7f);
+            if i == 9 && bits > 1 {
+                return Err(CompactError::NonCanonical("varint"));
+            }
+            value |= bits << (7 * i);
+            if byte & 0x80 == 0 {
+                if byte == 0 && i > 0 {
+                    return Err(CompactError::NonCanonical("varint"));
+                }
+                return Ok(value);
+            }
+        }
+        Err(CompactError::NonCanonical("varint"))
+    }
+
+    fn len(&mut self) -> Result<usize, CompactError> {
+        usize::try_from(self.varint()?).map_err(|_| CompactError::Truncated)
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], CompactError> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> Result<String, CompactError> {
+        let bytes = self.bytes()?;
+        core::str::from_utf8(bytes)
+            .map(String::from)
+            .map_err(|_| CompactError::InvalidUtf8)
+    }
+
+    fn address(&mut self) -> Result<[u8; 20], CompactError> {
+        let mut out = [0u8; 20];
+        out.copy_from_slice(self.take(20)?);
+        Ok(out)
+    }
+
+    fn option<T>(
+        &mut self,
+        read: fn(&mut Self) -> Result<T, CompactError>,
+    ) -> Result<Option<T>, CompactError> {
+        if self.bool("option")? {
+            read(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// A count-prefixed map whose keys must be strictly increasing.
+    fn map<K: Ord, V>(
+        &mut self,
+        key: impl Fn(&mut Self) -> Result<K, CompactError>,
+        value: impl Fn(&mut Self) -> Result<V, CompactError>,
+        what: &'static str,
+    ) -> Result<BTreeMap<K, V>, CompactError> {
+        let mut map = BTreeMap::new();
+        for _ in 0..self.varint()? {
+            let k = key(self)?;
+            if map.last_key_value().is_some_and(|(last, _)| *last >= k) {
+                return Err(CompactError::NonCanonical(what));
+            }
+            let v = value(self)?;
+            map.insert(k, v);
+        }
+        Ok(map)
+    }
+
+    fn string_map(&mut self) -> Result<BTreeMap<String, String>, CompactError> {
+        self.map(Reader::string, Reader::string, "string map")
+    }
+
+    fn fields(&mut self, depth: usize) -> Result<Vec<DisplayField>, CompactError> {
+        if depth > MAX_DEPTH {
+            return Err(CompactError::TooDeep);
+        }
+        let mut fields = Vec::new();
+        for _ in 0..self.varint()? {
+            let field = match self.u8()? {
+                0 => DisplayField::Simple {
+                    path: self.string()?,
+                    label: self.string()?,
+                    format: match self.u8()? {
+                        0 => None,
+                        tag => Some(field_format_from_tag(tag - 1).ok_or(
+                            CompactError::InvalidTag {
+                                what: "field format",
+                                tag,
+                            },
+                        )?),
+                    },
+                    params: self.option(Reader::params)?,
+                    visible: self.visible()?,
+                },
+                1 => DisplayField::Group {
+                    field_group: FieldGroup {
+                        label: self.string()?,
+                        iteration: match self.u8()? {
+                            0 => Iteration::Sequential,
+                            1 => Iteration::Bundled,
+                            tag => {
+                                return Err(CompactError::InvalidTag {
+                                    what: "iteration",
+                                    tag,
+                                })
+                            }
+                        },
+                        fields: self.fields(depth + 1)?,
+                    },
+                },
+                2 => DisplayField::Reference {
+                    reference: self.string()?,
+                },
+                tag => return Err(CompactError::InvalidTag { what: "field", tag }),
+            };
+            fields.push(field);
+        }
+        Ok(fields)
+    }
+
+    fn params(&mut self) -> Result<FormatParams, CompactError> {
+        let mask = self.varint()?;
+        if mask >> 16 != 0 {
+            return Err(CompactError::NonCanonical("params mask"));
+        }
+        let has = |bit: u32| mask & (1 << bit) != 0;
+        let mut strings: [Option<String>; 10] = Default::default();
+        for (bit, slot) in (0..).zip(strings.iter_mut()) {
+            if has(bit) {
+                *slot = Some(self.string()?);
+            }
+        }
+        let [token_path, native_currency_address, chain_id_path, collection_path, enum_path, ref_path, map_reference, threshold, message, base] =
+            strings;
+
+        let chain_id = if has(10) { Some(self.varint()?) } else { None };
+        let types = if has(11) {
+            let mut types = Vec::new();
+            for _ in 0..self.varint()? {
+                let tag = self.u8()?;
+                types.push(address_type_from_tag(tag).ok_or(CompactError::InvalidTag {
+                    what: "address type",
+                    tag,
+                })?);
+            }
+            Some(types)
+        } else {
+            None
+        };
+        let sources = if has(12) {
+            let mut sources = Vec::new();
+            for _ in 0..self.varint()? {
+                sources.push(self.string()?);
+            }
+            Some(sources)
+        } else {
+            None
+        };
+        let decimals = if has(13) { Some(self.u8()?) } else { None };
+        let prefix = if has(14) {
+            Some(self.bool("prefix")?)
+        } else {
+            None
+        };
+        let encryption = if has(15) {
+            Some(EncryptionParams {
+                fallback_label: self.option(Reader::string)?,
+            })
+        } else {
+            None
+        };
+
+        Ok(FormatParams {
+            token_path,
+            native_currency_address,
+            chain_id,
+            chain_id_path,
+            types,
+            sources,
+            collection_path,
+            enum_path,
+            ref_path,
+            map_reference,
+            threshold,
+            message,
+            base,
+            decimals,
+            prefix,
+            encryption,
+        })
+    }
+
+    fn visible(&mut self) -> Result<VisibleRule, CompactError> {
+        Ok(match self.u8()? {
+            0 => VisibleRule::Always,
+            1 => VisibleRule::Bool(self.bool("visible")?),
+            2 => VisibleRule::Named(self.string()?),
+            3 => VisibleRule::Condition(VisibleCondition {
+                if_not_in: self.option(|r| r.values(0))?,
+                must_be: self.option(|r| r.values(0))?,
+            }),
+            tag => {
+                return Err(CompactError::InvalidTag {
+                    what: "visibility",
+                    tag,
+                })
+            }
+        })
+    }
+
+    fn values(&mut self, depth: usize) -> Result<Vec<serde_json::Value>, CompactError> {
+        let mut values = Vec::new();
+        for _ in 0..self.varint()? {
+            values.push(self.value(depth)?);
+        }
+        Ok(values)
+    }
+
+    fn value(&mut self, depth: usize) -> Result<serde_json::Value, CompactError> {
+        use serde_json::Value;
+        if depth > MAX_DEPTH {
+            return Err(CompactError::TooDeep);
+        }
+        Ok(match self.u8()? {
+            0 => Value::Null,
+            1 => Value::Bool(false),
+            2 => Value::Bool(true),
+            3 => Value::from(self.varint()?),
+            4 => {
+                let n = !self.varint()? as i64;
+                if n >= 0 {
+                    return Err(CompactError::NonCanonical("negative integer"));
+                }
+                Value::from(n)
+            }
+            5 => {
+                let mut bits = [0u8; 8];
+                bits.copy_from_slice(self.take(8)?);
+                let n = f64::from_bits(u64::from_le_bytes(bits));
+                let number =
+                    serde_json::Number::from_f64(n).ok_or(CompactError::NonCanonical("float"))?;
+                Value::Number(number)
+            }
+            6 => {
+                let s = self.string()?;
+                if compact_hex(&s).is_some() {
+                    return Err(CompactError::NonCanonical("hex string"));
+                }
+                Value::String(s)
+            }
+            7 => Value::String(format!("0x{}", hex::encode(self.bytes()?))),
+            8 => Value::Array(self.values(depth + 1)?),
+            9 => {
+                let entries = self.map(Reader::string, |r| r.value(depth + 1), "object")?;
+                Value::Object(entries.into_iter().collect())
+            }
+            tag => return Err(CompactError::InvalidTag { what: "value", tag }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::{decode_calldata, parse_signature, CalldataBuilder};
+    use crate::eip712::TypedData;
+    use crate::token::StaticTokenSource;
+
+    fn aave_v3() -> Descriptor {
+        let json = include_str!("../tests/fixtures/aave-lpv3.json");
+        Descriptor::from_json(json).unwrap()
+    }
+
+    fn usdc() -> TokenMeta {
+        TokenMeta {
+            symbol: "USDC".to_string(),
+            decimals: 6,
+            name: "USD Coin".to_string(),
+            logo_uri: Some("https://example.org/usdc.png".to_string()),
+        }
+    }
+
+    const USDC: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+    const POOL: &str = "0x87870bca3f3fd6335c3f4ce8392d69350b4fa4e2";
+
+    #[test]
+    fn test_every_aave_format_round_trips() {
+        let descriptor = aave_v3();
+        for key in descriptor.display.formats.keys() {
+            let mut compact = CompactDescriptor::compile(&descriptor, key).unwrap();
+            compact.insert_token(1, USDC, usdc());
+            let bytes = compact.encode();
+            let decoded = CompactDescriptor::decode(&bytes).unwrap();
+            assert_eq!(decoded, compact, "{key}");
+            assert_eq!(decoded.encode(), bytes, "{key}");
+            assert_eq!(
+                compact
+                    .section_sizes()
+                    .iter()
+                    .map(|(_, n)| n)
+                    .sum::<usize>(),
+                bytes.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_encoding_is_deterministic_across_parses() {
+        let key = "supply(address asset,uint256 amount,address onBehalfOf,uint16 referralCode)";
+        let first = CompactDescriptor::compile(&aave_v3(), key)
+            .unwrap()
+            .encode();
+        for _ in 0..4 {
+            assert_eq!(
+                CompactDescriptor::compile(&aave_v3(), key)
+                    .unwrap()
+                    .encode(),
+                first
+            );
+        }
+    }
+
+    #[test]
+    fn test_compile_keeps_only_referenced_metadata() {
+        let descriptor = aave_v3();
+        let repay = descriptor
+            .display
+            .formats
+            .keys()
+            .find(|key| key.starts_with("repay("))
+            .unwrap();
+        let compact = CompactDescriptor::compile(&descriptor, repay).unwrap();
+        assert_eq!(compact.constants.keys().collect::<Vec<_>>(), ["max"]);
+        assert_eq!(
+            compact.enums.keys().collect::<Vec<_>>(),
+            ["interestRateMode"]
+        );
+        assert!(compact.encode().len() * 4 < serde_json::to_vec(&descriptor).unwrap().len());
+    }
+
+    #[test]
+    fn test_compile_keeps_address_book_only_for_address_names() {
+        let descriptor = Descriptor::from_json(&format!(
+            r#"{{
+            "context": {{"contract": {{"deployments": [{{"chainId": 1, "address": "{POOL}"}}]}}}},
+            "metadata": {{"owner": "test", "addressBook": {{"{USDC}": "USDC"}}}},
+            "display": {{"formats": {{
+                "withdraw(address,uint256)": {{"fields": [
+                    {{"path": "@.0", "label": "Asset", "format": "addressName"}},
+                    {{"path": "@.1", "label": "Amount", "format": "raw"}}
+                ]}},
+                "pause(uint256)": {{"fields": [
+                    {{"path": "@.0", "label": "Duration", "format": "raw"}}
+                ]}}
+            }}}}
+        }}"#
+        ))
+        .unwrap();
+        let usdc = parse_address(USDC).unwrap();
+
+        let withdraw =
+            CompactDescriptor::compile(&descriptor, "withdraw(address,uint256)").unwrap();
+        assert_eq!(
+            withdraw.address_book,
+            BTreeMap::from([(usdc, "USDC".to_string())])
+        );
+        let pause = CompactDescriptor::compile(&descriptor, "pause(uint256)").unwrap();
+        assert!(pause.address_book.is_empty());
+    }
+
+    #[test]
+    fn test_compile_matches_signature_by_selector() {
+        let compact =
+            CompactDescriptor::compile(&aave_v3(), "supply(address,uint256,address,uint16)")
+                .unwrap();
+        assert!(compact.format_key.starts_with("supply(address asset"));
+        assert!(CompactDescriptor::compile(&aave_v3(), "nope()").is_err());
+    }
+
+    #[test]
+    fn test_decoded_descriptor_renders_like_the_original() {
+        let descriptor = aave_v3();
+        let key = "supply(address,uint256,address,uint16)";
+        let mut compact = CompactDescriptor::compile(&descriptor, key).unwrap();
+        compact.insert_token(1, USDC, usdc());
+        compact.retain_chain(1);
+        let device = CompactDescriptor::decode(&compact.encode()).unwrap();
+
+        let sig = parse_signature(key).unwrap();
+        let calldata = CalldataBuilder::new(key)
+            .address(USDC)
+            .uint(1_000_000)
+            .address("0x1234567890abcdef1234567890abcdef12345678")
+            .uint(0)
+            .build()
+            .unwrap();
+        let decoded = decode_calldata(&sig, &calldata).unwrap();
+
+        let mut tokens = StaticTokenSource::new();
+        tokens.insert(1, USDC, usdc());
+        let expected =
+            crate::engine::format_calldata(&descriptor, 1, POOL, &decoded, None, &tokens).unwrap();
+        let actual = crate::engine::format_calldata(
+            &device.to_descriptor(),
+            1,
+            POOL,
+            &decoded,
+            None,
+            &device,
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&actual).unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_typed_data_round_trips_with_inlined_references() {
+        let descriptor = Descriptor::from_json(
+            r##"{
+                "context": {"eip712": {"deployments": [{"chainId": 1, "address": "0x000000000022D473030F116dDEE9F6B43aC78BA3"}]}},
+                "metadata": {
+                    "contractName": "Permit2",
+                    "constants": {"unused": 1, "cap": "0xff", "nested": {"b": [-1, 1.5], "a": null}},
+                    "enums": {"mode": {"0": "Off", "1": "On"}}
+                },
+                "display": {
+                    "definitions": {"amount": {"path": "amount", "label": "Amount", "format": "amount", "params": {"threshold": "$.metadata.constants.cap", "message": "All"}}},
+                    "formats": {"Order": {
+                        "intent": "Order",
+                        "fields": [
+                            {"$ref": "amount"},
+                            {"fieldGroup": {"label": "Details", "iteration": "bundled", "fields": [
+                                {"path": "mode", "label": "Mode", "format": "enum", "params": {"enumPath": "mode"}, "visible": {"ifNotIn": ["0"]}},
+                                {"$ref": "#/definitions/missing"}
+                            ]}}
+                        ]
+                    }}
+                }
+            }"##,
+        )
+        .unwrap();
+        let compact = CompactDescriptor::compile(&descriptor, "Order").unwrap();
+        assert_eq!(compact.kind, CompactKind::TypedData);
+        assert_eq!(compact.constants.keys().collect::<Vec<_>>(), ["cap"]);
+        assert!(matches!(
+            compact.format.fields[0],
+            DisplayField::Simple { .. }
+        ));
+        let bytes = compact.encode();
+        let decoded = CompactDescriptor::decode(&bytes).unwrap();
+        assert_eq!(decoded, compact);
+
+        let data: TypedData = serde_json::from_value(serde_json::json!({
+            "types": {
+                "EIP712Domain": [{"name": "chainId", "type": "uint256"}],
+                "Order": [{"name": "amount", "type": "uint256"}, {"name": "mode", "type": "uint8"}]
+            },
+            "primaryType": "Order",
+            "domain": {"chainId": 1},
+            "message": {"amount": "255", "mode": "1"}
+        }))
+        .unwrap();
+        let tokens = StaticTokenSource::new();
+        let expected = crate::eip712::format_typed_data(&descriptor, &data, &tokens).unwrap();
+        let actual =
+            crate::eip712::format_typed_data(&decoded.to_descriptor(), &data, &tokens).unwrap();
+        assert_eq!(
+            serde_json::to_value(&actual).unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_constants_round_trip_exactly() {
+        let values = serde_json::json!([
+            null, true, false, 0, 300, u64::MAX, -1, i64::MIN, 1.25,
+            "0x", "0xdeadbeef", "0xABCD", "0xabc", "text",
+            {"z": [1, {"y": "0x00"}], "a": "b"}
+        ]);
+        let mut bytes = Vec::new();
+        put_value(&mut bytes, &values);
+        let mut r = Reader {
+            bytes: &bytes,
+            pos: 0,
+        };
+        assert_eq!(r.value(0).unwrap(), values);
+        assert_eq!(r.pos, bytes.len());
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed_input() {
+        let compact =
+            CompactDescriptor::compile(&aave_v3(), "supply(address,uint256,address,uint16)")
+                .unwrap();
+        let bytes = compact.encode();
+        for len in 0..bytes.len() {
+            assert!(
+                CompactDescriptor::decode(&bytes[..len]).is_err(),
+                "prefix {len}"
+            );
+        }
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            CompactDescriptor::decode(&trailing),
+            Err(CompactError::TrailingBytes(1))
+        ));
+        let mut magic = bytes.clone();
+        magic[0] = 0;
+        assert!(matches!(
+            CompactDescriptor::decode(&magic),
+            Err(CompactError::BadMagic)
+        ));
+        let mut version = bytes.clone();
+        version[2] = 9;
+        assert!(matches!(
+            CompactDescriptor::decode(&version),
+            Err(CompactError::UnsupportedVersion(9))
+        ));
+
+        let mut overlong = Reader {
+            bytes: &[0x80, 0x00],
+            pos: 0,
+        };
+        assert!(overlong.varint().is_err());
+        let mut huge_count = Reader {
+            bytes: &[0xff, 0xff, 0xff, 0xff, 0x0f],
+            pos: 0,
+        };
+        assert!(matches!(huge_count.fields(0), Err(CompactError::Truncated)));
+
+        let mut nested = [8u8, 1].repeat(MAX_DEPTH + 2);
+        nested.push(0);
+        let mut deep = Reader {
+            bytes: &nested,
+            pos: 0,
+        };
+        assert!(matches!(deep.value(0), Err(CompactError::TooDeep)));
+    }
+}
@@ -1,9 +1,10 @@
-//! Solidity function signature parsing and ABI calldata decoding.
+//! Solidity function signature parsing and ABI calldata decoding and encoding.
 //! No JSON ABI needed — signatures are parsed directly from descriptor format keys.
 
 use tiny_keccak::{Hasher, Keccak};
 
 use crate::error::DecodeError;
+use crate::prelude::*;
 
 /// Parsed function signature.
 #[derive(Debug, Clone)]
@@ -31,6 +32,11 @@ pub enum ParamType {
 }
 
 impl ParamType {
+    /// Canonical ABI type string, as used in selector computation (e.g. `(address,uint256)[]`).
+    pub fn canonical(&self) -> String {
+        canonical_param(self)
+    }
+
     /// Whether this type is dynamically-sized in ABI encoding.
     pub fn is_dynamic(&self) -> bool {
         match self {
@@ -49,6 +55,8 @@ pub struct DecodedArguments {
     pub function_name: String,
     pub selector: [u8; 4],
     pub args: Vec<DecodedArgument>,
+    /// Non-canonical encodings accepted in [`DecodeMode::Lenient`].
+    pub warnings: Vec<DecodeError>,
 }
 
 /// A single decoded argument.
@@ -61,7 +69,7 @@ pub struct DecodedArgument {
 }
 
 /// Decoded argument values.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ArgumentValue {
     Address([u8; 20]),
     Uint(Vec<u8>),
@@ -69,7 +77,7 @@ pub enum ArgumentValue {
     Bool(bool),
     Bytes(Vec<u8>),
     FixedBytes(Vec<u8>),
-    String(std::string::String),
+    String(String),
     Array(Vec<ArgumentValue>),
     Tuple(Vec<ArgumentValue>),
 }
@@ -119,6 +127,9 @@ impl ArgumentValue {
     }
 }
 
+/// Types nested deeper than this (arrays and tuples) are rejected; real ABIs stay far below it.
+pub const MAX_TYPE_DEPTH: usize = 64;
+
 /// Parse a function signature string into a `FunctionSignature`.
 ///
 /// Example: `"transfer(address,uint256)"` → name="transfer", params=[Address, Uint(256)]
@@ -164,28 +175,28 @@ pub fn parse_signature(sig: &str) -> Result<FunctionSignature, DecodeError> {
 }
 
 /// Parse a comma-separated list of param types, respecting nested parentheses for tuples.
-fn parse_param_list(s: &str) -> Result<Vec<ParamType>, DecodeError> {
+fn parse_param_list(s: &str, depth: usize) -> Result<Vec<ParamType>, DecodeError> {
     let mut result = Vec::new();
-    let mut depth = 0usize;
+    let mut nesting = 0usize;
     let mut start = 0;
 
     for (i, c) in s.char_indices() {
         match c {
-            '(' => depth += 1,
+            '(' => nesting += 1,
             ')' => {
-                depth = depth
+                nesting = nesting
                     .checked_sub(1)
                     .ok_or_else(|| DecodeError::InvalidSignature("unbalanced ')'".to_string()))?;
             }
-            ',' if depth == 0 => {
-                result.push(parse_param_type(s[start..i].trim())?);
+            ',' if nesting == 0 => {
+                result.push(parse_param_type(s[start..i].trim(), depth)?);
                 start = i + 1;
             }
             _ => {}
         }
     }
 
-    if depth != 0 {
+    if nesting != 0 {
         return Err(DecodeError::InvalidSignature(
             "unbalanced parentheses".to_string(),
         ));
@@ -193,7 +204,7 @@ fn parse_param_list(s: &str) -> Result<Vec<ParamType>, DecodeError> {
 
     let last = s[start..].trim();
     if !last.is_empty() {
-        result.push(parse_param_type(last)?);
+        result.push(parse_param_type(last, depth)?);
     }
 
     Ok(result)
@@ -243,33 +254,38 @@ fn parse_param_with_name(s: &str) -> Result<(ParamType, Option<String>), DecodeE
     if let Some(pos) = s.rfind([')', ']']) {
         let after = s[pos + 1..].trim();
         if after.is_empty() {
-            return Ok((parse_param_type(s)?, None));
+            return Ok((parse_param_type(s, 0)?, None));
         }
         let type_str = &s[..pos + 1];
-        return Ok((parse_param_type(type_str)?, Some(after.to_string())));
+        return Ok((parse_param_type(type_str, 0)?, Some(after.to_string())));
     }
 
     // For simple types: split on first space
     if let Some(space_pos) = s.find(' ') {
         let type_str = &s[..space_pos];
         let name = s[space_pos..].trim();
-        return Ok((parse_param_type(type_str)?, Some(name.to_string())));
+        return Ok((parse_param_type(type_str, 0)?, Some(name.to_string())));
     }
 
     // No space, no name
-    Ok((parse_param_type(s)?, None))
+    Ok((parse_param_type(s, 0)?, None))
 }
 
-/// Parse a single param type string.
-fn parse_param_type(s: &str) -> Result<ParamType, DecodeError> {
+/// Parse a single param type string nested `depth` arrays or tuples deep.
+fn parse_param_type(s: &str, depth: usize) -> Result<ParamType, DecodeError> {
     let s = s.trim();
+    if depth > MAX_TYPE_DEPTH {
+        return Err(DecodeError::InvalidSignature(format!(
+            "types nested deeper than {MAX_TYPE_DEPTH}"
+        )));
+    }
 
     // Handle array suffixes: `type[]` or `type[N]`
     if let Some(bracket_pos) = s.rfind('[') {
         if s.ends_with(']') {
             let inner_str = &s[..bracket_pos];
             let size_str = &s[bracket_pos + 1..s.len() - 1];
-            let inner = parse_param_type(inner_str)?;
+            let inner = parse_param_type(inner_str, depth + 1)?;
 
             if size_str.is_empty() {
                 return Ok(ParamType::Array(Box::new(inner)));
@@ -288,7 +304,7 @@ fn parse_param_type(s: &str) -> Result<ParamType, DecodeError> {
         let members = if inner.is_empty() {
             vec![]
         } else {
-            parse_param_list(inner)?
+            parse_param_list(inner, depth + 1)?
         };
         return Ok(ParamType::Tuple(members));
     }
@@ -369,10 +385,70 @@ pub fn selector_from_signature(canonical: &str) -> [u8; 4] {
     [hash[0], hash[1], hash[2], hash[3]]
 }
 
-/// Decode calldata using a parsed function signature.
+/// How [`decode_calldata_with_mode`] treats encodings a canonical ABI encoder never
+/// produces: dirty address bytes, bools other than 0 or 1, out-of-range integers,
+/// non-zero padding, misplaced tail offsets and trailing bytes. Contracts may read
+/// such calldata differently from how it is displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum DecodeMode {
+    /// Decode anyway and record each problem in [`DecodedArguments::warnings`].
+    #[default]
+    Lenient,
+    /// Fail with the first problem.
+    Strict,
+}
+
+/// Resource limits for decoding untrusted calldata.
+///
+/// Offsets and lengths are attacker-controlled: heads may all point at one large tail,
+/// and arrays of zero-sized elements claim any length without occupying calldata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// Maximum nesting of arrays and tuples.
+    pub max_depth: usize,
+    /// Maximum number of decoded values, counting array elements and tuple members.
+    pub max_elements: usize,
+    /// Maximum number of bytes copied into decoded values.
+    pub max_bytes: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 32,
+            max_elements: 65_536,
+            max_bytes: 4 << 20,
+        }
+    }
+}
+
+/// Decode calldata using a parsed function signature, in [`DecodeMode::Lenient`].
 pub fn decode_calldata(
     sig: &FunctionSignature,
     calldata: &[u8],
+) -> Result<DecodedArguments, DecodeError> {
+    decode_calldata_with_mode(sig, calldata, DecodeMode::Lenient)
+}
+
+/// Decode calldata using a parsed function signature, within the default [`DecodeLimits`].
+///
+/// Byte positions in errors and warnings are offsets into `calldata`.
+pub fn decode_calldata_with_mode(
+    sig: &FunctionSignature,
+    calldata: &[u8],
+    mode: DecodeMode,
+) -> Result<DecodedArguments, DecodeError> {
+    decode_calldata_with_limits(sig, calldata, mode, DecodeLimits::default())
+}
+
+/// Decode calldata using a parsed function signature, failing with
+/// [`DecodeError::LimitExceeded`] once decoding would exceed `limits`.
+pub fn decode_calldata_with_limits(
+    sig: &FunctionSignature,
+    calldata: &[u8],
+    mode: DecodeMode,
+    limits: DecodeLimits,
 ) -> Result<DecodedArguments, DecodeError> {
     if calldata.len() < 4 {
         return Err(DecodeError::CalldataTooShort {
@@ -389,122 +465,282 @@ pub fn decode_calldata(
         });
     }
 
-    let data = &calldata[4..];
-    let mut args = Vec::with_capacity(sig.params.len());
+    let mut decoder = Decoder {
+        data: calldata,
+        mode,
+        warnings: Vec::new(),
+        limits,
+        depth: 0,
+        elements: 0,
+        bytes: 0,
+    };
+    let (values, end) = decoder.sequence(sig.params.iter(), heads_len(&sig.params), 4)?;
+    if calldata.len() > end {
+        decoder.flag(DecodeError::TrailingBytes {
+            len: calldata.len() - end,
+        })?;
+    }
 
-    // Decode head section
-    let mut offset = 0;
-    for (i, param) in sig.params.iter().enumerate() {
-        let value = decode_value(param, data, offset)?;
-        args.push(DecodedArgument {
+    let args = sig
+        .params
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(i, (param, value))| DecodedArgument {
             index: i,
             name: sig.param_names.get(i).cloned().flatten(),
             param_type: param.clone(),
             value,
-        });
-        offset += 32; // Each head entry is 32 bytes (value or offset pointer)
-    }
+        })
+        .collect();
 
     Ok(DecodedArguments {
         function_name: sig.name.clone(),
         selector: sig.selector,
         args,
+        warnings: decoder.warnings,
     })
 }
 
-/// Decode a single value from ABI-encoded data.
-fn decode_value(
-    param: &ParamType,
-    data: &[u8],
-    head_offset: usize,
-) -> Result<ArgumentValue, DecodeError> {
-    if param.is_dynamic() {
-        // Dynamic types: head contains offset to tail
-        let offset = read_u256_as_usize(data, head_offset)?;
-        decode_value_at(param, data, offset)
-    } else {
-        decode_value_at(param, data, head_offset)
+/// Size of a value's head: 32 bytes for an offset, or the whole inline encoding.
+fn head_size(param: &ParamType) -> usize {
+    match param {
+        _ if param.is_dynamic() => 32,
+        ParamType::FixedArray(inner, len) => head_size(inner).saturating_mul(*len),
+        ParamType::Tuple(members) => heads_len(members),
+        _ => 32,
     }
 }
 
-/// Decode a value at a specific byte offset.
-fn decode_value_at(
-    param: &ParamType,
-    data: &[u8],
-    offset: usize,
-) -> Result<ArgumentValue, DecodeError> {
-    ensure_bytes(data, offset, 32)?;
+fn heads_len(params: &[ParamType]) -> usize {
+    params
+        .iter()
+        .fold(0, |size, param| size.saturating_add(head_size(param)))
+}
 
-    match param {
-        ParamType::Address => {
-            let word = &data[offset..offset + 32];
-            let mut addr = [0u8; 20];
-            addr.copy_from_slice(&word[12..32]);
-            Ok(ArgumentValue::Address(addr))
-        }
-        ParamType::Uint(_) | ParamType::Int(_) => {
-            let word = data[offset..offset + 32].to_vec();
-            if matches!(param, ParamType::Uint(_)) {
-                Ok(ArgumentValue::Uint(word))
-            } else {
-                Ok(ArgumentValue::Int(word))
+struct Decoder<'a> {
+    data: &'a [u8],
+    mode: DecodeMode,
+    warnings: Vec<DecodeError>,
+    limits: DecodeLimits,
+    /// Arrays and tuples currently being decoded.
+    depth: usize,
+    /// Values decoded so far.
+    elements: usize,
+    /// Bytes copied into decoded values so far.
+    bytes: usize,
+}
+
+impl Decoder<'_> {
+    /// Reject a non-canonical encoding, or record it and carry on.
+    fn flag(&mut self, problem: DecodeError) -> Result<(), DecodeError> {
+        match self.mode {
+            DecodeMode::Strict => Err(problem),
+            DecodeMode::Lenient => {
+                self.warnings.push(problem);
+                Ok(())
             }
         }
-        ParamType::Bool => {
-            let b = data[offset + 31] != 0;
-            Ok(ArgumentValue::Bool(b))
-        }
-        ParamType::FixedBytes(size) => {
-            let bytes = data[offset..offset + size].to_vec();
-            Ok(ArgumentValue::FixedBytes(bytes))
-        }
-        ParamType::Bytes => {
-            let len = read_u256_as_usize(data, offset)?;
-            let start = offset + 32;
-            ensure_bytes(data, start, len)?;
-            Ok(ArgumentValue::Bytes(data[start..start + len].to_vec()))
-        }
-        ParamType::String => {
-            let len = read_u256_as_usize(data, offset)?;
-            let start = offset + 32;
-            ensure_bytes(data, start, len)?;
-            let s = std::str::from_utf8(&data[start..start + len])
-                .map_err(|e| DecodeError::InvalidEncoding(format!("invalid UTF-8: {e}")))?;
-            Ok(ArgumentValue::String(s.to_string()))
-        }
-        ParamType::Array(inner) => {
-            let len = read_u256_as_usize(data, offset)?;
-            let elements_start = offset + 32;
-            decode_array_elements(inner, data, elements_start, len)
-        }
-        ParamType::FixedArray(inner, len) => decode_array_elements(inner, data, offset, *len),
-        ParamType::Tuple(members) => {
-            let mut values = Vec::with_capacity(members.len());
-            let mut member_offset = offset;
-            for member in members {
-                let value = decode_value(member, data, member_offset)?;
+    }
+
+    /// Decode consecutive values whose `heads_len` bytes of heads start at `start`;
+    /// dynamic tails are located relative to `start`, as in a tuple or array encoding.
+    ///
+    /// Returns the values and the end of the encoding.
+    fn sequence<'p>(
+        &mut self,
+        params: impl Iterator<Item = &'p ParamType>,
+        heads_len: usize,
+        start: usize,
+    ) -> Result<(Vec<ArgumentValue>, usize), DecodeError> {
+        // Canonically each tail starts where the previous one ended
+        let mut end = start.saturating_add(heads_len);
+        let mut head = start;
+        let mut values = Vec::new();
+        for param in params {
+            if param.is_dynamic() {
+                let offset = start
+                    .checked_add(read_u256_as_usize(self.data, head)?)
+                    .ok_or_else(|| DecodeError::InvalidEncoding("offset overflow".to_string()))?;
+                if offset != end {
+                    self.flag(DecodeError::NonCanonicalOffset {
+                        at: head,
+                        expected: end,
+                        actual: offset,
+                    })?;
+                }
+                let (value, tail_end) = self.value_at(param, offset)?;
                 values.push(value);
-                member_offset += 32;
+                end = end.max(tail_end);
+            } else {
+                values.push(self.value_at(param, head)?.0);
             }
-            Ok(ArgumentValue::Tuple(values))
+            head = head.saturating_add(head_size(param));
         }
+        Ok((values, end))
+    }
+
+    /// Decode a value encoded at `offset`, returning it and the end of its encoding.
+    fn value_at(
+        &mut self,
+        param: &ParamType,
+        offset: usize,
+    ) -> Result<(ArgumentValue, usize), DecodeError> {
+        // Static tuples and fixed arrays may be zero-sized; their members check themselves
+        match param {
+            ParamType::Tuple(members) => {
+                let (values, end) = self.nested(members.iter(), heads_len(members), offset)?;
+                return Ok((ArgumentValue::Tuple(values), end));
+            }
+            ParamType::FixedArray(inner, len) => {
+                let elements = core::iter::repeat_n(&**inner, *len);
+                let heads = head_size(inner).saturating_mul(*len);
+                let (values, end) = self.nested(elements, heads, offset)?;
+                return Ok((ArgumentValue::Array(values), end));
+            }
+            _ => ensure_bytes(self.data, offset, 32)?,
+        }
+        let data = self.data;
+        let word = &data[offset..offset + 32];
+        let end = offset + 32;
+
+        let value = match param {
+            ParamType::Address => {
+                if word[..12].iter().any(|b| *b != 0) {
+                    self.flag(DecodeError::DirtyAddress { at: offset })?;
+                }
+                let mut addr = [0u8; 20];
+                addr.copy_from_slice(&word[12..32]);
+                ArgumentValue::Address(addr)
+            }
+            ParamType::Uint(bits) => {
+                let width = value_width(*bits).unwrap_or(32);
+                if word[..32 - width].iter().any(|b| *b != 0) {
+                    self.out_of_range(param, offset)?;
+                }
+                self.reserve_bytes(32)?;
+                ArgumentValue::Uint(word.to_vec())
+            }
+            ParamType::Int(bits) => {
+                let (high, low) = word.split_at(32 - value_width(*bits).unwrap_or(32));
+                let sign = if low[0] & 0x80 != 0 { 0xff } else { 0 };
+                if high.iter().any(|b| *b != sign) {
+                    self.out_of_range(param, offset)?;
+                }
+                self.reserve_bytes(32)?;
+                ArgumentValue::Int(word.to_vec())
+            }
+            ParamType::Bool => {
+                if word[..31].iter().any(|b| *b != 0) || word[31] > 1 {
+                    self.flag(DecodeError::InvalidBool { at: offset })?;
+                }
+                // Any non-zero word is true to the EVM
+                ArgumentValue::Bool(word.iter().any(|b| *b != 0))
+            }
+            ParamType::FixedBytes(size) => {
+                ensure_bytes(data, offset, *size)?;
+                if let Some(dirty) = word.iter().skip(*size).position(|b| *b != 0) {
+                    self.flag(DecodeError::DirtyPadding {
+                        at: offset + size + dirty,
+                    })?;
+                }
+                self.reserve_bytes(*size)?;
+                ArgumentValue::FixedBytes(data[offset..offset + size].to_vec())
+            }
+            ParamType::Bytes | ParamType::String => {
+                let len = read_u256_as_usize(data, offset)?;
+                let start = end;
+                ensure_bytes(data, start, len)?;
+                let bytes = &data[start..start + len];
+                let padded_end = len
+                    .checked_next_multiple_of(32)
+                    .and_then(|padded| start.checked_add(padded))
+                    .ok_or_else(|| DecodeError::InvalidEncoding("length overflow".to_string()))?;
+                if padded_end > data.len() {
+                    self.flag(DecodeError::CalldataTooShort {
+                        expected: padded_end,
+                        actual: data.len(),
+                    })?;
+                } else if let Some(dirty) =
+                    data[start + len..padded_end].iter().position(|b| *b != 0)
+                {
+                    self.flag(DecodeError::DirtyPadding {
+                        at: start + len + dirty,
+                    })?;
+                }
+                self.reserve_bytes(len)?;
+                let value = if matches!(param, ParamType::String) {
+                    let s = core::str::from_utf8(bytes)
+                        .map_err(|e| DecodeError::InvalidEncoding(format!("invalid UTF-8: {e}")))?;
+                    ArgumentValue::String(s.to_string())
+                } else {
+                    ArgumentValue::Bytes(bytes.to_vec())
+                };
+                return Ok((value, padded_end.min(data.len())));
+            }
+            ParamType::Array(inner) => {
+                let len = read_u256_as_usize(data, offset)?;
+                let elements = core::iter::repeat_n(&**inner, len);
+                let heads = head_size(inner).saturating_mul(len);
+                let (values, end) = self.nested(elements, heads, end)?;
+                return Ok((ArgumentValue::Array(values), end));
+            }
+            ParamType::Tuple(_) | ParamType::FixedArray(..) => unreachable!("handled above"),
+        };
+        Ok((value, end))
     }
-}
 
-fn decode_array_elements(
-    inner: &ParamType,
-    data: &[u8],
-    offset: usize,
-    len: usize,
-) -> Result<ArgumentValue, DecodeError> {
-    let mut values = Vec::with_capacity(len);
-    let mut elem_offset = offset;
-    for _ in 0..len {
-        let value = decode_value(inner, data, elem_offset)?;
-        values.push(value);
-        elem_offset += 32;
+    /// Account for `count` more values before decoding them.
+    fn reserve_elements(&mut self, count: usize) -> Result<(), DecodeError> {
+        self.elements = self.elements.saturating_add(count);
+        if self.elements > self.limits.max_elements {
+            return Err(DecodeError::LimitExceeded {
+                limit: "element count",
+                max: self.limits.max_elements,
+            });
+        }
+        Ok(())
+    }
+
+    /// Account for `len` more bytes before copying them.
+    fn reserve_bytes(&mut self, len: usize) -> Result<(), DecodeError> {
+        self.bytes = self.bytes.saturating_add(len);
+        if self.bytes > self.limits.max_bytes {
+            return Err(DecodeError::LimitExceeded {
+                limit: "decoded size",
+                max: self.limits.max_bytes,
+            });
+        }
+        Ok(())
+    }
+
+    /// Decode the members of an array or tuple one level deeper.
+    fn nested<'p>(
+        &mut self,
+        params: impl ExactSizeIterator<Item = &'p ParamType>,
+        heads_len: usize,
+        start: usize,
+    ) -> Result<(Vec<ArgumentValue>, usize), DecodeError> {
+        if self.depth >= self.limits.max_depth {
+            return Err(DecodeError::LimitExceeded {
+                limit: "nesting depth",
+                max: self.limits.max_depth,
+            });
+        }
+        // Claimed lengths are checked before any element is decoded
+        self.reserve_elements(params.len())?;
+        self.depth += 1;
+        let decoded = self.sequence(params, heads_len, start)?;
+        self.depth -= 1;
+        Ok(decoded)
+    }
+
+    fn out_of_range(&mut self, param: &ParamType, at: usize) -> Result<(), DecodeError> {
+        self.flag(DecodeError::ValueOutOfRange {
+            param: param.canonical(),
+            at,
+        })
     }
-    Ok(ArgumentValue::Array(values))
 }
 
 fn read_u256_as_usize(data: &[u8], offset: usize) -> Result<usize, DecodeError> {
@@ -520,17 +756,253 @@ fn read_u256_as_usize(data: &[u8], offset: usize) -> Result<usize, DecodeError>
     }
     let mut bytes = [0u8; 8];
     bytes.copy_from_slice(&word[24..32]);
-    Ok(u64::from_be_bytes(bytes) as usize)
+    usize::try_from(u64::from_be_bytes(bytes))
+        .map_err(|_| DecodeError::InvalidEncoding("offset too large for usize".to_string()))
 }
 
 fn ensure_bytes(data: &[u8], offset: usize, len: usize) -> Result<(), DecodeError> {
-    if offset + len > data.len() {
-        Err(DecodeError::CalldataTooShort {
-            expected: offset + len,
+    match offset.checked_add(len) {
+        Some(end) if end <= data.len() => Ok(()),
+        _ => Err(DecodeError::CalldataTooShort {
+            expected: offset.saturating_add(len),
             actual: data.len(),
-        })
+        }),
+    }
+}
+
+/// Decode caller-supplied hex such as calldata or a value: an optional `0x`/`0X`
+/// prefix and surrounding whitespace are ignored, and odd lengths get a leading
+/// zero (`0x0` is one zero byte). Shared by the FFI layers.
+#[cfg(any(feature = "capi", feature = "uniffi", feature = "wasm"))]
+pub(crate) fn decode_hex(input: &str) -> Result<Vec<u8>, hex::FromHexError> {
+    let trimmed = input.trim();
+    let digits = trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+        .unwrap_or(trimmed);
+    if digits.len().is_multiple_of(2) {
+        hex::decode(digits)
     } else {
-        Ok(())
+        hex::decode(format!("0{digits}"))
+    }
+}
+
+/// ABI-encode `args` as a call to `sig`: the selector followed by the head/tail
+/// encoding of the arguments.
+///
+/// Values must match the parameter types: `Uint`/`Int` hold big-endian words of at
+/// most 32 bytes that fit the declared width (`Int` is sign-extended), `FixedBytes`
+/// has exactly the declared length, and fixed arrays and tuples the declared arity.
+pub fn encode_calldata(
+    sig: &FunctionSignature,
+    args: &[ArgumentValue],
+) -> Result<Vec<u8>, DecodeError> {
+    let mut calldata = sig.selector.to_vec();
+    calldata.extend(encode_arguments(&sig.params, args)?);
+    Ok(calldata)
+}
+
+/// ABI-encode values as a tuple of `params`, without a selector.
+pub fn encode_arguments(
+    params: &[ParamType],
+    values: &[ArgumentValue],
+) -> Result<Vec<u8>, DecodeError> {
+    if params.len() != values.len() {
+        return Err(DecodeError::InvalidValue(format!(
+            "expected {} arguments, got {}",
+            params.len(),
+            values.len()
+        )));
+    }
+    encode_sequence(params.iter().zip(values))
+}
+
+fn encode_sequence<'a>(
+    items: impl Iterator<Item = (&'a ParamType, &'a ArgumentValue)> + Clone,
+) -> Result<Vec<u8>, DecodeError> {
+    let heads_len: usize = items.clone().map(|(param, _)| head_size(param)).sum();
+    let mut head = Vec::with_capacity(heads_len);
+    let mut tail = Vec::new();
+    for (param, value) in items {
+        let encoded = encode_value(param, value)?;
+        if param.is_dynamic() {
+            head.extend_from_slice(&usize_word(heads_len + tail.len()));
+            tail.extend(encoded);
+        } else {
+            head.extend(encoded);
+        }
+    }
+    head.extend(tail);
+    Ok(head)
+}
+
+fn encode_value(param: &ParamType, value: &ArgumentValue) -> Result<Vec<u8>, DecodeError> {
+    let mismatch = || {
+        DecodeError::InvalidValue(format!(
+            "{} cannot be encoded as {}",
+            value.to_json_value(),
+            param.canonical()
+        ))
+    };
+    match (param, value) {
+        (ParamType::Address, ArgumentValue::Address(addr)) => {
+            let mut word = vec![0u8; 12];
+            word.extend_from_slice(addr);
+            Ok(word)
+        }
+        (ParamType::Uint(bits), ArgumentValue::Uint(bytes)) => {
+            let word = extend_word(bytes, 0).ok_or_else(mismatch)?;
+            let (high, _) = word.split_at(32 - value_width(*bits).ok_or_else(mismatch)?);
+            if high.iter().any(|b| *b != 0) {
+                return Err(mismatch());
+            }
+            Ok(word.to_vec())
+        }
+        (ParamType::Int(bits), ArgumentValue::Int(bytes)) => {
+            let fill = match bytes.first() {
+                Some(b) if b & 0x80 != 0 => 0xff,
+                _ => 0,
+            };
+            let word = extend_word(bytes, fill).ok_or_else(mismatch)?;
+            let (high, low) = word.split_at(32 - value_width(*bits).ok_or_else(mismatch)?);
+            let sign = if low[0] & 0x80 != 0 { 0xff } else { 0 };
+            if high.iter().any(|b| *b != sign) {
+                return Err(mismatch());
+            }
+            Ok(word.to_vec())
+        }
+        (ParamType::Bool, ArgumentValue::Bool(b)) => Ok(usize_word(*b as usize).to_vec()),
+        (ParamType::FixedBytes(size), ArgumentValue::FixedBytes(bytes))
+            if (1..=32).contains(size) && bytes.len() == *size =>
+        {
+            let mut word = bytes.clone();
+            word.resize(32, 0);
+            Ok(word)
+        }
+        (ParamType::Bytes, ArgumentValue::Bytes(bytes)) => Ok(encode_bytes(bytes)),
+        (ParamType::String, ArgumentValue::String(s)) => Ok(encode_bytes(s.as_bytes())),
+        (ParamType::Array(inner), ArgumentValue::Array(items)) => {
+            let mut encoded = usize_word(items.len()).to_vec();
+            encoded.extend(encode_sequence(core::iter::repeat(&**inner).zip(items))?);
+            Ok(encoded)
+        }
+        (ParamType::FixedArray(inner, len), ArgumentValue::Array(items)) if items.len() == *len => {
+            encode_sequence(core::iter::repeat(&**inner).zip(items))
+        }
+        (ParamType::Tuple(members), ArgumentValue::Tuple(items))
+            if items.len() == members.len() =>
+        {
+            encode_sequence(members.iter().zip(items))
+        }
+        _ => Err(mismatch()),
+    }
+}
+
+/// Byte width of a `uintN`/`intN`, if `bits` is a valid ABI width.
+fn value_width(bits: usize) -> Option<usize> {
+    (bits.is_multiple_of(8) && (8..=256).contains(&bits)).then_some(bits / 8)
+}
+
+/// Left-pad a big-endian value of at most 32 bytes to a word with `fill`.
+fn extend_word(bytes: &[u8], fill: u8) -> Option<[u8; 32]> {
+    let start = 32usize.checked_sub(bytes.len())?;
+    let mut word = [fill; 32];
+    word[start..].copy_from_slice(bytes);
+    Some(word)
+}
+
+fn usize_word(n: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(n as u64).to_be_bytes());
+    word
+}
+
+/// Length word followed by the bytes, zero-padded to a whole number of words.
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut encoded = usize_word(bytes.len()).to_vec();
+    encoded.extend_from_slice(bytes);
+    encoded.resize(32 + bytes.len().div_ceil(32) * 32, 0);
+    encoded
+}
+
+/// Builds calldata for a signature one argument at a time.
+///
+/// ```
+/// use erc7730::decoder::CalldataBuilder;
+///
+/// let calldata = CalldataBuilder::new("transfer(address,uint256)")
+///     .address("0x1111111111111111111111111111111111111111")
+///     .uint(1_000_000)
+///     .build()
+///     .unwrap();
+/// assert_eq!(calldata.len(), 4 + 2 * 32);
+/// ```
+pub struct CalldataBuilder {
+    sig: Result<FunctionSignature, DecodeError>,
+    args: Vec<ArgumentValue>,
+    error: Option<DecodeError>,
+}
+
+impl CalldataBuilder {
+    /// Start building a call to `signature`; parse errors surface from [`build`](Self::build).
+    pub fn new(signature: &str) -> Self {
+        Self {
+            sig: parse_signature(signature),
+            args: Vec::new(),
+            error: None,
+        }
+    }
+
+    pub fn arg(mut self, value: ArgumentValue) -> Self {
+        self.args.push(value);
+        self
+    }
+
+    /// A hex address, with or without `0x`.
+    pub fn address(mut self, address: &str) -> Self {
+        let hex_str = address.strip_prefix("0x").unwrap_or(address);
+        let mut addr = [0u8; 20];
+        match hex::decode_to_slice(hex_str, &mut addr) {
+            Ok(()) => self.arg(ArgumentValue::Address(addr)),
+            Err(_) => {
+                self.error.get_or_insert_with(|| {
+                    DecodeError::InvalidValue(format!("invalid address: {address}"))
+                });
+                self
+            }
+        }
+    }
+
+    pub fn uint(self, value: u128) -> Self {
+        self.arg(ArgumentValue::Uint(value.to_be_bytes().to_vec()))
+    }
+
+    pub fn int(self, value: i128) -> Self {
+        self.arg(ArgumentValue::Int(value.to_be_bytes().to_vec()))
+    }
+
+    pub fn bool(self, value: bool) -> Self {
+        self.arg(ArgumentValue::Bool(value))
+    }
+
+    pub fn bytes(self, value: &[u8]) -> Self {
+        self.arg(ArgumentValue::Bytes(value.to_vec()))
+    }
+
+    pub fn fixed_bytes(self, value: &[u8]) -> Self {
+        self.arg(ArgumentValue::FixedBytes(value.to_vec()))
+    }
+
+    pub fn string(self, value: &str) -> Self {
+        self.arg(ArgumentValue::String(value.to_string()))
+    }
+
+    /// Encode the call, failing on the first invalid signature, address or value.
+    pub fn build(self) -> Result<Vec<u8>, DecodeError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        encode_calldata(&self.sig?, &self.args)
     }
 }
 
@@ -705,4 +1177,454 @@ mod tests {
         assert_eq!(named.selector, unnamed.selector);
         assert_eq!(named.canonical, unnamed.canonical);
     }
+
+    fn words(selector: &str, words: &[&str]) -> Vec<u8> {
+        let mut hex_str = selector.to_string();
+        for word in words {
+            hex_str.push_str(&format!("{word:0>64}"));
+        }
+        hex::decode(hex_str).unwrap()
+    }
+
+    fn values(decoded: DecodedArguments) -> Vec<ArgumentValue> {
+        decoded.args.into_iter().map(|arg| arg.value).collect()
+    }
+
+    fn uint(n: u64) -> ArgumentValue {
+        let mut word = vec![0u8; 24];
+        word.extend_from_slice(&n.to_be_bytes());
+        ArgumentValue::Uint(word)
+    }
+
+    #[test]
+    fn test_encode_solidity_docs_dynamic_example() {
+        let sig = parse_signature("f(uint256,uint32[],bytes10,bytes)").unwrap();
+        let args = vec![
+            uint(0x123),
+            ArgumentValue::Array(vec![uint(0x456), uint(0x789)]),
+            ArgumentValue::FixedBytes(b"1234567890".to_vec()),
+            ArgumentValue::Bytes(b"Hello, world!".to_vec()),
+        ];
+        let expected = words(
+            "8be65246",
+            &[
+                "123",
+                "80",
+                "3132333435363738393000000000000000000000000000000000000000000000",
+                "e0",
+                "2",
+                "456",
+                "789",
+                "d",
+                "48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+            ],
+        );
+        let calldata = encode_calldata(&sig, &args).unwrap();
+        assert_eq!(hex::encode(&calldata), hex::encode(&expected));
+        assert_eq!(values(decode_calldata(&sig, &calldata).unwrap()), args);
+    }
+
+    #[test]
+    fn test_nested_dynamic_offsets_are_relative() {
+        let sig = parse_signature("g(uint256[][],string[])").unwrap();
+        let args = vec![
+            ArgumentValue::Array(vec![
+                ArgumentValue::Array(vec![uint(1), uint(2)]),
+                ArgumentValue::Array(vec![uint(3)]),
+            ]),
+            ArgumentValue::Array(
+                ["one", "two", "three"]
+                    .iter()
+                    .map(|s| ArgumentValue::String(s.to_string()))
+                    .collect(),
+            ),
+        ];
+        let expected = words(
+            "2289b18c",
+            &[
+                "40",
+                "140",
+                "2",
+                "40",
+                "a0",
+                "2",
+                "1",
+                "2",
+                "1",
+                "3",
+                "3",
+                "60",
+                "a0",
+                "e0",
+                "3",
+                "6f6e650000000000000000000000000000000000000000000000000000000000",
+                "3",
+                "74776f0000000000000000000000000000000000000000000000000000000000",
+                "5",
+                "7468726565000000000000000000000000000000000000000000000000000000",
+            ],
+        );
+        assert_eq!(values(decode_calldata(&sig, &expected).unwrap()), args);
+        assert_eq!(encode_calldata(&sig, &args).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_static_tuple_members_are_inline() {
+        let sig = parse_signature("h((uint8,(bool,address))[2],uint16)").unwrap();
+        let pair = |n| {
+            ArgumentValue::Tuple(vec![
+                uint(n),
+                ArgumentValue::Tuple(vec![
+                    ArgumentValue::Bool(true),
+                    ArgumentValue::Address([n as u8; 20]),
+                ]),
+            ])
+        };
+        let args = vec![ArgumentValue::Array(vec![pair(1), pair(2)]), uint(7)];
+        let calldata = encode_calldata(&sig, &args).unwrap();
+        assert_eq!(calldata.len(), 4 + 7 * 32);
+        assert_eq!(values(decode_calldata(&sig, &calldata).unwrap()), args);
+    }
+
+    #[test]
+    fn test_encode_rejects_mismatched_values() {
+        let sig = parse_signature("f(uint8,int8,bytes2,address[2])").unwrap();
+        let valid = vec![
+            ArgumentValue::Uint(vec![0xff]),
+            ArgumentValue::Int(vec![0x80]),
+            ArgumentValue::FixedBytes(vec![1, 2]),
+            ArgumentValue::Array(vec![ArgumentValue::Address([0; 20]); 2]),
+        ];
+        let calldata = encode_calldata(&sig, &valid).unwrap();
+        assert_eq!(calldata[4 + 63], 0x80);
+        assert!(calldata[4 + 32..4 + 63].iter().all(|b| *b == 0xff));
+
+        let with = |index: usize, value: ArgumentValue| {
+            let mut args = valid.clone();
+            args[index] = value;
+            encode_calldata(&sig, &args)
+        };
+        assert!(with(0, ArgumentValue::Uint(vec![1, 0])).is_err());
+        assert!(with(0, ArgumentValue::Bool(true)).is_err());
+        assert!(with(1, ArgumentValue::Int(vec![0x00, 0x80])).is_err());
+        assert!(with(1, ArgumentValue::Int(vec![0xff, 0x80])).is_ok());
+        assert!(with(2, ArgumentValue::FixedBytes(vec![1])).is_err());
+        assert!(with(3, ArgumentValue::Array(vec![])).is_err());
+        assert!(matches!(
+            encode_calldata(&sig, &valid[..3]),
+            Err(DecodeError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_calldata_builder() {
+        let calldata = CalldataBuilder::new("f(address,uint256,int256,bool,bytes,bytes4,string)")
+            .address("1111111111111111111111111111111111111111")
+            .uint(1000)
+            .int(-1)
+            .bool(true)
+            .bytes(&[0xab; 33])
+            .fixed_bytes(&[1, 2, 3, 4])
+            .string("hi")
+            .build()
+            .unwrap();
+        let sig = parse_signature("f(address,uint256,int256,bool,bytes,bytes4,string)").unwrap();
+        let decoded = values(decode_calldata(&sig, &calldata).unwrap());
+        assert_eq!(decoded[0], ArgumentValue::Address([0x11; 20]));
+        assert_eq!(decoded[1], uint(1000));
+        assert_eq!(decoded[2], ArgumentValue::Int(vec![0xff; 32]));
+        assert_eq!(decoded[4], ArgumentValue::Bytes(vec![0xab; 33]));
+
+        assert!(CalldataBuilder::new("f(address)")
+            .address("0x123")
+            .build()
+            .is_err());
+        assert!(CalldataBuilder::new("f(").build().is_err());
+        assert!(CalldataBuilder::new("f(uint8)").uint(256).build().is_err());
+    }
+
+    /// Decode `calldata` strictly and leniently; the strict error must be the
+    /// lenient run's only warning.
+    fn non_canonical(signature: &str, calldata: &[u8]) -> (DecodeError, Vec<ArgumentValue>) {
+        let sig = parse_signature(signature).unwrap();
+        let strict = decode_calldata_with_mode(&sig, calldata, DecodeMode::Strict).unwrap_err();
+        let lenient = decode_calldata(&sig, calldata).unwrap();
+        assert_eq!(lenient.warnings, vec![strict.clone()]);
+        (strict, values(lenient))
+    }
+
+    #[test]
+    fn test_strict_rejects_dirty_address() {
+        let mut calldata = CalldataBuilder::new("f(address)")
+            .address("0x1111111111111111111111111111111111111111")
+            .build()
+            .unwrap();
+        calldata[4] = 0xff;
+        let (error, values) = non_canonical("f(address)", &calldata);
+        assert_eq!(error, DecodeError::DirtyAddress { at: 4 });
+        assert_eq!(values, vec![ArgumentValue::Address([0x11; 20])]);
+    }
+
+    #[test]
+    fn test_strict_rejects_invalid_bool() {
+        let mut calldata = CalldataBuilder::new("f(bool)").bool(false).build().unwrap();
+        calldata[4] = 1;
+        let (error, values) = non_canonical("f(bool)", &calldata);
+        assert_eq!(error, DecodeError::InvalidBool { at: 4 });
+        // The EVM treats any non-zero word as true
+        assert_eq!(values, vec![ArgumentValue::Bool(true)]);
+
+        calldata[4] = 0;
+        calldata[35] = 2;
+        let (error, _) = non_canonical("f(bool)", &calldata);
+        assert_eq!(error, DecodeError::InvalidBool { at: 4 });
+    }
+
+    #[test]
+    fn test_strict_rejects_out_of_range_integers() {
+        let calldata = CalldataBuilder::new("f(uint256,int256)")
+            .uint(0x100)
+            .int(0x80)
+            .build()
+            .unwrap();
+        let mut narrow = calldata.clone();
+        narrow[..4].copy_from_slice(&parse_signature("f(uint8,int8)").unwrap().selector);
+        let sig = parse_signature("f(uint8,int8)").unwrap();
+        assert_eq!(
+            decode_calldata_with_mode(&sig, &narrow, DecodeMode::Strict).unwrap_err(),
+            DecodeError::ValueOutOfRange {
+                param: "uint8".to_string(),
+                at: 4
+            }
+        );
+        assert_eq!(
+            decode_calldata(&sig, &narrow).unwrap().warnings,
+            vec![
+                DecodeError::ValueOutOfRange {
+                    param: "uint8".to_string(),
+                    at: 4
+                },
+                DecodeError::ValueOutOfRange {
+                    param: "int8".to_string(),
+                    at: 36
+                },
+            ]
+        );
+
+        let negative = CalldataBuilder::new("f(int8)").int(-128).build().unwrap();
+        let sig = parse_signature("f(int8)").unwrap();
+        assert!(decode_calldata_with_mode(&sig, &negative, DecodeMode::Strict).is_ok());
+    }
+
+    #[test]
+    fn test_strict_rejects_dirty_padding() {
+        let mut calldata = CalldataBuilder::new("f(bytes4)")
+            .fixed_bytes(&[1, 2, 3, 4])
+            .build()
+            .unwrap();
+        calldata[10] = 1;
+        let (error, values) = non_canonical("f(bytes4)", &calldata);
+        assert_eq!(error, DecodeError::DirtyPadding { at: 10 });
+        assert_eq!(values, vec![ArgumentValue::FixedBytes(vec![1, 2, 3, 4])]);
+
+        // selector, offset, length, then "hi" at byte 68
+        let mut calldata = CalldataBuilder::new("f(string)")
+            .string("hi")
+            .build()
+            .unwrap();
+        calldata[75] = b'!';
+        let (error, values) = non_canonical("f(string)", &calldata);
+        assert_eq!(error, DecodeError::DirtyPadding { at: 75 });
+        assert_eq!(values, vec![ArgumentValue::String("hi".to_string())]);
+
+        let truncated = &calldata[..70];
+        let (error, _) = non_canonical("f(string)", truncated);
+        assert_eq!(
+            error,
+            DecodeError::CalldataTooShort {
+                expected: 100,
+                actual: 70
+            }
+        );
+    }
+
+    #[test]
+    fn test_strict_rejects_misplaced_tails() {
+        let calldata = CalldataBuilder::new("f(bytes,bytes)")
+            .bytes(b"first")
+            .bytes(b"second")
+            .build()
+            .unwrap();
+        // Heads at 4 and 36 point to tails at 68 and 132
+        let offset = |data: &mut Vec<u8>, head: usize, value: u8| {
+            data[head..head + 32].fill(0);
+            data[head + 31] = value;
+        };
+
+        let sig = parse_signature("f(bytes,bytes)").unwrap();
+        let mut overlapping = calldata.clone();
+        offset(&mut overlapping, 36, 0x40);
+        let overlap = DecodeError::NonCanonicalOffset {
+            at: 36,
+            expected: 132,
+            actual: 68,
+        };
+        assert_eq!(
+            decode_calldata_with_mode(&sig, &overlapping, DecodeMode::Strict).unwrap_err(),
+            overlap
+        );
+        // Both heads share the first tail, leaving the second one unread
+        let decoded = decode_calldata(&sig, &overlapping).unwrap();
+        assert_eq!(
+            decoded.warnings,
+            vec![overlap, DecodeError::TrailingBytes { len: 64 }]
+        );
+        let values = values(decoded);
+        assert_eq!(values[0], values[1]);
+
+        let mut swapped = calldata.clone();
+        offset(&mut swapped, 4, 0x80);
+        offset(&mut swapped, 36, 0x40);
+        assert_eq!(
+            decode_calldata_with_mode(&sig, &swapped, DecodeMode::Strict).unwrap_err(),
+            DecodeError::NonCanonicalOffset {
+                at: 4,
+                expected: 68,
+                actual: 132
+            }
+        );
+        assert_eq!(decode_calldata(&sig, &swapped).unwrap().warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_strict_rejects_trailing_bytes() {
+        let mut calldata = CalldataBuilder::new("f(uint256)").uint(1).build().unwrap();
+        calldata.push(0);
+        let (error, _) = non_canonical("f(uint256)", &calldata);
+        assert_eq!(error, DecodeError::TrailingBytes { len: 1 });
+    }
+
+    #[test]
+    fn test_strict_accepts_canonical_nested_encoding() {
+        let sig = parse_signature("g(uint256[][],string[])").unwrap();
+        let calldata = encode_calldata(
+            &sig,
+            &[
+                ArgumentValue::Array(vec![ArgumentValue::Array(vec![uint(1)])]),
+                ArgumentValue::Array(vec![ArgumentValue::String("x".to_string())]),
+            ],
+        )
+        .unwrap();
+        let decoded = decode_calldata_with_mode(&sig, &calldata, DecodeMode::Strict).unwrap();
+        assert!(decoded.warnings.is_empty());
+    }
+
+    fn limit(limit: &'static str, max: usize) -> DecodeError {
+        DecodeError::LimitExceeded { limit, max }
+    }
+
+    #[test]
+    fn test_parse_rejects_deeply_nested_types() {
+        let arrays = |depth: usize| format!("f(uint256{})", "[]".repeat(depth));
+        assert!(parse_signature(&arrays(MAX_TYPE_DEPTH)).is_ok());
+        assert!(parse_signature(&arrays(MAX_TYPE_DEPTH + 1)).is_err());
+
+        let tuples = format!("f({}uint256{})", "(".repeat(100_000), ")".repeat(100_000));
+        assert!(matches!(
+            parse_signature(&tuples),
+            Err(DecodeError::InvalidSignature(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_limits_nesting_depth() {
+        let sig = parse_signature("f(uint256[][][])").unwrap();
+        let value = ArgumentValue::Array(vec![ArgumentValue::Array(vec![ArgumentValue::Array(
+            vec![uint(1)],
+        )])]);
+        let calldata = encode_calldata(&sig, &[value]).unwrap();
+        let limits = DecodeLimits {
+            max_depth: 2,
+            ..DecodeLimits::default()
+        };
+        assert_eq!(
+            decode_calldata_with_limits(&sig, &calldata, DecodeMode::Lenient, limits).unwrap_err(),
+            limit("nesting depth", 2)
+        );
+        let limits = DecodeLimits {
+            max_depth: 3,
+            ..limits
+        };
+        assert!(decode_calldata_with_limits(&sig, &calldata, DecodeMode::Strict, limits).is_ok());
+    }
+
+    #[test]
+    fn test_decode_limits_claimed_lengths() {
+        // Zero-sized elements occupy no calldata, so any claimed length "fits"
+        let sig = parse_signature("f(()[])").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        calldata.extend(usize_word(32));
+        calldata.extend([0xff; 32]);
+        calldata[36..60].fill(0);
+        assert_eq!(
+            decode_calldata(&sig, &calldata).unwrap_err(),
+            limit("element count", 65_536)
+        );
+
+        let sig = parse_signature("f(()[4294967295])").unwrap();
+        assert_eq!(
+            decode_calldata(&sig, &sig.selector).unwrap_err(),
+            limit("element count", 65_536)
+        );
+    }
+
+    #[test]
+    fn test_decode_limits_aliased_tails() {
+        // Every element of a `bytes[]` points at the same 4 KiB tail
+        let sig = parse_signature("f(bytes[])").unwrap();
+        let elements = 2_000;
+        let mut calldata = sig.selector.to_vec();
+        calldata.extend(usize_word(32));
+        calldata.extend(usize_word(elements));
+        for _ in 0..elements {
+            calldata.extend(usize_word(elements * 32));
+        }
+        calldata.extend(encode_bytes(&[7; 4096]));
+
+        assert_eq!(
+            decode_calldata(&sig, &calldata).unwrap_err(),
+            limit("decoded size", 4 << 20)
+        );
+        let limits = DecodeLimits {
+            max_bytes: 16 << 20,
+            ..DecodeLimits::default()
+        };
+        let decoded =
+            decode_calldata_with_limits(&sig, &calldata, DecodeMode::Lenient, limits).unwrap();
+        assert_eq!(decoded.warnings.len(), elements - 1);
+    }
+
+    #[test]
+    fn test_decode_rejects_overflowing_offsets() {
+        let sig = parse_signature("f(uint256,bytes)").unwrap();
+        for offset in [u64::MAX, u64::MAX - 3] {
+            let mut calldata = sig.selector.to_vec();
+            calldata.extend([0; 32]);
+            calldata.extend([0; 24]);
+            calldata.extend(offset.to_be_bytes());
+            assert!(decode_calldata(&sig, &calldata).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "capi", feature = "uniffi", feature = "wasm"))]
+    fn test_decode_hex_input() {
+        assert_eq!(
+            decode_hex(" 0xA9059CBB ").unwrap(),
+            vec![0xa9, 0x05, 0x9c, 0xbb]
+        );
+        assert_eq!(decode_hex("0X0").unwrap(), vec![0]);
+        assert_eq!(decode_hex("").unwrap(), Vec::<u8>::new());
+        assert!(decode_hex("0xzz").is_err());
+    }
 }
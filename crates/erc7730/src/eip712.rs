@@ -1,17 +1,22 @@
 //! EIP-712 typed data formatting — parses structured typed data and produces
 //! a [`DisplayModel`](crate::engine::DisplayModel) using the same descriptor format as calldata.
 
-use std::collections::HashMap;
-
 use serde::{Deserialize, Serialize};
 
 use crate::address_book::AddressBook;
-use crate::engine::{DisplayEntry, DisplayItem, DisplayModel, GroupIteration};
+use crate::address_name::NameSource;
+use crate::chain::{ChainSource, WellKnownChainSource};
+use crate::engine::{
+    self, DisplayEntry, DisplayItem, DisplayModel, FieldWarning, GroupIteration, RenderSources,
+};
 use crate::error::Error;
+use crate::prefetch::{AddressLookup, LookupRequests, LookupResults};
+use crate::prelude::*;
+use crate::risk::{self, RiskLookup, SpenderStatus};
 use crate::token::{TokenLookupKey, TokenSource};
 use crate::types::descriptor::Descriptor;
 use crate::types::display::{
-    DisplayField, FieldFormat, FieldGroup, FormatParams, Iteration, VisibleRule,
+    AddressType, DisplayField, FieldFormat, FieldGroup, FormatParams, Iteration, VisibleRule,
 };
 
 /// EIP-712 typed data as received for signing.
@@ -57,6 +62,41 @@ pub fn format_typed_data(
     descriptor: &Descriptor,
     data: &TypedData,
     token_source: &dyn TokenSource,
+) -> Result<DisplayModel, Error> {
+    format_typed_data_with_chains(
+        descriptor,
+        data,
+        token_source,
+        WellKnownChainSource::shared(),
+    )
+}
+
+/// Format EIP-712 typed data, naming chains through `chains`.
+pub fn format_typed_data_with_chains(
+    descriptor: &Descriptor,
+    data: &TypedData,
+    token_source: &dyn TokenSource,
+    chains: &dyn ChainSource,
+) -> Result<DisplayModel, Error> {
+    format_typed_data_with_sources(
+        descriptor,
+        data,
+        &RenderSources {
+            tokens: token_source,
+            chains,
+            lookups: &LookupResults::default(),
+            known_addresses: None,
+            lookalike_chars: engine::LOOKALIKE_CHARS,
+        },
+    )
+}
+
+/// Format EIP-712 typed data with caller-supplied token, chain and prefetched
+/// lookup sources (see [`collect_typed_lookups`]).
+pub fn format_typed_data_with_sources(
+    descriptor: &Descriptor,
+    data: &TypedData,
+    sources: &RenderSources<'_>,
 ) -> Result<DisplayModel, Error> {
     let address_book = AddressBook::from_descriptor(&descriptor.context, &descriptor.metadata);
     let chain_id = data.domain.chain_id.unwrap_or(1);
@@ -66,7 +106,7 @@ pub fn format_typed_data(
 
     // Graceful fallback: if no format matches, show raw message fields
     let Some(format) = format else {
-        return Ok(build_typed_raw_fallback(data));
+        return Ok(build_typed_raw_fallback(data, sources));
     };
 
     let mut warnings = Vec::new();
@@ -75,7 +115,7 @@ pub fn format_typed_data(
         &data.message,
         &format.fields,
         chain_id,
-        token_source,
+        sources,
         &address_book,
         &mut warnings,
     )?;
@@ -91,16 +131,193 @@ pub fn format_typed_data(
             .map(|template| interpolate_typed_intent(template, &data.message, &format.fields)),
         entries,
         warnings,
+        risks: risk::analyze_typed_data(
+            data,
+            &TypedRiskLookup {
+                sources,
+                address_book: &address_book,
+            },
+        ),
     })
 }
 
+/// Collect the token and address-name lookups needed to render `data` with
+/// `descriptor`, so they can be resolved in one batch beforehand.
+///
+/// Tokens `sources.tokens` already knows are left out.
+pub fn collect_typed_lookups(
+    descriptor: &Descriptor,
+    data: &TypedData,
+    sources: &RenderSources<'_>,
+) -> LookupRequests {
+    let mut requests = LookupRequests::default();
+    if let Some(format) = descriptor.display.formats.get(&data.primary_type) {
+        collect_typed_struct(
+            descriptor,
+            &format.fields,
+            &data.message,
+            data.domain.chain_id.unwrap_or(1),
+            &mut requests,
+        );
+    }
+    collect_spender_lookups(
+        data,
+        &AddressBook::from_descriptor(&descriptor.context, &descriptor.metadata),
+        &mut requests,
+    );
+    requests
+        .tokens
+        .retain(|key| sources.tokens.lookup(key).is_none());
+    requests
+}
+
+/// Add the name and code lookups the permit risk checks need for the spender
+/// of `data` to `requests`, unless `address_book` already labels it.
+pub(crate) fn collect_spender_lookups(
+    data: &TypedData,
+    address_book: &AddressBook,
+    requests: &mut LookupRequests,
+) {
+    if let Some(spender) = risk::typed_spender(data) {
+        let chain_id = data.domain.chain_id.unwrap_or(1);
+        if address_book.resolve(&spender).is_none() {
+            requests.add_address(chain_id, &spender);
+        }
+        requests.add_code(chain_id, &spender);
+    }
+}
+
+/// Add the lookups `fields` need against `message` (such as a Permit2 witness)
+/// to `requests`.
+pub(crate) fn collect_typed_struct(
+    descriptor: &Descriptor,
+    fields: &[DisplayField],
+    message: &serde_json::Value,
+    chain_id: u64,
+    requests: &mut LookupRequests,
+) {
+    let address_book = AddressBook::from_descriptor(&descriptor.context, &descriptor.metadata);
+    collect_typed_fields(
+        descriptor,
+        message,
+        fields,
+        chain_id,
+        &address_book,
+        requests,
+    );
+}
+
+fn collect_typed_fields(
+    descriptor: &Descriptor,
+    message: &serde_json::Value,
+    fields: &[DisplayField],
+    chain_id: u64,
+    address_book: &AddressBook,
+    requests: &mut LookupRequests,
+) {
+    for field in fields {
+        match field {
+            DisplayField::Reference { reference } => {
+                let key = reference
+                    .strip_prefix("#/definitions/")
+                    .unwrap_or(reference);
+                if let Some(resolved) = descriptor.display.definitions.get(key) {
+                    collect_typed_fields(
+                        descriptor,
+                        message,
+                        core::slice::from_ref(resolved),
+                        chain_id,
+                        address_book,
+                        requests,
+                    );
+                }
+            }
+            DisplayField::Group { field_group } => collect_typed_fields(
+                descriptor,
+                message,
+                &field_group.fields,
+                chain_id,
+                address_book,
+                requests,
+            ),
+            DisplayField::Simple {
+                path,
+                format,
+                params,
+                visible,
+                ..
+            } => {
+                let value = resolve_typed_path(message, path);
+                if !check_typed_visibility(visible, &value) {
+                    continue;
+                }
+                let Some(val) = value else { continue };
+                let params = params.as_ref();
+                match format {
+                    Some(FieldFormat::TokenAmount) => {
+                        let token = params
+                            .and_then(|p| p.token_path.as_ref())
+                            .and_then(|path| resolve_typed_path(message, path));
+                        if let Some(serde_json::Value::String(addr)) = token {
+                            let lookup_chain = resolve_typed_chain_id(params, chain_id, message);
+                            requests.add_token(TokenLookupKey::new(lookup_chain, &addr));
+                        }
+                    }
+                    Some(FieldFormat::TokenTicker) => {
+                        let lookup_chain = resolve_typed_chain_id(params, chain_id, message);
+                        requests.add_token(TokenLookupKey::new(
+                            lookup_chain,
+                            &json_value_to_string(&val),
+                        ));
+                    }
+                    Some(FieldFormat::AddressName) => {
+                        let addr = json_value_to_string(&val);
+                        if address_book.resolve(&addr).is_none() {
+                            requests.add_address(chain_id, &addr);
+                        }
+                        // A known token is how a token address is recognised, and
+                        // code presence tells an EOA from a contract
+                        if params.is_some_and(|p| p.types.is_some()) {
+                            requests.add_token(TokenLookupKey::new(chain_id, &addr));
+                            requests.add_code(chain_id, &addr);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Render `fields` against a nested struct (such as a Permit2 witness) with the
+/// descriptor's own address book.
+pub(crate) fn render_typed_struct(
+    descriptor: &Descriptor,
+    fields: &[DisplayField],
+    message: &serde_json::Value,
+    chain_id: u64,
+    sources: &RenderSources<'_>,
+    warnings: &mut Vec<String>,
+) -> Result<Vec<DisplayEntry>, Error> {
+    let address_book = AddressBook::from_descriptor(&descriptor.context, &descriptor.metadata);
+    render_typed_fields(
+        descriptor,
+        message,
+        fields,
+        chain_id,
+        sources,
+        &address_book,
+        warnings,
+    )
+}
+
 /// Render typed data fields recursively.
 fn render_typed_fields(
     descriptor: &Descriptor,
     message: &serde_json::Value,
     fields: &[DisplayField],
     chain_id: u64,
-    token_source: &dyn TokenSource,
+    sources: &RenderSources<'_>,
     address_book: &AddressBook,
     warnings: &mut Vec<String>,
 ) -> Result<Vec<DisplayEntry>, Error> {
@@ -116,9 +333,9 @@ fn render_typed_fields(
                     let mut sub = render_typed_fields(
                         descriptor,
                         message,
-                        std::slice::from_ref(resolved),
+                        core::slice::from_ref(resolved),
                         chain_id,
-                        token_source,
+                        sources,
                         address_book,
                         warnings,
                     )?;
@@ -133,7 +350,7 @@ fn render_typed_fields(
                     message,
                     field_group,
                     chain_id,
-                    token_source,
+                    sources,
                     address_book,
                     warnings,
                 )? {
@@ -161,14 +378,63 @@ fn render_typed_fields(
                     params.as_ref(),
                     chain_id,
                     message,
-                    token_source,
+                    sources,
                     address_book,
                     warnings,
                 )?;
+                let mut name_source = None;
+                let mut field_warnings = Vec::new();
+                if let (Some(FieldFormat::AddressName), Some(val)) = (format, &value) {
+                    let addr = json_value_to_string(val);
+                    let name = resolve_typed_address_name(
+                        &addr,
+                        params.as_ref(),
+                        chain_id,
+                        sources,
+                        address_book,
+                    );
+                    name_source = name.as_ref().map(|(_, source)| *source);
+                    if let Some(expected) = params.as_ref().and_then(|p| p.types.as_ref()) {
+                        let lookup = AddressLookup {
+                            chain_id,
+                            address: addr.to_lowercase(),
+                        };
+                        let known_token = sources
+                            .lookup_token(&TokenLookupKey::new(chain_id, &addr))
+                            .is_some();
+                        let named = if engine::is_deployment(descriptor, chain_id, &addr) {
+                            Some(AddressType::Contract)
+                        } else {
+                            sources
+                                .lookups
+                                .address_names
+                                .get(&lookup)
+                                .and_then(|name| name.kind)
+                        };
+                        let has_code = sources.lookups.has_code.get(&lookup).copied();
+                        if let Some(warning) = engine::address_kind_warning(
+                            label,
+                            expected,
+                            known_token,
+                            named,
+                            has_code,
+                        ) {
+                            warnings.push(warning.message.clone());
+                            field_warnings.push(warning);
+                        }
+                    }
+                }
+                if let Some(val) = &value {
+                    let start = field_warnings.len();
+                    check_typed_lookalikes(val, label, sources, &mut field_warnings);
+                    warnings.extend(field_warnings[start..].iter().map(|w| w.message.clone()));
+                }
 
                 entries.push(DisplayEntry::Item(DisplayItem {
                     label: label.clone(),
                     value: formatted,
+                    name_source,
+                    warnings: field_warnings,
                 }));
             }
         }
@@ -182,7 +448,7 @@ fn render_typed_field_group(
     message: &serde_json::Value,
     group: &FieldGroup,
     chain_id: u64,
-    token_source: &dyn TokenSource,
+    sources: &RenderSources<'_>,
     address_book: &AddressBook,
     warnings: &mut Vec<String>,
 ) -> Result<Option<DisplayEntry>, Error> {
@@ -191,7 +457,7 @@ fn render_typed_field_group(
         message,
         &group.fields,
         chain_id,
-        token_source,
+        sources,
         address_book,
         warnings,
     )?;
@@ -220,8 +486,43 @@ fn render_typed_field_group(
     }))
 }
 
+/// Flag addresses in `value`, including inside arrays and structs, imitating one
+/// of the caller's known addresses.
+pub(crate) fn check_typed_lookalikes(
+    value: &serde_json::Value,
+    label: &str,
+    sources: &RenderSources<'_>,
+    warnings: &mut Vec<FieldWarning>,
+) {
+    let Some(known) = sources.known_addresses else {
+        return;
+    };
+    match value {
+        serde_json::Value::String(s) if s.starts_with("0x") => {
+            let warning = engine::parse_address(s).and_then(|addr| {
+                engine::lookalike_warning(known, &addr, label, sources.lookalike_chars)
+            });
+            warnings.extend(warning);
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                check_typed_lookalikes(item, label, sources, warnings);
+            }
+        }
+        serde_json::Value::Object(members) => {
+            for member in members.values() {
+                check_typed_lookalikes(member, label, sources, warnings);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Build a raw fallback DisplayModel for EIP-712 typed data when no format matches.
-pub(crate) fn build_typed_raw_fallback(data: &TypedData) -> DisplayModel {
+pub(crate) fn build_typed_raw_fallback(
+    data: &TypedData,
+    sources: &RenderSources<'_>,
+) -> DisplayModel {
     let mut entries = Vec::new();
 
     // Use the primary type's field definitions to order entries if available
@@ -235,6 +536,8 @@ pub(crate) fn build_typed_raw_fallback(data: &TypedData) -> DisplayModel {
             entries.push(DisplayEntry::Item(DisplayItem {
                 label: field.name.clone(),
                 value,
+                name_source: None,
+                warnings: Vec::new(),
             }));
         }
     } else if let Some(obj) = data.message.as_object() {
@@ -243,6 +546,8 @@ pub(crate) fn build_typed_raw_fallback(data: &TypedData) -> DisplayModel {
             entries.push(DisplayEntry::Item(DisplayItem {
                 label: key.clone(),
                 value: json_value_to_string(val),
+                name_source: None,
+                warnings: Vec::new(),
             }));
         }
     }
@@ -252,6 +557,38 @@ pub(crate) fn build_typed_raw_fallback(data: &TypedData) -> DisplayModel {
         interpolated_intent: None,
         entries,
         warnings: vec!["No matching descriptor format found".to_string()],
+        risks: risk::analyze_typed_data(
+            data,
+            &TypedRiskLookup {
+                sources,
+                address_book: &AddressBook::empty(),
+            },
+        ),
+    }
+}
+
+/// Risk lookups for typed data: tokens and spender names from the render
+/// sources and prefetched lookups, labels from the descriptor's address book.
+pub(crate) struct TypedRiskLookup<'a> {
+    pub(crate) sources: &'a RenderSources<'a>,
+    pub(crate) address_book: &'a AddressBook,
+}
+
+impl RiskLookup for TypedRiskLookup<'_> {
+    fn token_decimals(&self, chain_id: u64, token: &str) -> Option<u8> {
+        self.sources
+            .lookup_token(&TokenLookupKey::new(chain_id, token))
+            .map(|meta| meta.decimals)
+    }
+
+    fn spender_status(&self, chain_id: u64, spender: &str) -> SpenderStatus {
+        engine::spender_status(
+            self.sources.lookups,
+            self.address_book,
+            self.sources.known_addresses,
+            chain_id,
+            spender,
+        )
     }
 }
 
@@ -303,7 +640,7 @@ fn format_typed_value(
     params: Option<&FormatParams>,
     chain_id: u64,
     message: &serde_json::Value,
-    token_source: &dyn TokenSource,
+    sources: &RenderSources<'_>,
     address_book: &AddressBook,
     warnings: &mut Vec<String>,
 ) -> Result<String, Error> {
@@ -340,11 +677,11 @@ fn format_typed_value(
         FieldFormat::Address => Ok(json_value_to_string(val)),
         FieldFormat::AddressName => {
             let addr = json_value_to_string(val);
-            if let Some(label) = address_book.resolve(&addr) {
-                Ok(label.to_string())
-            } else {
-                Ok(addr)
-            }
+            Ok(
+                resolve_typed_address_name(&addr, params, chain_id, sources, address_book)
+                    .map(|(name, _)| name)
+                    .unwrap_or(addr),
+            )
         }
         FieldFormat::TokenAmount => {
             let amount_str = json_value_to_string(val);
@@ -359,7 +696,7 @@ fn format_typed_value(
                     let token_addr = resolve_typed_path(message, token_path);
                     if let Some(serde_json::Value::String(addr)) = token_addr {
                         let key = TokenLookupKey::new(lookup_chain, &addr);
-                        token_source.lookup(&key)
+                        sources.lookup_token(&key)
                     } else {
                         None
                     }
@@ -383,15 +720,8 @@ fn format_typed_value(
                 serde_json::Value::String(s) => s.parse().unwrap_or(0),
                 _ => 0,
             };
-            let dt = time::OffsetDateTime::from_unix_timestamp(ts)
-                .map_err(|e| Error::Render(format!("invalid timestamp: {e}")))?;
-            let format = time::format_description::parse(
-                "[year]-[month]-[day] [hour]:[minute]:[second] UTC",
-            )
-            .map_err(|e| Error::Render(format!("format error: {e}")))?;
-            Ok(dt
-                .format(&format)
-                .map_err(|e| Error::Render(format!("format error: {e}")))?)
+            engine::format_timestamp(ts)
+                .ok_or_else(|| Error::Render(format!("invalid timestamp: {ts}")))
         }
         FieldFormat::Enum => {
             let raw = json_value_to_string(val);
@@ -421,7 +751,7 @@ fn format_typed_value(
             let lookup_chain = resolve_typed_chain_id(params, chain_id, message);
             let addr = json_value_to_string(val);
             let key = TokenLookupKey::new(lookup_chain, &addr);
-            if let Some(meta) = token_source.lookup(&key) {
+            if let Some(meta) = sources.lookup_token(&key) {
                 Ok(meta.symbol)
             } else {
                 warnings.push("token ticker not found".to_string());
@@ -434,7 +764,7 @@ fn format_typed_value(
                 serde_json::Value::String(s) => s.parse().unwrap_or(0),
                 _ => 0,
             };
-            Ok(crate::engine::chain_name_public(cid))
+            Ok(sources.chains.chain_name(cid))
         }
         _ => {
             warnings.push(format!("format {fmt:?} not yet implemented for EIP-712"));
@@ -443,6 +773,30 @@ fn format_typed_value(
     }
 }
 
+/// Name of an address from the descriptor's address book, then from prefetched
+/// lookups whose source the field's `sources` param accepts.
+fn resolve_typed_address_name(
+    addr: &str,
+    params: Option<&FormatParams>,
+    chain_id: u64,
+    sources: &RenderSources<'_>,
+    address_book: &AddressBook,
+) -> Option<(String, NameSource)> {
+    if let Some(label) = address_book.resolve(addr) {
+        return Some((label.to_string(), NameSource::Descriptor));
+    }
+    let accepted = params.and_then(|p| p.sources.as_ref());
+    sources
+        .lookups
+        .address_names
+        .get(&AddressLookup {
+            chain_id,
+            address: addr.to_lowercase(),
+        })
+        .filter(|name| accepted.is_none_or(|s| s.iter().any(|s| s == name.source.key())))
+        .map(|name| (name.name.clone(), name.source))
+}
+
 fn resolve_typed_chain_id(
     params: Option<&FormatParams>,
     default_chain: u64,
@@ -463,7 +817,7 @@ fn resolve_typed_chain_id(
     default_chain
 }
 
-fn json_value_to_string(val: &serde_json::Value) -> String {
+pub(crate) fn json_value_to_string(val: &serde_json::Value) -> String {
     match val {
         serde_json::Value::String(s) => s.clone(),
         serde_json::Value::Number(n) => n.to_string(),
@@ -544,16 +898,7 @@ fn resolve_and_format_typed_interpolation(
                         serde_json::Value::String(s) => s.parse().unwrap_or(0),
                         _ => 0,
                     };
-                    if let Ok(dt) = time::OffsetDateTime::from_unix_timestamp(ts) {
-                        if let Ok(fmt) = time::format_description::parse(
-                            "[year]-[month]-[day] [hour]:[minute]:[second] UTC",
-                        ) {
-                            if let Ok(s) = dt.format(&fmt) {
-                                return s;
-                            }
-                        }
-                    }
-                    json_value_to_string(&v)
+                    engine::format_timestamp(ts).unwrap_or_else(|| json_value_to_string(&v))
                 }
                 _ => json_value_to_string(&v),
             }
@@ -2,29 +2,40 @@
 //! and produces a [`DisplayModel`] with labeled entries for wallet UIs.
 
 use num_bigint::BigUint;
+use serde::Serialize;
 
 use crate::address_book::AddressBook;
+use crate::address_name::{AddressName, NameSource};
+use crate::chain::{ChainSource, WellKnownChainSource};
 use crate::decoder::{ArgumentValue, DecodedArguments};
 use crate::error::Error;
-use crate::token::{TokenLookupKey, TokenSource};
+use crate::prefetch::{AddressLookup, LookupRequests, LookupResults, NftLookup};
+use crate::prelude::*;
+use crate::risk::{self, RiskFinding, RiskLookup, SpenderStatus};
+use crate::token::{TokenLookupKey, TokenMeta, TokenSource};
 use crate::types::descriptor::Descriptor;
 use crate::types::display::{
-    DisplayField, DisplayFormat, FieldFormat, FieldGroup, FormatParams, Iteration, VisibleRule,
+    AddressType, DisplayField, DisplayFormat, FieldFormat, FieldGroup, FormatParams, Iteration,
+    VisibleRule,
 };
 
 /// Output model for clear signing display.
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DisplayModel {
     pub intent: String,
     pub interpolated_intent: Option<String>,
     pub entries: Vec<DisplayEntry>,
     pub warnings: Vec<String>,
+    /// Approval risks found in the transaction or signature.
+    pub risks: Vec<RiskFinding>,
 }
 
 /// A display entry — either a flat item or a group of items.
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub enum DisplayEntry {
     Item(DisplayItem),
     Group {
@@ -35,7 +46,8 @@ pub enum DisplayEntry {
 }
 
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum GroupIteration {
     Sequential,
     Bundled,
@@ -43,55 +55,200 @@ pub enum GroupIteration {
 
 /// A single label+value pair for display.
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DisplayItem {
     pub label: String,
     pub value: String,
+    /// Provenance of the name shown for an `addressName` field; `None` when the
+    /// value is not a resolved name.
+    pub name_source: Option<NameSource>,
+    /// Warnings about this field's value, also listed in [`DisplayModel::warnings`].
+    pub warnings: Vec<FieldWarning>,
 }
 
-/// Known chain IDs → human-readable names (public for eip712 module).
-pub(crate) fn chain_name_public(chain_id: u64) -> String {
-    chain_name(chain_id)
+/// How strongly a [`FieldWarning`] should be surfaced.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WarningSeverity {
+    Info,
+    Warning,
+    /// The transaction likely does not do what the user expects.
+    Danger,
 }
 
-fn chain_name(chain_id: u64) -> String {
-    match chain_id {
-        1 => "Ethereum".to_string(),
-        10 => "Optimism".to_string(),
-        56 => "BNB Chain".to_string(),
-        100 => "Gnosis".to_string(),
-        137 => "Polygon".to_string(),
-        250 => "Fantom".to_string(),
-        324 => "zkSync Era".to_string(),
-        8453 => "Base".to_string(),
-        42161 => "Arbitrum One".to_string(),
-        42170 => "Arbitrum Nova".to_string(),
-        43114 => "Avalanche".to_string(),
-        59144 => "Linea".to_string(),
-        534352 => "Scroll".to_string(),
-        7777777 => "Zora".to_string(),
-        _ => format!("Chain {chain_id}"),
-    }
+/// What a [`FieldWarning`] is about.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum FieldWarningKind {
+    /// The address is a different kind than the `addressName` `types` param accepts.
+    AddressTypeMismatch,
+    /// The address shares its leading and trailing characters with a known address
+    /// it is not — a likely address-poisoning attempt.
+    LookAlikeAddress {
+        /// Checksummed known address it imitates.
+        known_address: String,
+        known_label: String,
+    },
 }
 
+/// A warning attached to a single display item.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldWarning {
+    pub kind: FieldWarningKind,
+    pub severity: WarningSeverity,
+    pub message: String,
+}
+
+/// Number of leading and trailing hex characters compared by look-alike detection,
+/// matching how wallets commonly abbreviate addresses (`0x1234…abcd`).
+pub const LOOKALIKE_CHARS: usize = 4;
+
 /// Rendering context passed through the pipeline.
 struct RenderContext<'a> {
     descriptor: &'a Descriptor,
     decoded: &'a DecodedArguments,
     chain_id: u64,
     token_source: &'a dyn TokenSource,
+    chains: &'a dyn ChainSource,
     address_book: &'a AddressBook,
+    known_addresses: Option<&'a AddressBook>,
+    lookalike_chars: usize,
+    lookups: &'a LookupResults,
     warnings: Vec<String>,
+    /// Warnings for the field being rendered, moved onto its item.
+    field_warnings: Vec<FieldWarning>,
+}
+
+impl RenderContext<'_> {
+    /// Token metadata from the token source, then from prefetched results.
+    fn lookup_token(&self, key: &TokenLookupKey) -> Option<TokenMeta> {
+        self.token_source
+            .lookup(key)
+            .or_else(|| self.lookups.tokens.get(key).cloned())
+    }
+
+    /// Native currency metadata for a chain, `None` when the chain is unknown.
+    fn native_token_meta(&self, chain_id: u64) -> Option<TokenMeta> {
+        self.chains
+            .native_currency(chain_id)
+            .map(|currency| currency.to_token_meta())
+    }
+}
+
+impl RiskLookup for RenderContext<'_> {
+    fn token_decimals(&self, chain_id: u64, token: &str) -> Option<u8> {
+        self.lookup_token(&TokenLookupKey::new(chain_id, token))
+            .map(|meta| meta.decimals)
+    }
+
+    fn spender_status(&self, chain_id: u64, spender: &str) -> SpenderStatus {
+        spender_status(
+            self.lookups,
+            self.address_book,
+            self.known_addresses,
+            chain_id,
+            spender,
+        )
+    }
+}
+
+/// What is known about a spender. It is an EOA when its name says so or it has
+/// no code, and known when the descriptor, the caller's address book or a name
+/// source other than ENS names it.
+pub(crate) fn spender_status(
+    lookups: &LookupResults,
+    address_book: &AddressBook,
+    known_addresses: Option<&AddressBook>,
+    chain_id: u64,
+    spender: &str,
+) -> SpenderStatus {
+    let lookup = AddressLookup {
+        chain_id,
+        address: spender.to_lowercase(),
+    };
+    let name = lookups.address_names.get(&lookup);
+    if name.is_some_and(|n| n.kind == Some(AddressType::Eoa))
+        || lookups.has_code.get(&lookup) == Some(&false)
+    {
+        return SpenderStatus::Eoa;
+    }
+    let trusted_name = name.is_some_and(|n| n.source != NameSource::Ens);
+    let known = address_book.resolve(spender).is_some()
+        || known_addresses.is_some_and(|book| book.resolve(spender).is_some());
+    if known || trusted_name {
+        SpenderStatus::Known
+    } else {
+        SpenderStatus::Unknown
+    }
+}
+
+/// External data sources used while rendering.
+#[derive(Clone, Copy)]
+pub struct RenderSources<'a> {
+    pub tokens: &'a dyn TokenSource,
+    pub chains: &'a dyn ChainSource,
+    /// Prefetched lookups, read when `tokens` misses (see [`collect_calldata_lookups`]).
+    pub lookups: &'a LookupResults,
+    /// The user's known counterparties; displayed addresses imitating one are flagged.
+    pub known_addresses: Option<&'a AddressBook>,
+    /// Leading and trailing hex characters compared against `known_addresses`,
+    /// usually [`LOOKALIKE_CHARS`].
+    pub lookalike_chars: usize,
+}
+
+impl RenderSources<'_> {
+    /// Token metadata from the token source, then from prefetched results.
+    pub(crate) fn lookup_token(&self, key: &TokenLookupKey) -> Option<TokenMeta> {
+        self.tokens
+            .lookup(key)
+            .or_else(|| self.lookups.tokens.get(key).cloned())
+    }
 }
 
 /// Format calldata into a display model using a descriptor.
+///
+/// Chains are named by [`WellKnownChainSource`]; use [`format_calldata_with_sources`]
+/// to supply a different chain source or prefetched lookups.
 pub fn format_calldata(
     descriptor: &Descriptor,
     chain_id: u64,
-    _to: &str,
+    to: &str,
     decoded: &DecodedArguments,
-    _value: Option<&[u8]>,
+    value: Option<&[u8]>,
     token_source: &dyn TokenSource,
+) -> Result<DisplayModel, Error> {
+    format_calldata_with_sources(
+        descriptor,
+        chain_id,
+        to,
+        decoded,
+        value,
+        &RenderSources {
+            tokens: token_source,
+            chains: WellKnownChainSource::shared(),
+            lookups: &LookupResults::default(),
+            known_addresses: None,
+            lookalike_chars: LOOKALIKE_CHARS,
+        },
+    )
+}
+
+/// Format calldata with caller-supplied token, chain and prefetched lookup sources.
+pub fn format_calldata_with_sources(
+    descriptor: &Descriptor,
+    chain_id: u64,
+    to: &str,
+    decoded: &DecodedArguments,
+    _value: Option<&[u8]>,
+    sources: &RenderSources<'_>,
 ) -> Result<DisplayModel, Error> {
     let address_book = AddressBook::from_descriptor(&descriptor.context, &descriptor.metadata);
 
@@ -102,9 +259,14 @@ pub fn format_calldata(
         descriptor,
         decoded,
         chain_id,
-        token_source,
+        token_source: sources.tokens,
+        chains: sources.chains,
         address_book: &address_book,
+        known_addresses: sources.known_addresses,
+        lookalike_chars: sources.lookalike_chars,
+        lookups: sources.lookups,
         warnings: Vec::new(),
+        field_warnings: Vec::new(),
     };
 
     let entries = render_fields(&mut ctx, &format.fields)?;
@@ -114,6 +276,14 @@ pub fn format_calldata(
         .as_ref()
         .map(|template| interpolate_intent(template, &ctx, &format.fields));
 
+    let mut warnings: Vec<String> = decoded
+        .warnings
+        .iter()
+        .map(|problem| format!("non-canonical calldata: {problem}"))
+        .collect();
+    let risks = risk::analyze_calldata(chain_id, to, decoded, &ctx);
+    warnings.append(&mut ctx.warnings);
+
     Ok(DisplayModel {
         intent: format
             .intent
@@ -121,7 +291,166 @@ pub fn format_calldata(
             .unwrap_or_else(|| decoded.function_name.clone()),
         interpolated_intent: interpolated,
         entries,
-        warnings: ctx.warnings,
+        risks,
+        warnings,
+    })
+}
+
+/// Collect the token, address-name and NFT lookups needed to render `decoded`.
+///
+/// Walks the same fields as rendering (including references, groups and hidden
+/// fields' visibility), so the result can be resolved in one batch beforehand.
+/// Tokens `sources.tokens` already knows are left out.
+pub fn collect_calldata_lookups(
+    descriptor: &Descriptor,
+    chain_id: u64,
+    decoded: &DecodedArguments,
+    sources: &RenderSources<'_>,
+) -> Result<LookupRequests, Error> {
+    let format = find_format(descriptor, &decoded.function_name, &decoded.selector)?;
+    let address_book = AddressBook::from_descriptor(&descriptor.context, &descriptor.metadata);
+    let ctx = RenderContext {
+        descriptor,
+        decoded,
+        chain_id,
+        token_source: sources.tokens,
+        chains: sources.chains,
+        address_book: &address_book,
+        known_addresses: sources.known_addresses,
+        lookalike_chars: sources.lookalike_chars,
+        lookups: sources.lookups,
+        warnings: Vec::new(),
+        field_warnings: Vec::new(),
+    };
+    let mut requests = LookupRequests::default();
+    collect_fields(&ctx, &format.fields, &mut requests);
+    // The approval risk checks read the spender's name and code
+    if let Some(spender) = risk::calldata_spender(chain_id, "", decoded) {
+        if address_book.resolve(&spender).is_none() {
+            requests.add_address(chain_id, &spender);
+        }
+        requests.add_code(chain_id, &spender);
+    }
+    // An `approve` amount is only checked when the called contract is a known token
+    if risk::is_allowance_call(decoded) {
+        if let Some(ArgumentValue::Address(token)) = resolve_path(decoded, "@.to") {
+            let token = format!("0x{}", hex::encode(token));
+            requests.add_token(TokenLookupKey::new(chain_id, &token));
+        }
+    }
+    requests
+        .tokens
+        .retain(|key| sources.tokens.lookup(key).is_none());
+    Ok(requests)
+}
+
+fn collect_fields(ctx: &RenderContext<'_>, fields: &[DisplayField], requests: &mut LookupRequests) {
+    for field in fields {
+        match field {
+            DisplayField::Reference { reference } => {
+                if let Some(resolved) = resolve_reference(ctx.descriptor, reference) {
+                    collect_fields(ctx, core::slice::from_ref(&resolved), requests);
+                }
+            }
+            DisplayField::Group { field_group } => {
+                collect_fields(ctx, &field_group.fields, requests);
+            }
+            DisplayField::Simple {
+                path,
+                format,
+                params,
+                visible,
+                ..
+            } => {
+                let value = resolve_path(ctx.decoded, path);
+                if !check_visibility(visible, &value) {
+                    continue;
+                }
+                let Some(val) = value else { continue };
+                let params = params.as_ref();
+                match format {
+                    Some(FieldFormat::TokenAmount) => {
+                        if let Some(key) = token_amount_key(ctx, params) {
+                            requests.add_token(key);
+                        }
+                    }
+                    Some(FieldFormat::TokenTicker) => {
+                        if let ArgumentValue::Address(addr) = val {
+                            let addr_hex = format!("0x{}", hex::encode(addr));
+                            requests.add_token(TokenLookupKey::new(
+                                resolve_chain_id(ctx, params),
+                                &addr_hex,
+                            ));
+                        }
+                    }
+                    Some(FieldFormat::AddressName) => {
+                        if let ArgumentValue::Address(addr) = val {
+                            let addr_hex = format!("0x{}", hex::encode(addr));
+                            if ctx.address_book.resolve(&addr_hex).is_none() {
+                                requests.add_address(ctx.chain_id, &addr_hex);
+                            }
+                            // A known token is how a token address is recognised, and
+                            // code presence tells an EOA from a contract
+                            if params.is_some_and(|p| p.types.is_some()) {
+                                requests.add_token(TokenLookupKey::new(ctx.chain_id, &addr_hex));
+                                requests.add_code(ctx.chain_id, &addr_hex);
+                            }
+                        }
+                    }
+                    Some(FieldFormat::NftName) => {
+                        if let Some(nft) = nft_lookup(ctx, &val, params) {
+                            requests.add_nft(nft.chain_id, &nft.collection, nft.token_id);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// The token whose metadata a `tokenAmount` field needs; `None` for native currency.
+fn token_amount_key(
+    ctx: &RenderContext<'_>,
+    params: Option<&FormatParams>,
+) -> Option<TokenLookupKey> {
+    let params = params?;
+    let token_path = params.token_path.as_ref()?;
+    let ArgumentValue::Address(addr) = resolve_path(ctx.decoded, token_path)? else {
+        return None;
+    };
+    let addr_hex = format!("0x{}", hex::encode(addr));
+    if let Some(ref native) = params.native_currency_address {
+        if addr_hex.to_lowercase() == native.to_lowercase() {
+            return None;
+        }
+    }
+    Some(TokenLookupKey::new(
+        resolve_chain_id(ctx, Some(params)),
+        &addr_hex,
+    ))
+}
+
+/// The NFT an `nftName` field refers to: collection from `collectionPath`
+/// (default `@.to`), token ID from the field value.
+fn nft_lookup(
+    ctx: &RenderContext<'_>,
+    val: &ArgumentValue,
+    params: Option<&FormatParams>,
+) -> Option<NftLookup> {
+    let ArgumentValue::Uint(id) = val else {
+        return None;
+    };
+    let collection_path = params
+        .and_then(|p| p.collection_path.as_deref())
+        .unwrap_or("@.to");
+    let ArgumentValue::Address(collection) = resolve_path(ctx.decoded, collection_path)? else {
+        return None;
+    };
+    Some(NftLookup {
+        chain_id: resolve_chain_id(ctx, params),
+        collection: format!("0x{}", hex::encode(collection)),
+        token_id: BigUint::from_bytes_be(id).to_string(),
     })
 }
 
@@ -166,7 +495,7 @@ fn render_fields(
         match field {
             DisplayField::Reference { reference } => {
                 if let Some(resolved) = resolve_reference(ctx.descriptor, reference) {
-                    let mut sub = render_fields(ctx, std::slice::from_ref(&resolved))?;
+                    let mut sub = render_fields(ctx, core::slice::from_ref(&resolved))?;
                     entries.append(&mut sub);
                 } else {
                     ctx.warnings
@@ -195,10 +524,21 @@ fn render_fields(
 
                 let formatted =
                     format_value(ctx, &value, format.as_ref(), params.as_ref(), path, label)?;
+                let name_source = match (format, &value) {
+                    (Some(FieldFormat::AddressName), Some(ArgumentValue::Address(addr))) => {
+                        resolve_address_name(ctx, addr, params.as_ref()).map(|name| name.source)
+                    }
+                    _ => None,
+                };
+                if let Some(val) = &value {
+                    check_lookalikes(ctx, val, label);
+                }
 
                 entries.push(DisplayEntry::Item(DisplayItem {
                     label: label.clone(),
                     value: formatted,
+                    name_source,
+                    warnings: core::mem::take(&mut ctx.field_warnings),
                 }));
             }
         }
@@ -215,7 +555,7 @@ fn render_field_group(
     let mut items = Vec::new();
 
     for field in &group.fields {
-        let sub_entries = render_fields(ctx, std::slice::from_ref(field))?;
+        let sub_entries = render_fields(ctx, core::slice::from_ref(field))?;
         for entry in sub_entries {
             match entry {
                 DisplayEntry::Item(item) => items.push(item),
@@ -389,14 +729,28 @@ fn format_value(
         FieldFormat::Date => format_date(val),
         FieldFormat::Enum => format_enum(ctx, val, params),
         FieldFormat::Address => Ok(format_address(val)),
-        FieldFormat::AddressName => Ok(format_address_name(ctx, val)),
+        FieldFormat::AddressName => {
+            check_address_type(ctx, val, params, label);
+            Ok(format_address_name(ctx, val, params))
+        }
         FieldFormat::Number => Ok(format_number(val)),
         FieldFormat::Raw => Ok(format_raw(val)),
         FieldFormat::TokenTicker => format_token_ticker(ctx, val, params),
-        FieldFormat::ChainId => format_chain_id(val),
+        FieldFormat::ChainId => format_chain_id(ctx, val),
         FieldFormat::Duration => Ok(format_duration(val)),
         FieldFormat::Unit => Ok(format_unit(val, params)),
-        FieldFormat::Calldata | FieldFormat::NftName => {
+        FieldFormat::NftName => {
+            let name = nft_lookup(ctx, val, params).and_then(|nft| ctx.lookups.nft_names.get(&nft));
+            if let Some(name) = name {
+                return Ok(name.clone());
+            }
+            ctx.warnings.push(format!(
+                "NFT name not found for field '{}' (path: {})",
+                label, path
+            ));
+            Ok(format_raw(val))
+        }
+        FieldFormat::Calldata => {
             ctx.warnings.push(format!(
                 "format {:?} not yet implemented for field '{}' (path: {})",
                 fmt, label, path
@@ -406,7 +760,7 @@ fn format_value(
     }
 }
 
-fn format_raw(val: &ArgumentValue) -> String {
+pub(crate) fn format_raw(val: &ArgumentValue) -> String {
     match val {
         ArgumentValue::Address(addr) => format!("0x{}", hex::encode(addr)),
         ArgumentValue::Uint(bytes) | ArgumentValue::Int(bytes) => {
@@ -436,18 +790,202 @@ fn format_address(val: &ArgumentValue) -> String {
     }
 }
 
-fn format_address_name(ctx: &RenderContext<'_>, val: &ArgumentValue) -> String {
+fn format_address_name(
+    ctx: &RenderContext<'_>,
+    val: &ArgumentValue,
+    params: Option<&FormatParams>,
+) -> String {
     if let ArgumentValue::Address(addr) = val {
-        let hex_addr = format!("0x{}", hex::encode(addr));
-        if let Some(label) = ctx.address_book.resolve(&hex_addr) {
-            return label.to_string();
-        }
-        eip55_checksum(addr)
+        resolve_address_name(ctx, addr, params)
+            .map(|name| name.name)
+            .unwrap_or_else(|| eip55_checksum(addr))
     } else {
         format_raw(val)
     }
 }
 
+/// Name of an address from the descriptor's address book, then from prefetched
+/// lookups whose source the field's `sources` param accepts.
+///
+/// Descriptor labels are always accepted: they come from the descriptor itself.
+fn resolve_address_name(
+    ctx: &RenderContext<'_>,
+    addr: &[u8; 20],
+    params: Option<&FormatParams>,
+) -> Option<AddressName> {
+    let hex_addr = format!("0x{}", hex::encode(addr));
+    if let Some(label) = ctx.address_book.resolve(&hex_addr) {
+        let kind =
+            is_deployment(ctx.descriptor, ctx.chain_id, &hex_addr).then_some(AddressType::Contract);
+        return Some(AddressName {
+            name: label.to_string(),
+            source: NameSource::Descriptor,
+            kind,
+        });
+    }
+    let lookup = AddressLookup {
+        chain_id: ctx.chain_id,
+        address: hex_addr,
+    };
+    let sources = params.and_then(|p| p.sources.as_ref());
+    ctx.lookups
+        .address_names
+        .get(&lookup)
+        .filter(|name| sources.is_none_or(|s| s.iter().any(|s| s == name.source.key())))
+        .cloned()
+}
+
+/// Warn when an `addressName` value is known to be a different kind of address
+/// than the field's `types` param accepts.
+fn check_address_type(
+    ctx: &mut RenderContext<'_>,
+    val: &ArgumentValue,
+    params: Option<&FormatParams>,
+    label: &str,
+) {
+    let (ArgumentValue::Address(addr), Some(expected)) =
+        (val, params.and_then(|p| p.types.as_ref()))
+    else {
+        return;
+    };
+    let hex_addr = format!("0x{}", hex::encode(addr));
+    let known_token = ctx
+        .lookup_token(&TokenLookupKey::new(ctx.chain_id, &hex_addr))
+        .is_some();
+    let named = resolve_address_name(ctx, addr, None).and_then(|name| name.kind);
+    let has_code = ctx
+        .lookups
+        .has_code
+        .get(&AddressLookup {
+            chain_id: ctx.chain_id,
+            address: hex_addr,
+        })
+        .copied();
+    if let Some(warning) = address_kind_warning(label, expected, known_token, named, has_code) {
+        ctx.warnings.push(warning.message.clone());
+        ctx.field_warnings.push(warning);
+    }
+}
+
+/// Flag addresses in `val`, including inside arrays and tuples, imitating one
+/// of the caller's known addresses.
+fn check_lookalikes(ctx: &mut RenderContext<'_>, val: &ArgumentValue, label: &str) {
+    let Some(known) = ctx.known_addresses else {
+        return;
+    };
+    match val {
+        ArgumentValue::Address(addr) => {
+            if let Some(warning) = lookalike_warning(known, addr, label, ctx.lookalike_chars) {
+                ctx.warnings.push(warning.message.clone());
+                ctx.field_warnings.push(warning);
+            }
+        }
+        ArgumentValue::Array(items) | ArgumentValue::Tuple(items) => {
+            for item in items {
+                check_lookalikes(ctx, item, label);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Danger warning when `addr` shares its first and last `chars` hex characters
+/// with a known address it is not.
+pub(crate) fn lookalike_warning(
+    known: &AddressBook,
+    addr: &[u8; 20],
+    label: &str,
+    chars: usize,
+) -> Option<FieldWarning> {
+    let hex_addr = format!("0x{}", hex::encode(addr));
+    let (known_address, known_label) = known.find_lookalike(&hex_addr, chars)?;
+    let known_address = parse_address(known_address)
+        .map(|bytes| eip55_checksum(&bytes))
+        .unwrap_or_else(|| known_address.to_string());
+    Some(FieldWarning {
+        severity: WarningSeverity::Danger,
+        message: format!(
+            "'{label}' address {} looks like your known address {known_label} ({known_address}) but is different",
+            eip55_checksum(addr)
+        ),
+        kind: FieldWarningKind::LookAlikeAddress {
+            known_address,
+            known_label: known_label.to_string(),
+        },
+    })
+}
+
+pub(crate) fn parse_address(address: &str) -> Option<[u8; 20]> {
+    let bytes = hex::decode(address.strip_prefix("0x").unwrap_or(address)).ok()?;
+    bytes.try_into().ok()
+}
+
+/// Warning for an `addressName` value whose kind contradicts the field's `types`.
+///
+/// The kind comes from the token lookups, then the address's name, then its code:
+/// an address without code is an EOA, one with code some contract (possibly a
+/// token, collection or smart-contract wallet). A field accepting only tokens also
+/// warns about an address that is not a known token.
+pub(crate) fn address_kind_warning(
+    label: &str,
+    expected: &[AddressType],
+    known_token: bool,
+    named: Option<AddressType>,
+    has_code: Option<bool>,
+) -> Option<FieldWarning> {
+    let kind = if known_token {
+        Some(AddressType::Token)
+    } else {
+        named
+    };
+    if let Some(kind) = kind {
+        return address_type_warning(label, kind, expected);
+    }
+    match has_code {
+        Some(false) => return address_type_warning(label, AddressType::Eoa, expected),
+        Some(true) if expected.iter().all(|e| *e == AddressType::Eoa) => {
+            return address_type_warning(label, AddressType::Contract, expected)
+        }
+        _ => {}
+    }
+    (expected == [AddressType::Token]).then(|| FieldWarning {
+        kind: FieldWarningKind::AddressTypeMismatch,
+        severity: WarningSeverity::Warning,
+        message: format!("'{label}' is not a known token address"),
+    })
+}
+
+/// Danger warning for an address of `kind` where one of `expected` is required.
+pub(crate) fn address_type_warning(
+    label: &str,
+    kind: AddressType,
+    expected: &[AddressType],
+) -> Option<FieldWarning> {
+    if expected.iter().any(|e| kind.satisfies(*e)) {
+        return None;
+    }
+    let expected: Vec<&str> = expected.iter().map(AddressType::label).collect();
+    Some(FieldWarning {
+        kind: FieldWarningKind::AddressTypeMismatch,
+        severity: WarningSeverity::Danger,
+        message: format!(
+            "'{label}' is {} {} address, expected {}",
+            if kind == AddressType::Eoa { "an" } else { "a" },
+            kind.label(),
+            expected.join(" or ")
+        ),
+    })
+}
+
+/// Whether `address` is one of the descriptor's own deployments on `chain_id`.
+pub(crate) fn is_deployment(descriptor: &Descriptor, chain_id: u64, address: &str) -> bool {
+    descriptor
+        .context
+        .deployments()
+        .iter()
+        .any(|d| d.chain_id == chain_id && d.address.eq_ignore_ascii_case(address))
+}
+
 /// EIP-55 mixed-case checksum encoding.
 fn eip55_checksum(addr: &[u8; 20]) -> String {
     use tiny_keccak::{Hasher, Keccak};
@@ -510,14 +1048,14 @@ fn format_token_amount(
                 // Check for native currency
                 if let Some(ref native) = params.native_currency_address {
                     if addr_hex.to_lowercase() == native.to_lowercase() {
-                        Some(native_token_meta(lookup_chain_id))
+                        ctx.native_token_meta(lookup_chain_id)
                     } else {
                         let key = TokenLookupKey::new(lookup_chain_id, &addr_hex);
-                        ctx.token_source.lookup(&key)
+                        ctx.lookup_token(&key)
                     }
                 } else {
                     let key = TokenLookupKey::new(lookup_chain_id, &addr_hex);
-                    ctx.token_source.lookup(&key)
+                    ctx.lookup_token(&key)
                 }
             } else {
                 None
@@ -591,7 +1129,7 @@ fn format_token_ticker(
     if let ArgumentValue::Address(addr) = val {
         let addr_hex = format!("0x{}", hex::encode(addr));
         let key = TokenLookupKey::new(lookup_chain_id, &addr_hex);
-        if let Some(meta) = ctx.token_source.lookup(&key) {
+        if let Some(meta) = ctx.lookup_token(&key) {
             return Ok(meta.symbol);
         }
     }
@@ -600,11 +1138,11 @@ fn format_token_ticker(
     Ok(format_raw(val))
 }
 
-fn format_chain_id(val: &ArgumentValue) -> Result<String, Error> {
+fn format_chain_id(ctx: &RenderContext<'_>, val: &ArgumentValue) -> Result<String, Error> {
     if let ArgumentValue::Uint(bytes) = val {
         let n = BigUint::from_bytes_be(bytes);
         let chain_id: u64 = n.try_into().unwrap_or(0);
-        Ok(chain_name(chain_id))
+        Ok(ctx.chains.chain_name(chain_id))
     } else {
         Ok(format_raw(val))
     }
@@ -630,39 +1168,25 @@ fn resolve_chain_id(ctx: &RenderContext<'_>, params: Option<&FormatParams>) -> u
     ctx.chain_id
 }
 
-/// Get native token metadata for a chain.
-fn native_token_meta(chain_id: u64) -> crate::token::TokenMeta {
-    let (symbol, name) = match chain_id {
-        1 | 5 | 11155111 => ("ETH", "Ether"),
-        137 | 80001 => ("MATIC", "Polygon"),
-        56 | 97 => ("BNB", "BNB"),
-        43114 | 43113 => ("AVAX", "Avalanche"),
-        250 => ("FTM", "Fantom"),
-        42161 | 421613 => ("ETH", "Ether"),
-        10 | 420 => ("ETH", "Ether"),
-        8453 | 84531 => ("ETH", "Ether"),
-        _ => ("ETH", "Ether"),
-    };
-    crate::token::TokenMeta {
-        symbol: symbol.to_string(),
-        decimals: 18,
-        name: name.to_string(),
-    }
-}
-
 fn format_amount(
-    ctx: &RenderContext<'_>,
+    ctx: &mut RenderContext<'_>,
     val: &ArgumentValue,
     path: &str,
 ) -> Result<String, Error> {
     match val {
         ArgumentValue::Uint(bytes) | ArgumentValue::Int(bytes) => {
             let n = BigUint::from_bytes_be(bytes);
-            if path.starts_with("@.value") {
-                let meta = native_token_meta(ctx.chain_id);
+            if !path.starts_with("@.value") {
+                return Ok(n.to_string());
+            }
+            if let Some(meta) = ctx.native_token_meta(ctx.chain_id) {
                 let formatted = format_with_decimals(&n, meta.decimals);
                 Ok(format!("{} {}", formatted, meta.symbol))
             } else {
+                ctx.warnings.push(format!(
+                    "native currency unknown for chain {}",
+                    ctx.chain_id
+                ));
                 Ok(n.to_string())
             }
         }
@@ -675,25 +1199,57 @@ fn format_date(val: &ArgumentValue) -> Result<String, Error> {
         ArgumentValue::Uint(bytes) => {
             let n = BigUint::from_bytes_be(bytes);
             let timestamp: i64 = i64::try_from(n).unwrap_or(0);
-
-            let dt = time::OffsetDateTime::from_unix_timestamp(timestamp)
-                .map_err(|e| Error::Render(format!("invalid timestamp: {e}")))?;
-
-            let format = time::format_description::parse(
-                "[year]-[month]-[day] [hour]:[minute]:[second] UTC",
-            )
-            .map_err(|e| Error::Render(format!("format error: {e}")))?;
-
-            Ok(dt
-                .format(&format)
-                .map_err(|e| Error::Render(format!("format error: {e}")))?)
+            format_timestamp(timestamp)
+                .ok_or_else(|| Error::Render(format!("invalid timestamp: {timestamp}")))
         }
         _ => Ok(format_raw(val)),
     }
 }
 
+/// Unix timestamp as `YYYY-MM-DD HH:MM:SS UTC`; `None` outside years ±9999.
+#[cfg(feature = "std")]
+pub(crate) fn format_timestamp(timestamp: i64) -> Option<String> {
+    let format =
+        time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]:[second] UTC");
+    time::OffsetDateTime::from_unix_timestamp(timestamp)
+        .ok()?
+        .format(&format)
+        .ok()
+}
+
+/// Unix timestamp as `YYYY-MM-DD HH:MM:SS UTC`; `None` outside years ±9999.
+///
+/// Civil-from-days conversion (proleptic Gregorian) for builds without `time`.
+#[cfg(any(not(feature = "std"), test))]
+pub(crate) fn format_timestamp_civil(timestamp: i64) -> Option<String> {
+    // -9999-01-01T00:00:00Z ..= 9999-12-31T23:59:59Z, the range `time` accepts
+    if !(-377_705_116_800..=253_402_300_799).contains(&timestamp) {
+        return None;
+    }
+    let (days, secs) = (timestamp.div_euclid(86_400), timestamp.rem_euclid(86_400));
+    let z = days + 719_468;
+    let (era, doe) = (z.div_euclid(146_097), z.rem_euclid(146_097));
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    let sign = if year < 0 { "-" } else { "" };
+    Some(format!(
+        "{sign}{:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        year.abs(),
+        secs / 3_600,
+        secs / 60 % 60,
+        secs % 60
+    ))
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) use format_timestamp_civil as format_timestamp;
+
 fn format_enum(
-    ctx: &mut RenderContext<'_>,
+    ctx: &RenderContext<'_>,
     val: &ArgumentValue,
     params: Option<&FormatParams>,
 ) -> Result<String, Error> {
@@ -742,7 +1298,7 @@ pub(crate) fn format_with_decimals(amount: &BigUint, decimals: u8) -> String {
     if s.len() <= decimals {
         let zeros = decimals - s.len();
         let mut result = String::from("0.");
-        result.extend(std::iter::repeat_n('0', zeros));
+        result.extend(core::iter::repeat_n('0', zeros));
         result.push_str(&s);
         // Trim trailing zeros after decimal point
         let trimmed = result.trim_end_matches('0');
@@ -910,23 +1466,26 @@ fn resolve_and_format_for_interpolation(
             match field_format {
                 Some(FieldFormat::Date) => format_date(&v).unwrap_or_else(|_| format_raw(&v)),
                 Some(FieldFormat::Number) => format_number(&v),
+                Some(FieldFormat::Enum) => {
+                    format_enum(ctx, &v, field_params).unwrap_or_else(|_| format_raw(&v))
+                }
                 Some(FieldFormat::Address) => format_address(&v),
+                Some(FieldFormat::AddressName) => format_address_name(ctx, &v, field_params),
                 Some(FieldFormat::TokenAmount) => {
                     format_token_amount_for_interpolation(ctx, &v, field_params)
                 }
                 Some(FieldFormat::Amount) => {
-                    if path.starts_with("@.value") {
-                        let meta = native_token_meta(ctx.chain_id);
-                        match &v {
-                            ArgumentValue::Uint(bytes) | ArgumentValue::Int(bytes) => {
-                                let n = BigUint::from_bytes_be(bytes);
-                                let formatted = format_with_decimals(&n, meta.decimals);
-                                format!("{} {}", formatted, meta.symbol)
-                            }
-                            _ => format_raw(&v),
+                    let native = path
+                        .starts_with("@.value")
+                        .then(|| ctx.native_token_meta(ctx.chain_id))
+                        .flatten();
+                    match (&v, native) {
+                        (ArgumentValue::Uint(bytes) | ArgumentValue::Int(bytes), Some(meta)) => {
+                            let n = BigUint::from_bytes_be(bytes);
+                            let formatted = format_with_decimals(&n, meta.decimals);
+                            format!("{} {}", formatted, meta.symbol)
                         }
-                    } else {
-                        format_raw(&v)
+                        _ => format_raw(&v),
                     }
                 }
                 _ => format_raw(&v),
@@ -955,11 +1514,11 @@ fn format_token_amount_for_interpolation(
                 let addr_hex = format!("0x{}", hex::encode(addr));
                 if let Some(ref native) = p.native_currency_address {
                     if addr_hex.to_lowercase() == native.to_lowercase() {
-                        return Some(native_token_meta(lookup_chain_id));
+                        return ctx.native_token_meta(lookup_chain_id);
                     }
                 }
                 let key = TokenLookupKey::new(lookup_chain_id, &addr_hex);
-                ctx.token_source.lookup(&key)
+                ctx.lookup_token(&key)
             } else {
                 None
             }
@@ -1012,9 +1571,10 @@ mod tests {
 
     #[test]
     fn test_chain_name() {
-        assert_eq!(chain_name(1), "Ethereum");
-        assert_eq!(chain_name(137), "Polygon");
-        assert_eq!(chain_name(99999), "Chain 99999");
+        let chains = WellKnownChainSource::shared();
+        assert_eq!(chains.chain_name(1), "Ethereum");
+        assert_eq!(chains.chain_name(137), "Polygon");
+        assert_eq!(chains.chain_name(99999), "Chain 99999");
     }
 
     #[test]
@@ -1052,6 +1612,7 @@ mod tests {
                     ]),
                 },
             ],
+            warnings: Vec::new(),
         };
 
         let descriptor: Descriptor = serde_json::from_str(
@@ -1064,8 +1625,13 @@ mod tests {
             decoded: &decoded,
             chain_id: 1,
             token_source: &token_source,
+            chains: WellKnownChainSource::shared(),
             address_book: &address_book,
+            known_addresses: None,
+            lookalike_chars: LOOKALIKE_CHARS,
+            lookups: &LookupResults::default(),
             warnings: Vec::new(),
+            field_warnings: Vec::new(),
         };
 
         let result = interpolate_intent("Send ${1} to ${0}", &ctx, &[]);
@@ -1074,4 +1640,369 @@ mod tests {
             "Send 1000 to 0x0000000000000000000000000000000000000000"
         );
     }
+
+    #[test]
+    fn test_collect_calldata_lookups_and_nft_names() {
+        let descriptor: Descriptor = serde_json::from_str(
+            r#"{
+                "context": {"contract": {"deployments": []}},
+                "metadata": {"owner": "test", "addressBook": {"0x00000000000000000000000000000000000000b1": "Known"}},
+                "display": {"definitions": {}, "formats": {
+                    "buy(address,address,uint256,address)": {
+                        "intent": "Buy",
+                        "fields": [
+                            {"path": "@.0", "label": "Pay with", "format": "tokenTicker"},
+                            {"path": "@.1", "label": "Seller", "format": "addressName"},
+                            {"fieldGroup": {"label": "Item", "iteration": "sequential", "fields": [
+                                {"path": "@.2", "label": "NFT", "format": "nftName", "params": {"collectionPath": "@.3"}}
+                            ]}},
+                            {"path": "@.3", "label": "Hidden", "format": "addressName", "visible": "never"}
+                        ]
+                    }
+                }}
+            }"#,
+        )
+        .unwrap();
+        let sig = crate::decoder::parse_signature("buy(address,address,uint256,address)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        for word in [0xa1u8, 0xb1, 0x2a, 0xc1] {
+            let mut buf = [0u8; 32];
+            buf[31] = word;
+            calldata.extend_from_slice(&buf);
+        }
+        let decoded = crate::decoder::decode_calldata(&sig, &calldata).unwrap();
+
+        let empty = LookupResults::default();
+        let sources = RenderSources {
+            tokens: &crate::token::EmptyTokenSource,
+            chains: WellKnownChainSource::shared(),
+            lookups: &empty,
+            known_addresses: None,
+            lookalike_chars: LOOKALIKE_CHARS,
+        };
+        let requests = collect_calldata_lookups(&descriptor, 1, &decoded, &sources).unwrap();
+        assert_eq!(
+            requests.tokens,
+            vec![TokenLookupKey::new(
+                1,
+                "0x00000000000000000000000000000000000000a1"
+            )]
+        );
+        // The seller is labelled by the descriptor; the hidden field is skipped
+        assert!(requests.addresses.is_empty());
+        let nft = NftLookup {
+            chain_id: 1,
+            collection: "0x00000000000000000000000000000000000000c1".to_string(),
+            token_id: "42".to_string(),
+        };
+        assert_eq!(requests.nfts, vec![nft.clone()]);
+
+        let mut lookups = LookupResults::default();
+        lookups.nft_names.insert(nft, "Bored Ape #42".to_string());
+        let sources = RenderSources {
+            tokens: &crate::token::EmptyTokenSource,
+            chains: WellKnownChainSource::shared(),
+            lookups: &lookups,
+            known_addresses: None,
+            lookalike_chars: LOOKALIKE_CHARS,
+        };
+        let model = format_calldata_with_sources(
+            &descriptor,
+            1,
+            "0x00000000000000000000000000000000000000ff",
+            &decoded,
+            None,
+            &sources,
+        )
+        .unwrap();
+        match &model.entries[2] {
+            DisplayEntry::Group { items, .. } => assert_eq!(items[0].value, "Bored Ape #42"),
+            other => panic!("expected group, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_address_name_types_and_sources() {
+        let descriptor: Descriptor = serde_json::from_str(
+            r#"{
+                "context": {"contract": {"deployments": []}},
+                "metadata": {"owner": "test"},
+                "display": {"definitions": {}, "formats": {
+                    "send(address,address)": {
+                        "intent": "Send",
+                        "fields": [
+                            {"path": "@.0", "label": "Recipient", "format": "addressName",
+                             "params": {"types": ["eoa", "wallet"], "sources": ["local"]}},
+                            {"path": "@.1", "label": "Spender", "format": "addressName",
+                             "params": {"types": ["contract"]}}
+                        ]
+                    }
+                }}
+            }"#,
+        )
+        .unwrap();
+        let sig = crate::decoder::parse_signature("send(address,address)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        for word in [0xa1u8, 0xb1] {
+            let mut buf = [0u8; 32];
+            buf[31] = word;
+            calldata.extend_from_slice(&buf);
+        }
+        let decoded = crate::decoder::decode_calldata(&sig, &calldata).unwrap();
+        let token = "0x00000000000000000000000000000000000000a1";
+        let spender = "0x00000000000000000000000000000000000000b1";
+
+        let empty = LookupResults::default();
+        let sources = RenderSources {
+            tokens: &crate::token::EmptyTokenSource,
+            chains: WellKnownChainSource::shared(),
+            lookups: &empty,
+            known_addresses: None,
+            lookalike_chars: LOOKALIKE_CHARS,
+        };
+        let requests = collect_calldata_lookups(&descriptor, 1, &decoded, &sources).unwrap();
+        assert!(requests.tokens.contains(&TokenLookupKey::new(1, token)));
+
+        let mut lookups = LookupResults::default();
+        lookups.tokens.insert(
+            TokenLookupKey::new(1, token),
+            TokenMeta {
+                symbol: "USDC".to_string(),
+                decimals: 6,
+                name: "USD Coin".to_string(),
+                logo_uri: None,
+            },
+        );
+        for (address, name, source) in [
+            (token, "usdc.eth", NameSource::Ens),
+            (spender, "Router", NameSource::Trusted),
+        ] {
+            lookups.address_names.insert(
+                AddressLookup {
+                    chain_id: 1,
+                    address: address.to_string(),
+                },
+                AddressName {
+                    name: name.to_string(),
+                    source,
+                    kind: Some(AddressType::Contract),
+                },
+            );
+        }
+        let sources = RenderSources {
+            tokens: &crate::token::EmptyTokenSource,
+            chains: WellKnownChainSource::shared(),
+            lookups: &lookups,
+            known_addresses: None,
+            lookalike_chars: LOOKALIKE_CHARS,
+        };
+        let model = format_calldata_with_sources(&descriptor, 1, spender, &decoded, None, &sources)
+            .unwrap();
+
+        let DisplayEntry::Item(recipient) = &model.entries[0] else {
+            panic!("expected item");
+        };
+        // The ENS name is not an accepted source; the token is not an EOA or wallet
+        assert_eq!(
+            recipient.value,
+            "0x00000000000000000000000000000000000000A1"
+        );
+        assert_eq!(recipient.name_source, None);
+        assert_eq!(
+            recipient.warnings,
+            vec![FieldWarning {
+                kind: FieldWarningKind::AddressTypeMismatch,
+                severity: WarningSeverity::Danger,
+                message: "'Recipient' is a token address, expected eoa or wallet".to_string(),
+            }]
+        );
+        assert_eq!(model.warnings, vec![recipient.warnings[0].message.clone()]);
+
+        let DisplayEntry::Item(spender) = &model.entries[1] else {
+            panic!("expected item");
+        };
+        assert_eq!(spender.value, "Router");
+        assert_eq!(spender.name_source, Some(NameSource::Trusted));
+        assert!(spender.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_address_type_from_code_presence() {
+        let descriptor: Descriptor = serde_json::from_str(
+            r#"{
+                "context": {"contract": {"deployments": []}},
+                "metadata": {"owner": "test"},
+                "display": {"definitions": {}, "formats": {
+                    "route(address,address,address)": {
+                        "intent": "Route",
+                        "fields": [
+                            {"path": "@.0", "label": "Pool", "format": "addressName",
+                             "params": {"types": ["contract"]}},
+                            {"path": "@.1", "label": "Owner", "format": "addressName",
+                             "params": {"types": ["wallet"]}},
+                            {"path": "@.2", "label": "Token", "format": "addressName",
+                             "params": {"types": ["token"]}}
+                        ]
+                    }
+                }}
+            }"#,
+        )
+        .unwrap();
+        let sig = crate::decoder::parse_signature("route(address,address,address)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        for word in [0xa1u8, 0xa1, 0xc1] {
+            let mut buf = [0u8; 32];
+            buf[31] = word;
+            calldata.extend_from_slice(&buf);
+        }
+        let decoded = crate::decoder::decode_calldata(&sig, &calldata).unwrap();
+        let eoa = "0x00000000000000000000000000000000000000a1";
+        let contract = "0x00000000000000000000000000000000000000c1";
+
+        let empty = LookupResults::default();
+        let mut sources = RenderSources {
+            tokens: &crate::token::EmptyTokenSource,
+            chains: WellKnownChainSource::shared(),
+            lookups: &empty,
+            known_addresses: None,
+            lookalike_chars: LOOKALIKE_CHARS,
+        };
+        let requests = collect_calldata_lookups(&descriptor, 1, &decoded, &sources).unwrap();
+        let code: Vec<&str> = requests.code.iter().map(|l| l.address.as_str()).collect();
+        assert_eq!(code, vec![eoa, contract]);
+
+        let field_warnings = |model: &DisplayModel| -> Vec<Vec<(WarningSeverity, String)>> {
+            model
+                .entries
+                .iter()
+                .map(|entry| match entry {
+                    DisplayEntry::Item(item) => item
+                        .warnings
+                        .iter()
+                        .map(|w| (w.severity, w.message.clone()))
+                        .collect(),
+                    DisplayEntry::Group { .. } => panic!("expected item"),
+                })
+                .collect()
+        };
+
+        // Without code presence only the token-only field can tell
+        let model =
+            format_calldata_with_sources(&descriptor, 1, eoa, &decoded, None, &sources).unwrap();
+        assert_eq!(
+            field_warnings(&model),
+            vec![
+                vec![],
+                vec![],
+                vec![(
+                    WarningSeverity::Warning,
+                    "'Token' is not a known token address".to_string()
+                )],
+            ]
+        );
+
+        // An address without code is an EOA: fine as a wallet, not as a contract
+        let mut lookups = LookupResults::default();
+        for (address, has_code) in [(eoa, false), (contract, true)] {
+            lookups.has_code.insert(
+                AddressLookup {
+                    chain_id: 1,
+                    address: address.to_string(),
+                },
+                has_code,
+            );
+        }
+        sources.lookups = &lookups;
+        let model =
+            format_calldata_with_sources(&descriptor, 1, eoa, &decoded, None, &sources).unwrap();
+        assert_eq!(
+            field_warnings(&model),
+            vec![
+                vec![(
+                    WarningSeverity::Danger,
+                    "'Pool' is an eoa address, expected contract".to_string()
+                )],
+                vec![],
+                vec![(
+                    WarningSeverity::Warning,
+                    "'Token' is not a known token address".to_string()
+                )],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_display_model_serializes_camel_case() {
+        let model = DisplayModel {
+            intent: "Swap".to_string(),
+            interpolated_intent: None,
+            entries: vec![
+                DisplayEntry::Item(DisplayItem {
+                    label: "To".to_string(),
+                    value: "alice.eth".to_string(),
+                    name_source: Some(NameSource::Ens),
+                    warnings: vec![FieldWarning {
+                        kind: FieldWarningKind::LookAlikeAddress {
+                            known_address: "0xAb".to_string(),
+                            known_label: "Savings".to_string(),
+                        },
+                        severity: WarningSeverity::Danger,
+                        message: "m".to_string(),
+                    }],
+                }),
+                DisplayEntry::Group {
+                    label: "Path".to_string(),
+                    iteration: GroupIteration::Bundled,
+                    items: Vec::new(),
+                },
+            ],
+            warnings: Vec::new(),
+            risks: vec![RiskFinding {
+                kind: risk::RiskKind::UnlimitedAllowance,
+                severity: WarningSeverity::Warning,
+                message: "r".to_string(),
+            }],
+        };
+        assert_eq!(
+            serde_json::to_value(&model).unwrap(),
+            serde_json::json!({
+                "intent": "Swap",
+                "interpolatedIntent": null,
+                "entries": [
+                    {
+                        "type": "item",
+                        "label": "To",
+                        "value": "alice.eth",
+                        "nameSource": "ens",
+                        "warnings": [{
+                            "kind": {"type": "lookAlikeAddress", "knownAddress": "0xAb", "knownLabel": "Savings"},
+                            "severity": "danger",
+                            "message": "m"
+                        }]
+                    },
+                    {"type": "group", "label": "Path", "iteration": "bundled", "items": []}
+                ],
+                "warnings": [],
+                "risks": [{"kind": "unlimitedAllowance", "severity": "warning", "message": "r"}]
+            })
+        );
+    }
+
+    #[test]
+    fn test_civil_timestamp_matches_time() {
+        for ts in [
+            0,
+            1_700_000_000,
+            951_782_400, // 2000-02-29
+            -1,
+            -62_135_596_800, // 0001-01-01
+            -62_135_596_801,
+            -377_705_116_800,
+            253_402_300_799,
+        ] {
+            assert_eq!(format_timestamp_civil(ts), format_timestamp(ts), "{ts}");
+        }
+        assert_eq!(format_timestamp_civil(253_402_300_800), None);
+        assert_eq!(format_timestamp_civil(-377_705_116_801), None);
+    }
 }
@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use crate::prelude::*;
+
 /// Unified error type for the ERC-7730 library.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -21,8 +23,10 @@ pub enum Error {
     Render(String),
 }
 
-/// Errors during signature parsing and calldata decoding.
-#[derive(Debug, Error)]
+/// Errors during signature parsing and calldata decoding or encoding.
+///
+/// Byte positions (`at`) are offsets into the calldata, selector included.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum DecodeError {
     #[error("invalid function signature: {0}")]
     InvalidSignature(String),
@@ -38,6 +42,62 @@ pub enum DecodeError {
 
     #[error("unsupported type: {0}")]
     UnsupportedType(String),
+
+    #[error("invalid argument value: {0}")]
+    InvalidValue(String),
+
+    #[error("address at byte {at} has non-zero high-order bytes")]
+    DirtyAddress { at: usize },
+
+    #[error("bool at byte {at} is neither 0 nor 1")]
+    InvalidBool { at: usize },
+
+    #[error("{param} at byte {at} is out of range")]
+    ValueOutOfRange { param: String, at: usize },
+
+    #[error("non-zero padding at byte {at}")]
+    DirtyPadding { at: usize },
+
+    #[error("offset at byte {at} points to byte {actual}, expected {expected}")]
+    NonCanonicalOffset {
+        at: usize,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("{len} trailing bytes after the arguments")]
+    TrailingBytes { len: usize },
+
+    #[error("{limit} exceeds the decode limit of {max}")]
+    LimitExceeded { limit: &'static str, max: usize },
+}
+
+/// Errors while decoding a compact binary descriptor.
+#[derive(Debug, Error)]
+pub enum CompactError {
+    #[error("unexpected end of input")]
+    Truncated,
+
+    #[error("not a compact descriptor")]
+    BadMagic,
+
+    #[error("unsupported compact descriptor version {0}")]
+    UnsupportedVersion(u8),
+
+    #[error("invalid {what} tag {tag}")]
+    InvalidTag { what: &'static str, tag: u8 },
+
+    #[error("invalid UTF-8 string")]
+    InvalidUtf8,
+
+    #[error("non-canonical {0}")]
+    NonCanonical(&'static str),
+
+    #[error("nesting too deep")]
+    TooDeep,
+
+    #[error("{0} trailing bytes")]
+    TrailingBytes(usize),
 }
 
 /// Errors during descriptor resolution.
@@ -51,4 +111,10 @@ pub enum ResolveError {
 
     #[error("io error: {0}")]
     Io(String),
+
+    #[error("include cycle detected: {0}")]
+    IncludeCycle(String),
+
+    #[error("proxy resolution failed: {0}")]
+    Proxy(String),
 }
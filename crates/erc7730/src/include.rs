@@ -0,0 +1,287 @@
+//! Descriptor `includes` resolution — loads shared base descriptors and deep-merges
+//! them under the including descriptor following the ERC-7730 merge rules.
+
+use serde_json::{Map, Value};
+
+use crate::error::ResolveError;
+use crate::prelude::*;
+use crate::types::descriptor::Descriptor;
+
+/// Maximum number of documents in an include chain.
+pub const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// An include chain being resolved, from the root descriptor down to its deepest base.
+///
+/// Drive it with [`pending`](Self::pending) / [`push`](Self::push) using whatever
+/// loader fits the source (filesystem, HTTP), then call [`finish`](Self::finish).
+#[derive(Debug, Clone)]
+pub struct IncludeChain {
+    locations: Vec<String>,
+    documents: Vec<Value>,
+}
+
+impl IncludeChain {
+    /// Start a chain from the raw JSON of the including (root) descriptor.
+    pub fn new(location: &str, root: Value) -> Self {
+        Self {
+            locations: vec![location.to_string()],
+            documents: vec![root],
+        }
+    }
+
+    /// The next document to load: `(location of the including document, includes reference)`.
+    pub fn pending(&self) -> Option<(&str, &str)> {
+        let last = self.documents.last()?;
+        let location = self.locations.last()?;
+        include_ref(last).map(|reference| (location.as_str(), reference))
+    }
+
+    /// Append a loaded document, detecting cycles and overly long chains.
+    pub fn push(&mut self, location: String, document: Value) -> Result<(), ResolveError> {
+        if self.locations.contains(&location) {
+            let mut chain = self.locations.clone();
+            chain.push(location);
+            return Err(ResolveError::IncludeCycle(chain.join(" -> ")));
+        }
+        if self.documents.len() >= MAX_INCLUDE_DEPTH {
+            return Err(ResolveError::Parse(format!(
+                "include chain exceeds {MAX_INCLUDE_DEPTH} documents at {location}"
+            )));
+        }
+        self.locations.push(location);
+        self.documents.push(document);
+        Ok(())
+    }
+
+    /// Merge the chain (deepest base first) and parse the result as a descriptor.
+    pub fn finish(mut self) -> Result<Descriptor, ResolveError> {
+        let mut merged = self.documents.pop().unwrap_or(Value::Null);
+        while let Some(overlay) = self.documents.pop() {
+            merged = merge_json(merged, overlay);
+        }
+        if let Value::Object(ref mut obj) = merged {
+            obj.remove("includes");
+        }
+        serde_json::from_value(merged).map_err(|e| ResolveError::Parse(e.to_string()))
+    }
+}
+
+/// Return the `includes` reference of a raw descriptor, if any.
+pub fn include_ref(json: &Value) -> Option<&str> {
+    json.get("includes").and_then(Value::as_str)
+}
+
+/// Resolve an `includes` reference against the location of the including document.
+///
+/// Absolute URLs are returned unchanged; relative references are joined onto the
+/// including document's directory with `.` and `..` segments normalized.
+pub fn join_location(including: &str, reference: &str) -> String {
+    if reference.starts_with("https://") || reference.starts_with("http://") {
+        return reference.to_string();
+    }
+
+    let (prefix, path) = match including.find("://") {
+        Some(scheme_end) => {
+            let host_end = including[scheme_end + 3..]
+                .find('/')
+                .map(|i| scheme_end + 3 + i)
+                .unwrap_or(including.len());
+            (&including[..host_end], &including[host_end..])
+        }
+        None => ("", including),
+    };
+
+    let mut segments: Vec<&str> = if reference.starts_with('/') {
+        Vec::new()
+    } else {
+        let mut segs: Vec<&str> = path.split('/').collect();
+        segs.pop(); // drop the including file name
+        segs
+    };
+
+    for segment in reference.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                if segments.last().is_some_and(|s| !s.is_empty()) {
+                    segments.pop();
+                }
+            }
+            other => segments.push(other),
+        }
+    }
+
+    let joined = segments
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("/");
+    if prefix.is_empty() && !path.starts_with('/') && !reference.starts_with('/') {
+        joined
+    } else {
+        format!("{prefix}/{joined}")
+    }
+}
+
+/// Deep-merge an included descriptor (`base`) with the including one (`overlay`).
+///
+/// Objects merge key by key with the including side winning. `fields` arrays merge
+/// by `path`: matching fields are merged in place, new ones are appended in order.
+/// Any other array or scalar from the including side replaces the base value.
+pub fn merge_json(base: Value, overlay: Value) -> Value {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            Value::Object(merge_objects(base, overlay))
+        }
+        (_, overlay) => overlay,
+    }
+}
+
+fn merge_objects(mut base: Map<String, Value>, overlay: Map<String, Value>) -> Map<String, Value> {
+    for (key, value) in overlay {
+        let merged = match (key.as_str(), base.remove(&key), value) {
+            ("fields", Some(Value::Array(b)), Value::Array(o)) => Value::Array(merge_fields(b, o)),
+            (_, Some(b), o) => merge_json(b, o),
+            (_, None, o) => o,
+        };
+        base.insert(key, merged);
+    }
+    base
+}
+
+fn merge_fields(mut base: Vec<Value>, overlay: Vec<Value>) -> Vec<Value> {
+    for field in overlay {
+        let position = field_path(&field)
+            .and_then(|path| base.iter().position(|b| field_path(b) == Some(path)));
+        match position {
+            Some(i) => {
+                let existing = core::mem::take(&mut base[i]);
+                base[i] = merge_json(existing, field);
+            }
+            None => base.push(field),
+        }
+    }
+    base
+}
+
+fn field_path(field: &Value) -> Option<&str> {
+    field.get("path").and_then(Value::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn base_erc20() -> Value {
+        json!({
+            "metadata": {
+                "owner": "common",
+                "constants": { "max": "0xff" }
+            },
+            "display": {
+                "formats": {
+                    "transfer(address,uint256)": {
+                        "intent": "Transfer",
+                        "fields": [
+                            { "path": "@.0", "label": "To", "format": "address" },
+                            { "path": "@.1", "label": "Amount", "format": "number" }
+                        ]
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_merge_overrides_and_appends_fields() {
+        let overlay = json!({
+            "includes": "common-erc20.json",
+            "context": { "contract": { "deployments": [{ "chainId": 1, "address": "0xabc" }] } },
+            "metadata": { "owner": "Tether", "constants": { "min": "0x01" } },
+            "display": {
+                "formats": {
+                    "transfer(address,uint256)": {
+                        "fields": [
+                            { "path": "@.1", "label": "Amount", "format": "tokenAmount" },
+                            { "path": "@.value", "label": "Value", "format": "amount" }
+                        ]
+                    }
+                }
+            }
+        });
+
+        let merged = merge_json(base_erc20(), overlay);
+        assert_eq!(merged["metadata"]["owner"], "Tether");
+        assert_eq!(merged["metadata"]["constants"]["max"], "0xff");
+        assert_eq!(merged["metadata"]["constants"]["min"], "0x01");
+
+        let format = &merged["display"]["formats"]["transfer(address,uint256)"];
+        assert_eq!(format["intent"], "Transfer");
+        let fields = format["fields"].as_array().unwrap();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields[0]["label"], "To");
+        assert_eq!(fields[1]["format"], "tokenAmount");
+        assert_eq!(fields[2]["path"], "@.value");
+    }
+
+    #[test]
+    fn test_chain_finish_parses_descriptor() {
+        let root = json!({
+            "includes": "common-erc20.json",
+            "context": { "contract": { "deployments": [{ "chainId": 1, "address": "0xabc" }] } },
+            "metadata": { "owner": "Tether" }
+        });
+        let mut chain = IncludeChain::new("tether/calldata.json", root);
+        let (location, reference) = chain.pending().unwrap();
+        let next = join_location(location, reference);
+        assert_eq!(next, "tether/common-erc20.json");
+        chain.push(next, base_erc20()).unwrap();
+        assert!(chain.pending().is_none());
+
+        let descriptor = chain.finish().unwrap();
+        assert!(descriptor.includes.is_none());
+        assert_eq!(descriptor.metadata.owner.as_deref(), Some("Tether"));
+        assert!(descriptor
+            .display
+            .formats
+            .contains_key("transfer(address,uint256)"));
+    }
+
+    #[test]
+    fn test_chain_detects_cycle() {
+        let mut chain = IncludeChain::new("a.json", json!({ "includes": "b.json" }));
+        chain
+            .push("b.json".to_string(), json!({ "includes": "a.json" }))
+            .unwrap();
+        let err = chain
+            .push("a.json".to_string(), json!({}))
+            .expect_err("cycle should be detected");
+        assert!(
+            matches!(err, ResolveError::IncludeCycle(ref c) if c == "a.json -> b.json -> a.json")
+        );
+    }
+
+    #[test]
+    fn test_join_location() {
+        assert_eq!(
+            join_location("aave/calldata-lpv3.json", "../common/erc20.json"),
+            "common/erc20.json"
+        );
+        assert_eq!(
+            join_location("/registry/aave/lpv3.json", "./common.json"),
+            "/registry/aave/common.json"
+        );
+        assert_eq!(
+            join_location(
+                "https://example.com/repo/main/aave/lpv3.json",
+                "../erc20.json"
+            ),
+            "https://example.com/repo/main/erc20.json"
+        );
+        assert_eq!(
+            join_location("aave/lpv3.json", "https://example.com/erc20.json"),
+            "https://example.com/erc20.json"
+        );
+    }
+}
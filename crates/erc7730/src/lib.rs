@@ -2,27 +2,82 @@
 //! and EIP-712 typed data for human-readable display using JSON descriptors.
 //!
 //! Entry points: [`format_calldata()`], [`format_typed_data()`], [`format()`].
+//!
+//! Without the default `std` feature the crate is `no_std + alloc`, so embedded
+//! signers can decode and render on-device; filesystem sources, wall-clock risk
+//! checks and the RPC-backed token cache are then unavailable.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+// The `cdylib` needs a panic handler and allocator; on hosted targets take std's.
+// The library itself only uses `core` and `alloc` without the `std` feature.
+#[cfg(all(not(feature = "std"), not(target_os = "none")))]
+extern crate std;
 
 #[cfg(feature = "uniffi")]
 uniffi::setup_scaffolding!();
 
 pub mod address_book;
+pub mod address_name;
+#[cfg(all(feature = "capi", not(target_arch = "wasm32")))]
+pub mod capi;
+pub mod chain;
+pub mod compact;
 pub mod decoder;
 pub mod eip712;
 pub mod engine;
 pub mod error;
+pub mod include;
+pub mod permit2;
+pub mod prefetch;
+mod prelude;
+pub mod proxy;
 pub mod resolver;
+pub mod risk;
+pub mod rpc;
+pub mod signatures;
+pub mod templates;
 pub mod token;
+pub mod token_list;
 pub mod types;
 #[cfg(feature = "uniffi")]
 pub mod uniffi_compat;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub mod wasm;
 
 use error::Error;
+use prelude::*;
 
 // Re-exports for convenience
-pub use engine::{DisplayEntry, DisplayItem, DisplayModel};
-pub use resolver::{DescriptorSource, FilesystemSource, ResolvedDescriptor};
-pub use token::{CompositeTokenSource, TokenMeta, TokenSource, WellKnownTokenSource};
+pub use address_name::{
+    AddressName, AddressNameSource, CompositeAddressNameSource, ContactsAddressNameSource,
+    EnsAddressNameSource, NameSource, StaticAddressNameSource,
+};
+pub use chain::{ChainSource, CompositeChainSource, StaticChainSource, WellKnownChainSource};
+pub use engine::{
+    DisplayEntry, DisplayItem, DisplayModel, FieldWarning, FieldWarningKind, WarningSeverity,
+};
+pub use prefetch::{
+    BatchLookup, CodeBatchLookup, CompositeBatchLookup, LookupRequests, LookupResults,
+};
+pub use proxy::{ProxyAwareSource, ProxyResolver, SlotProxyResolver, StorageReader};
+#[cfg(feature = "std")]
+pub use resolver::FilesystemSource;
+pub use resolver::{
+    CompositeDescriptorSource, DescriptorSource, FactoryAwareSource, FactoryOracle,
+    ResolvedDescriptor,
+};
+pub use risk::{RiskFinding, RiskKind};
+pub use rpc::{CodeReader, EthCallTransport};
+pub use signatures::{SignatureSource, WellKnownSignatureSource};
+use templates::TokenStandard;
+#[cfg(feature = "std")]
+pub use token::OnChainTokenSource;
+pub use token::{
+    AsyncTokenSource, CompositeTokenSource, TokenMeta, TokenSource, WellKnownTokenSource,
+};
+pub use token_list::{TokenList, TokenListSource};
 pub use types::descriptor::Descriptor;
 
 /// Format contract calldata for clear signing display.
@@ -63,6 +118,61 @@ pub fn format_calldata_with_from(
     from: Option<&str>,
     token_source: &dyn TokenSource,
 ) -> Result<DisplayModel, Error> {
+    format_calldata_inner(
+        descriptor,
+        chain_id,
+        to,
+        calldata,
+        value,
+        from,
+        token_source,
+        WellKnownSignatureSource::shared(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn format_calldata_inner(
+    descriptor: &Descriptor,
+    chain_id: u64,
+    to: &str,
+    calldata: &[u8],
+    value: Option<&[u8]>,
+    from: Option<&str>,
+    token_source: &dyn TokenSource,
+    signatures: &dyn SignatureSource,
+) -> Result<DisplayModel, Error> {
+    let Some(decoded) = decode_for_descriptor(
+        descriptor,
+        chain_id,
+        to,
+        calldata,
+        value,
+        from,
+        decoder::DecodeMode::Lenient,
+        decoder::DecodeLimits::default(),
+    )?
+    else {
+        // Graceful fallback: return raw preview for unknown selectors
+        return Ok(build_raw_fallback(calldata, signatures));
+    };
+
+    // Render the display model
+    engine::format_calldata(descriptor, chain_id, to, &decoded, value, token_source)
+}
+
+/// Decode calldata against the descriptor format matching its selector, with
+/// container values injected. `None` when the descriptor has no such format.
+#[allow(clippy::too_many_arguments)]
+fn decode_for_descriptor(
+    descriptor: &Descriptor,
+    chain_id: u64,
+    to: &str,
+    calldata: &[u8],
+    value: Option<&[u8]>,
+    from: Option<&str>,
+    mode: decoder::DecodeMode,
+    limits: decoder::DecodeLimits,
+) -> Result<Option<decoder::DecodedArguments>, Error> {
     if calldata.len() < 4 {
         return Err(Error::Decode(error::DecodeError::CalldataTooShort {
             expected: 4,
@@ -73,22 +183,17 @@ pub fn format_calldata_with_from(
     let actual_selector = &calldata[..4];
 
     // Find matching format key and parse its signature
-    let (sig, _format_key) = match find_matching_signature(descriptor, actual_selector) {
-        Ok(result) => result,
-        Err(_) => {
-            // Graceful fallback: return raw preview for unknown selectors
-            return Ok(build_raw_fallback(calldata));
-        }
+    let Ok((sig, _format_key)) = find_matching_signature(descriptor, actual_selector) else {
+        return Ok(None);
     };
 
     // Decode calldata using the parsed signature
-    let mut decoded = decoder::decode_calldata(&sig, calldata)?;
+    let mut decoded = decoder::decode_calldata_with_limits(&sig, calldata, mode, limits)?;
 
     // Inject container values as synthetic arguments
     inject_container_values(&mut decoded, chain_id, to, value, from);
 
-    // Render the display model
-    engine::format_calldata(descriptor, chain_id, to, &decoded, value, token_source)
+    Ok(Some(decoded))
 }
 
 /// Inject EIP-7730 container values (@.value, @.to, @.chainId, @.from) as synthetic arguments.
@@ -161,8 +266,53 @@ fn parse_address_bytes(addr: &str) -> Option<[u8; 20]> {
     Some(result)
 }
 
-/// Build a raw fallback DisplayModel for unknown selectors (graceful degradation).
-fn build_raw_fallback(calldata: &[u8]) -> DisplayModel {
+/// Build a fallback DisplayModel for unknown selectors (graceful degradation).
+///
+/// Arguments are decoded with the first candidate signature from `signatures`
+/// that fits the calldata; otherwise they are shown as raw 32-byte words.
+fn build_raw_fallback(calldata: &[u8], signatures: &dyn SignatureSource) -> DisplayModel {
+    let candidates = match calldata.get(..4).and_then(|s| <[u8; 4]>::try_from(s).ok()) {
+        Some(selector) => signatures.lookup(&selector),
+        None => Vec::new(),
+    };
+    let decoded = candidates.iter().find_map(|candidate| {
+        let sig = decoder::parse_signature(candidate).ok()?;
+        let decoded = decoder::decode_calldata(&sig, calldata).ok()?;
+        Some((candidate, decoded))
+    });
+
+    let mut model = match decoded {
+        Some((signature, decoded)) => DisplayModel {
+            intent: signature.clone(),
+            interpolated_intent: None,
+            entries: decoded
+                .args
+                .iter()
+                .map(|arg| {
+                    DisplayEntry::Item(DisplayItem {
+                        label: format!("Param {} ({})", arg.index, arg.param_type.canonical()),
+                        value: engine::format_raw(&arg.value),
+                        name_source: None,
+                        warnings: Vec::new(),
+                    })
+                })
+                .collect(),
+            warnings: vec!["No matching descriptor format found".to_string()],
+            risks: Vec::new(),
+        },
+        None => build_hex_fallback(calldata),
+    };
+    if candidates.len() > 1 {
+        model.warnings.push(format!(
+            "Selector matches multiple known signatures: {}",
+            candidates.join(", ")
+        ));
+    }
+    model
+}
+
+/// Raw preview splitting the arguments into 32-byte hex words.
+fn build_hex_fallback(calldata: &[u8]) -> DisplayModel {
     let selector = if calldata.len() >= 4 {
         format!("0x{}", hex::encode(&calldata[..4]))
     } else {
@@ -181,6 +331,8 @@ fn build_raw_fallback(calldata: &[u8]) -> DisplayModel {
         entries.push(DisplayEntry::Item(DisplayItem {
             label: format!("Param {}", i),
             value: format!("0x{}", hex::encode(chunk)),
+            name_source: None,
+            warnings: Vec::new(),
         }));
     }
 
@@ -189,10 +341,14 @@ fn build_raw_fallback(calldata: &[u8]) -> DisplayModel {
         interpolated_intent: None,
         entries,
         warnings: vec!["No matching descriptor format found".to_string()],
+        risks: Vec::new(),
     }
 }
 
 /// Format EIP-712 typed data for clear signing display.
+///
+/// Chains are named by [`WellKnownChainSource`]; [`format_typed_with_options`]
+/// takes the chain source from [`FormatOptions::chains`].
 pub fn format_typed_data(
     descriptor: &Descriptor,
     data: &eip712::TypedData,
@@ -201,9 +357,73 @@ pub fn format_typed_data(
     eip712::format_typed_data(descriptor, data, token_source)
 }
 
+/// Optional collaborators for the high-level [`format_with_options`] entry point.
+#[derive(Clone, Copy, Default)]
+pub struct FormatOptions<'a> {
+    /// Sender address for `@.from` resolution.
+    pub from: Option<&'a str>,
+    /// Proxy resolver tried when no descriptor is indexed for the target address.
+    pub proxy: Option<&'a dyn ProxyResolver>,
+    /// Signature database for the raw fallback; defaults to [`WellKnownSignatureSource`].
+    pub signatures: Option<&'a dyn SignatureSource>,
+    /// Batch resolver for lookups the token source cannot answer; when set, they are
+    /// collected before rendering and resolved in one round-trip.
+    pub lookups: Option<&'a dyn BatchLookup>,
+    /// Chain metadata for chain names and native currencies; defaults to
+    /// [`WellKnownChainSource`].
+    pub chains: Option<&'a dyn ChainSource>,
+    /// The user's known counterparties, for address-poisoning detection.
+    pub known_addresses: Option<&'a address_book::AddressBook>,
+    /// Leading and trailing hex characters (at least 1) an address must share with a known
+    /// address to be flagged; defaults to [`engine::LOOKALIKE_CHARS`].
+    pub lookalike_chars: Option<usize>,
+    /// Whether non-canonical calldata is rejected or rendered with warnings.
+    pub decode_mode: decoder::DecodeMode,
+    /// Resource limits for decoding the calldata.
+    pub decode_limits: decoder::DecodeLimits,
+}
+
+impl FormatOptions<'_> {
+    /// Render sources over `tokens` and `lookups`, with these options' chains and
+    /// known addresses.
+    fn sources<'s>(
+        &'s self,
+        tokens: &'s dyn TokenSource,
+        lookups: &'s prefetch::LookupResults,
+    ) -> engine::RenderSources<'s> {
+        engine::RenderSources {
+            tokens,
+            chains: self
+                .chains
+                .unwrap_or_else(|| WellKnownChainSource::shared()),
+            lookups,
+            known_addresses: self.known_addresses,
+            lookalike_chars: self.lookalike_chars.unwrap_or(engine::LOOKALIKE_CHARS),
+        }
+    }
+}
+
+/// Resolve `requests` in one round-trip; a failed batch becomes a warning.
+async fn prefetch_lookups(
+    batch: &dyn BatchLookup,
+    requests: &prefetch::LookupRequests,
+) -> (prefetch::LookupResults, Option<String>) {
+    if requests.is_empty() {
+        return Default::default();
+    }
+    match batch.resolve_batch(requests).await {
+        Ok(results) => (results, None),
+        Err(e) => (
+            prefetch::LookupResults::default(),
+            Some(format!("lookup prefetch failed: {e}")),
+        ),
+    }
+}
+
 /// High-level convenience: resolve descriptor then format calldata.
 ///
-/// Gracefully degrades to raw preview when no descriptor is found.
+/// Gracefully degrades to raw preview when no descriptor is found. To follow
+/// proxies, wrap `source` in a [`ProxyAwareSource`].
 pub async fn format(
     chain_id: u64,
     to: &str,
@@ -212,24 +432,14 @@ pub async fn format(
     source: &dyn DescriptorSource,
     tokens: &dyn TokenSource,
 ) -> Result<DisplayModel, Error> {
-    match source.resolve_calldata(chain_id, to).await {
-        Ok(resolved) => format_calldata_with_from(
-            &resolved.descriptor,
-            chain_id,
-            to,
-            calldata,
-            value,
-            None,
-            tokens,
-        ),
-        Err(error::ResolveError::NotFound { .. }) => Ok(build_raw_fallback(calldata)),
-        Err(e) => Err(Error::Resolve(e)),
-    }
+    let options = FormatOptions::default();
+    format_with_options(chain_id, to, calldata, value, source, tokens, &options).await
 }
 
 /// High-level convenience: resolve descriptor then format calldata (with from address).
 ///
-/// Gracefully degrades to raw preview when no descriptor is found.
+/// Gracefully degrades to raw preview when no descriptor is found. To follow
+/// proxies, wrap `source` in a [`ProxyAwareSource`].
 pub async fn format_with_from(
     chain_id: u64,
     to: &str,
@@ -239,28 +449,254 @@ pub async fn format_with_from(
     source: &dyn DescriptorSource,
     tokens: &dyn TokenSource,
 ) -> Result<DisplayModel, Error> {
-    match source.resolve_calldata(chain_id, to).await {
-        Ok(resolved) => format_calldata_with_from(
-            &resolved.descriptor,
-            chain_id,
-            to,
-            calldata,
-            value,
-            from,
-            tokens,
-        ),
-        Err(error::ResolveError::NotFound { .. }) => Ok(build_raw_fallback(calldata)),
+    let options = FormatOptions {
+        from,
+        ..FormatOptions::default()
+    };
+    format_with_options(chain_id, to, calldata, value, source, tokens, &options).await
+}
+
+/// High-level convenience: like [`format_with_from`], but when no descriptor is
+/// indexed for `to`, asks `proxy` for its implementation and retries resolution.
+///
+/// See [`format_with_options`] for the "Via proxy" entry this adds.
+#[allow(clippy::too_many_arguments)]
+pub async fn format_with_proxy(
+    chain_id: u64,
+    to: &str,
+    calldata: &[u8],
+    value: Option<&[u8]>,
+    from: Option<&str>,
+    source: &dyn DescriptorSource,
+    tokens: &dyn TokenSource,
+    proxy: &dyn ProxyResolver,
+) -> Result<DisplayModel, Error> {
+    let options = FormatOptions {
+        from,
+        proxy: Some(proxy),
+        ..FormatOptions::default()
+    };
+    format_with_options(chain_id, to, calldata, value, source, tokens, &options).await
+}
+
+/// High-level convenience: resolve descriptor then format calldata, with the
+/// optional collaborators in [`FormatOptions`].
+///
+/// When no descriptor is found, standard token calls are formatted with the
+/// built-in [`templates`]; anything else degrades to a raw preview.
+///
+/// When the descriptor was found through a proxy (via `options.proxy` or a
+/// [`ProxyAwareSource`]), a final "Via proxy" entry names the proxy standard and
+/// implementation address. The call is still rendered against `to`, the address
+/// actually called. A failed proxy lookup degrades like a missing descriptor, with
+/// a warning.
+pub async fn format_with_options(
+    chain_id: u64,
+    to: &str,
+    calldata: &[u8],
+    value: Option<&[u8]>,
+    source: &dyn DescriptorSource,
+    tokens: &dyn TokenSource,
+    options: &FormatOptions<'_>,
+) -> Result<DisplayModel, Error> {
+    let call = CalldataCall {
+        chain_id,
+        to,
+        calldata,
+        value,
+        tokens,
+        options,
+    };
+
+    let resolved = match options.proxy {
+        Some(proxy) => {
+            proxy::resolve_through_proxy(proxy, chain_id, to, |address| {
+                Box::pin(async move { source.resolve_calldata(chain_id, &address).await })
+            })
+            .await
+        }
+        None => source.resolve_calldata(chain_id, to).await,
+    };
+    match resolved {
+        Ok(resolved) => {
+            let mut model = call.render(&resolved.descriptor).await?;
+            note_proxy(&mut model, resolved.proxy.as_ref());
+            Ok(model)
+        }
+        Err(error::ResolveError::NotFound { .. }) => Ok(call.fallback().await),
+        Err(e @ error::ResolveError::Proxy(_)) => {
+            let mut model = call.fallback().await;
+            model.warnings.push(e.to_string());
+            Ok(model)
+        }
         Err(e) => Err(Error::Resolve(e)),
     }
 }
 
+/// Append the "Via proxy" entry for a descriptor resolved through a proxy.
+fn note_proxy(model: &mut DisplayModel, proxy: Option<&proxy::ProxyTarget>) {
+    if let Some(target) = proxy {
+        model.entries.push(DisplayEntry::Item(DisplayItem {
+            label: "Via proxy".to_string(),
+            value: format!(
+                "{} implementation {}",
+                target.kind.label(),
+                target.implementation
+            ),
+            name_source: None,
+            warnings: Vec::new(),
+        }));
+    }
+}
+
+/// A calldata formatting request of [`format_with_options`].
+struct CalldataCall<'a> {
+    chain_id: u64,
+    to: &'a str,
+    calldata: &'a [u8],
+    value: Option<&'a [u8]>,
+    tokens: &'a dyn TokenSource,
+    options: &'a FormatOptions<'a>,
+}
+
+impl CalldataCall<'_> {
+    fn signatures(&self) -> &dyn SignatureSource {
+        self.options
+            .signatures
+            .unwrap_or_else(|| WellKnownSignatureSource::shared())
+    }
+
+    /// Render with `descriptor`, prefetching lookups in one batch when configured.
+    async fn render(&self, descriptor: &Descriptor) -> Result<DisplayModel, Error> {
+        Ok(self.render_with_lookups(descriptor).await?.0)
+    }
+
+    /// [`render`](Self::render), also returning what the batch lookup resolved.
+    async fn render_with_lookups(
+        &self,
+        descriptor: &Descriptor,
+    ) -> Result<(DisplayModel, prefetch::LookupResults), Error> {
+        let Some(decoded) = decode_for_descriptor(
+            descriptor,
+            self.chain_id,
+            self.to,
+            self.calldata,
+            self.value,
+            self.options.from,
+            self.options.decode_mode,
+            self.options.decode_limits,
+        )?
+        else {
+            return Ok((
+                build_raw_fallback(self.calldata, self.signatures()),
+                prefetch::LookupResults::default(),
+            ));
+        };
+
+        // Phase 1: collect what the token source cannot answer and resolve it in one batch
+        let (lookups, warning) = match self.options.lookups {
+            Some(batch) => {
+                let empty = prefetch::LookupResults::default();
+                let requests = engine::collect_calldata_lookups(
+                    descriptor,
+                    self.chain_id,
+                    &decoded,
+                    &self.options.sources(self.tokens, &empty),
+                )?;
+                prefetch_lookups(batch, &requests).await
+            }
+            None => Default::default(),
+        };
+
+        // Phase 2: render synchronously from the filled results
+        let sources = self.options.sources(self.tokens, &lookups);
+        let mut model = engine::format_calldata_with_sources(
+            descriptor,
+            self.chain_id,
+            self.to,
+            &decoded,
+            self.value,
+            &sources,
+        )?;
+        model.warnings.extend(warning);
+        Ok((model, lookups))
+    }
+
+    /// Built-in token templates rank below registry descriptors, above the raw preview.
+    ///
+    /// The ERC-20 template needs `to` to be a token known to the token source or
+    /// the batch lookup: an ERC-721 `approve` or `transferFrom` has the same
+    /// selector and would show a token ID as an amount. Other contracts only get
+    /// the NFT-only templates, then the signature-decoded raw preview. Calldata
+    /// without a full selector, such as a plain ETH transfer, skips the templates.
+    async fn fallback(&self) -> DisplayModel {
+        let templates = templates::TokenTemplates::shared();
+        let Some(selector) = self.calldata.get(..4) else {
+            return build_raw_fallback(self.calldata, self.signatures());
+        };
+        let token = token::TokenLookupKey::new(self.chain_id, self.to);
+
+        let rendered = if let Some((standard, descriptor)) =
+            templates.find(selector, &[TokenStandard::Erc20])
+        {
+            let known = self.tokens.lookup(&token).is_some();
+            if known || self.options.lookups.is_some() {
+                match self.render_with_lookups(descriptor).await {
+                    Ok((model, lookups)) if known || lookups.tokens.contains_key(&token) => {
+                        Some((standard, model))
+                    }
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        } else if let Some((standard, descriptor)) =
+            templates.find(selector, &[TokenStandard::Erc721, TokenStandard::Erc1155])
+        {
+            self.render(descriptor)
+                .await
+                .ok()
+                .map(|model| (standard, model))
+        } else {
+            None
+        };
+
+        match rendered {
+            Some((standard, mut model)) => {
+                model.warnings.push(format!(
+                    "No registry descriptor found; formatted with the built-in {} template",
+                    standard.label()
+                ));
+                model
+            }
+            None => build_raw_fallback(self.calldata, self.signatures()),
+        }
+    }
+}
+
 /// High-level convenience: resolve descriptor then format EIP-712 typed data.
 ///
-/// Gracefully degrades to raw preview when no descriptor is found.
+/// When no descriptor is found, Permit2 signatures are formatted with the
+/// built-in [`permit2`] formatter; anything else degrades to a raw preview.
 pub async fn format_typed(
     data: &eip712::TypedData,
     source: &dyn DescriptorSource,
     tokens: &dyn TokenSource,
+) -> Result<DisplayModel, Error> {
+    format_typed_with_options(data, source, tokens, &FormatOptions::default()).await
+}
+
+/// High-level convenience: resolve descriptor then format EIP-712 typed data,
+/// with the optional collaborators in [`FormatOptions`].
+///
+/// Like [`format_with_options`], the token and address-name lookups the display
+/// needs are collected first and resolved through `options.lookups` in one batch.
+/// The calldata-only options (`from`, `signatures` and decoding) are ignored.
+pub async fn format_typed_with_options(
+    data: &eip712::TypedData,
+    source: &dyn DescriptorSource,
+    tokens: &dyn TokenSource,
+    options: &FormatOptions<'_>,
 ) -> Result<DisplayModel, Error> {
     let chain_id = data.domain.chain_id.unwrap_or(1);
     let address = data
@@ -268,23 +704,141 @@ pub async fn format_typed(
         .verifying_contract
         .as_deref()
         .unwrap_or("0x0000000000000000000000000000000000000000");
-    match source.resolve_typed(chain_id, address).await {
-        Ok(resolved) => format_typed_data(&resolved.descriptor, data, tokens),
-        Err(error::ResolveError::NotFound { .. }) => Ok(eip712::build_typed_raw_fallback(data)),
+    let resolved = match options.proxy {
+        Some(proxy) => {
+            proxy::resolve_through_proxy(proxy, chain_id, address, |address| {
+                Box::pin(async move { source.resolve_typed(chain_id, &address).await })
+            })
+            .await
+        }
+        None => source.resolve_typed(chain_id, address).await,
+    };
+    match resolved {
+        Ok(resolved) => {
+            let descriptor = &resolved.descriptor;
+            let (lookups, warning) = match options.lookups {
+                Some(batch) => {
+                    let empty = prefetch::LookupResults::default();
+                    let requests = eip712::collect_typed_lookups(
+                        descriptor,
+                        data,
+                        &options.sources(tokens, &empty),
+                    );
+                    prefetch_lookups(batch, &requests).await
+                }
+                None => Default::default(),
+            };
+            let mut model = eip712::format_typed_data_with_sources(
+                descriptor,
+                data,
+                &options.sources(tokens, &lookups),
+            )?;
+            model.warnings.extend(warning);
+            note_proxy(&mut model, resolved.proxy.as_ref());
+            Ok(model)
+        }
+        Err(error::ResolveError::NotFound { .. }) if permit2::is_permit2(data) => {
+            format_permit2_typed(data, source, tokens, options).await
+        }
+        Err(error::ResolveError::NotFound { .. }) => {
+            Ok(typed_raw_fallback(data, tokens, options).await)
+        }
+        Err(e @ error::ResolveError::Proxy(_)) => {
+            let mut model = typed_raw_fallback(data, tokens, options).await;
+            model.warnings.push(e.to_string());
+            Ok(model)
+        }
         Err(e) => Err(Error::Resolve(e)),
     }
 }
 
+/// Raw message fields of typed data no descriptor covers, with the permit risk
+/// checks resolving the spender through the batch lookup.
+async fn typed_raw_fallback(
+    data: &eip712::TypedData,
+    tokens: &dyn TokenSource,
+    options: &FormatOptions<'_>,
+) -> DisplayModel {
+    let (lookups, warning) = match options.lookups {
+        Some(batch) => {
+            let mut requests = prefetch::LookupRequests::default();
+            eip712::collect_spender_lookups(
+                data,
+                &address_book::AddressBook::empty(),
+                &mut requests,
+            );
+            prefetch_lookups(batch, &requests).await
+        }
+        None => Default::default(),
+    };
+    let mut model = eip712::build_typed_raw_fallback(data, &options.sources(tokens, &lookups));
+    model.warnings.extend(warning);
+    model
+}
+
+/// Built-in Permit2 formatting, with the spender's descriptor naming the
+/// spender and decoding any witness.
+async fn format_permit2_typed(
+    data: &eip712::TypedData,
+    source: &dyn DescriptorSource,
+    tokens: &dyn TokenSource,
+    options: &FormatOptions<'_>,
+) -> Result<DisplayModel, Error> {
+    let chain_id = data.domain.chain_id.unwrap_or(1);
+    let mut warning = None;
+    let spender = match data.message.get("spender").and_then(|s| s.as_str()) {
+        Some(spender) => match source.resolve_typed(chain_id, spender).await {
+            Ok(resolved) => Some(resolved.descriptor),
+            Err(error::ResolveError::NotFound { .. }) => None,
+            Err(e) => {
+                warning = Some(format!("spender descriptor lookup failed: {e}"));
+                None
+            }
+        },
+        None => None,
+    };
+
+    let (lookups, prefetch_warning) = match options.lookups {
+        Some(batch) => {
+            let empty = prefetch::LookupResults::default();
+            let requests = permit2::collect_permit2_lookups(
+                data,
+                spender.as_ref(),
+                &options.sources(tokens, &empty),
+            );
+            prefetch_lookups(batch, &requests).await
+        }
+        None => Default::default(),
+    };
+    let model =
+        permit2::format_permit2(data, spender.as_ref(), &options.sources(tokens, &lookups))?;
+    let Some(mut model) = model else {
+        return Ok(typed_raw_fallback(data, tokens, options).await);
+    };
+    model.warnings.extend(warning);
+    model.warnings.extend(prefetch_warning);
+    model.warnings.push(
+        "No registry descriptor found; formatted with the built-in Permit2 template".to_string(),
+    );
+    Ok(model)
+}
+
 /// Find a format key whose signature matches the calldata selector.
 fn find_matching_signature(
     descriptor: &Descriptor,
     actual_selector: &[u8],
 ) -> Result<(decoder::FunctionSignature, String), Error> {
+    let Some(selector) = actual_selector.get(..4) else {
+        return Err(Error::Decode(error::DecodeError::CalldataTooShort {
+            expected: 4,
+            actual: actual_selector.len(),
+        }));
+    };
     for key in descriptor.display.formats.keys() {
         if key.contains('(') {
             match decoder::parse_signature(key) {
                 Ok(sig) => {
-                    if sig.selector[..] == actual_selector[..4] {
+                    if sig.selector[..] == selector[..] {
                         return Ok((sig, key.clone()));
                     }
                 }
@@ -295,7 +849,7 @@ fn find_matching_signature(
 
     Err(Error::Render(format!(
         "no matching format key for selector 0x{}",
-        hex::encode(&actual_selector[..4])
+        hex::encode(selector)
     )))
 }
 
@@ -458,6 +1012,7 @@ mod tests {
                 symbol: "USDT".to_string(),
                 decimals: 6,
                 name: "Tether USD".to_string(),
+                logo_uri: None,
             },
         );
 
@@ -707,85 +1262,1277 @@ mod tests {
         assert_eq!(result.intent, "Transfer tokens");
     }
 
-    #[test]
-    fn test_stakeweight_increase_unlock_time() {
-        let json = r#"{
-            "context": {
-                "contract": {
-                    "deployments": [
-                        { "chainId": 10, "address": "0x521B4C065Bbdbe3E20B3727340730936912DfA46" }
-                    ]
-                }
-            },
-            "metadata": {
-                "owner": "WalletConnect",
-                "contractName": "StakeWeight",
-                "enums": {},
-                "constants": {},
-                "addressBook": {},
-                "maps": {}
-            },
-            "display": {
-                "definitions": {},
-                "formats": {
-                    "increaseUnlockTime(uint256)": {
-                        "intent": "Increase Unlock Time",
-                        "interpolatedIntent": "Increase unlock time to ${@.0}",
-                        "fields": [
-                            {
-                                "path": "@.0",
-                                "label": "New Unlock Time",
-                                "format": "date"
-                            }
-                        ]
-                    }
-                }
-            }
-        }"#;
+    #[tokio::test]
+    async fn test_non_canonical_calldata_warns_or_fails_in_strict_mode() {
+        let descriptor = Descriptor::from_json(test_descriptor_json()).unwrap();
+        let mut source = resolver::StaticSource::new();
+        source.add_calldata(1, "0xdac17f958d2ee523a2206206994597c13d831ec7", descriptor);
 
-        let descriptor = Descriptor::from_json(json).unwrap();
-        // Real calldata from yttrium test
-        let calldata =
-            hex::decode("7c616fe6000000000000000000000000000000000000000000000000000000006945563d")
-                .unwrap();
+        let mut calldata = decoder::CalldataBuilder::new("transfer(address,uint256)")
+            .address("0x0000000000000000000000000000000000000001")
+            .uint(1000)
+            .build()
+            .unwrap();
+        // High-order garbage the contract ignores but a careless wallet might display
+        calldata[4] = 0xde;
 
-        let result = format_calldata(
-            &descriptor,
-            10,
-            "0x521B4C065Bbdbe3E20B3727340730936912DfA46",
+        let lenient = format(
+            1,
+            "0xdac17f958d2ee523a2206206994597c13d831ec7",
             &calldata,
             None,
+            &source,
             &EmptyTokenSource,
         )
+        .await
         .unwrap();
-
-        assert_eq!(result.intent, "Increase Unlock Time");
-        assert_eq!(result.entries.len(), 1);
-        if let DisplayEntry::Item(ref item) = result.entries[0] {
-            assert_eq!(item.label, "New Unlock Time");
-            assert_eq!(item.value, "2025-12-19 13:42:21 UTC");
-        } else {
-            panic!("expected Item");
-        }
+        assert_eq!(lenient.intent, "Transfer tokens");
         assert_eq!(
-            result.interpolated_intent.as_deref(),
-            Some("Increase unlock time to 2025-12-19 13:42:21 UTC")
+            lenient.warnings,
+            vec![
+                "non-canonical calldata: address at byte 4 has non-zero high-order bytes"
+                    .to_string()
+            ]
         );
-        assert!(result.warnings.is_empty());
+
+        let options = FormatOptions {
+            decode_mode: decoder::DecodeMode::Strict,
+            ..FormatOptions::default()
+        };
+        let strict = format_with_options(
+            1,
+            "0xdac17f958d2ee523a2206206994597c13d831ec7",
+            &calldata,
+            None,
+            &source,
+            &EmptyTokenSource,
+            &options,
+        )
+        .await;
+        assert!(matches!(
+            strict,
+            Err(Error::Decode(error::DecodeError::DirtyAddress { at: 4 }))
+        ));
     }
 
-    #[test]
-    fn test_eip712_format() {
-        let json = r#"{
-            "context": {
-                "eip712": {
-                    "deployments": [
-                        { "chainId": 1, "address": "0xabc" }
-                    ]
-                }
-            },
-            "metadata": {
-                "owner": "test",
+    #[tokio::test]
+    async fn test_format_with_proxy_resolves_implementation() {
+        use crate::proxy::{StaticStorageReader, EIP1967_IMPLEMENTATION_SLOT};
+
+        let proxy_addr = "0x00000000000000000000000000000000000000a1";
+        let implementation = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+        let descriptor = Descriptor::from_json(test_descriptor_json()).unwrap();
+        let mut source = resolver::StaticSource::new();
+        source.add_calldata(1, implementation, descriptor);
+
+        let mut reader = StaticStorageReader::new();
+        reader.insert_slot_address(1, proxy_addr, EIP1967_IMPLEMENTATION_SLOT, implementation);
+        let proxy = SlotProxyResolver::new(Box::new(reader.clone()));
+
+        let sig = decoder::parse_signature("transfer(address,uint256)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        calldata.extend_from_slice(&[0u8; 64]);
+
+        // Without a proxy resolver the proxy address is unknown
+        let result = format(1, proxy_addr, &calldata, None, &source, &EmptyTokenSource)
+            .await
+            .unwrap();
+        assert_eq!(result.intent, "transfer(address,uint256)");
+
+        let result = format_with_proxy(
+            1,
+            proxy_addr,
+            &calldata,
+            None,
+            None,
+            &source,
+            &EmptyTokenSource,
+            &proxy,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.intent, "Transfer tokens");
+        match result.entries.last() {
+            Some(DisplayEntry::Item(item)) => {
+                assert_eq!(item.label, "Via proxy");
+                assert_eq!(
+                    item.value,
+                    format!("EIP-1967 implementation {implementation}")
+                );
+            }
+            other => panic!("expected proxy note, got {other:?}"),
+        }
+
+        // The plain entry points follow proxies through a wrapped source
+        let mut inner = resolver::StaticSource::new();
+        inner.add_calldata(
+            1,
+            implementation,
+            Descriptor::from_json(test_descriptor_json()).unwrap(),
+        );
+        let proxied = ProxyAwareSource::new(
+            Box::new(inner),
+            Box::new(SlotProxyResolver::new(Box::new(reader.clone()))),
+        );
+        let result = format(1, proxy_addr, &calldata, None, &proxied, &EmptyTokenSource)
+            .await
+            .unwrap();
+        assert_eq!(result.intent, "Transfer tokens");
+        assert!(matches!(
+            result.entries.last(),
+            Some(DisplayEntry::Item(item)) if item.label == "Via proxy"
+        ));
+
+        // A slot holding something other than an address is reported, not truncated
+        let mut dirty = [0xffu8; 32];
+        dirty[31] = 0xa1;
+        reader.insert_slot(1, proxy_addr, EIP1967_IMPLEMENTATION_SLOT, dirty);
+        let proxy = SlotProxyResolver::new(Box::new(reader));
+        let result = format_with_proxy(
+            1,
+            proxy_addr,
+            &calldata,
+            None,
+            None,
+            &source,
+            &EmptyTokenSource,
+            &proxy,
+        )
+        .await
+        .unwrap();
+        assert!(!result
+            .entries
+            .iter()
+            .any(|entry| matches!(entry, DisplayEntry::Item(item) if item.label == "Via proxy")));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.starts_with("proxy resolution failed: slot word")));
+    }
+
+    #[tokio::test]
+    async fn test_signature_fallback_decodes_arguments() {
+        let source = resolver::StaticSource::new();
+        let sig = decoder::parse_signature("burnFrom(address,uint256)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        let mut to = [0u8; 32];
+        to[31] = 0xab;
+        calldata.extend_from_slice(&to);
+        let mut amount = [0u8; 32];
+        amount[30..].copy_from_slice(&1000u16.to_be_bytes());
+        calldata.extend_from_slice(&amount);
+
+        let result = format(1, "0xabc", &calldata, None, &source, &EmptyTokenSource)
+            .await
+            .unwrap();
+        assert_eq!(result.intent, "burnFrom(address,uint256)");
+        assert_eq!(result.warnings, vec!["No matching descriptor format found"]);
+        let items: Vec<(String, String)> = result
+            .entries
+            .iter()
+            .map(|e| match e {
+                DisplayEntry::Item(item) => (item.label.clone(), item.value.clone()),
+                other => panic!("expected item, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                (
+                    "Param 0 (address)".to_string(),
+                    "0x00000000000000000000000000000000000000ab".to_string()
+                ),
+                ("Param 1 (uint256)".to_string(), "1000".to_string()),
+            ]
+        );
+
+        // A selector the table does not know keeps the hex-word preview
+        let mut unknown = vec![0xde, 0xad, 0xbe, 0xef];
+        unknown.extend_from_slice(&amount);
+        let result = format(1, "0xabc", &unknown, None, &source, &EmptyTokenSource)
+            .await
+            .unwrap();
+        assert_eq!(result.intent, "Unknown function 0xdeadbeef");
+    }
+
+    #[tokio::test]
+    async fn test_short_calldata_for_unknown_contract_falls_back_to_raw() {
+        let source = resolver::StaticSource::new();
+        for calldata in [&[][..], &[0xa9], &[0xa9, 0x05], &[0xa9, 0x05, 0x9c]] {
+            let result = format(1, "0xabc", calldata, None, &source, &EmptyTokenSource)
+                .await
+                .unwrap();
+            assert_eq!(
+                result.intent,
+                format!("Unknown function 0x{}", hex::encode(calldata))
+            );
+            assert!(result.entries.is_empty());
+        }
+
+        let descriptor = Descriptor::from_json(test_descriptor_json()).unwrap();
+        assert!(matches!(
+            find_matching_signature(&descriptor, &[0xa9, 0x05, 0x9c]),
+            Err(Error::Decode(error::DecodeError::CalldataTooShort {
+                expected: 4,
+                actual: 3
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_token_template_without_registry_descriptor() {
+        let usdc = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        let mut tokens = StaticTokenSource::new();
+        tokens.insert(
+            1,
+            usdc,
+            TokenMeta {
+                symbol: "USDC".to_string(),
+                decimals: 6,
+                name: "USD Coin".to_string(),
+                logo_uri: None,
+            },
+        );
+        let source = resolver::StaticSource::new();
+
+        let sig = decoder::parse_signature("transfer(address,uint256)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        let mut recipient = [0u8; 32];
+        recipient[12..]
+            .copy_from_slice(&hex::decode("d8da6bf26964af9d7eed9e03e53415d37aa96045").unwrap());
+        calldata.extend_from_slice(&recipient);
+        let mut amount = [0u8; 32];
+        amount[24..].copy_from_slice(&10_000_000u64.to_be_bytes());
+        calldata.extend_from_slice(&amount);
+
+        let result = format(1, usdc, &calldata, None, &source, &tokens)
+            .await
+            .unwrap();
+        assert_eq!(result.intent, "Send");
+        assert_eq!(
+            result.interpolated_intent.as_deref(),
+            Some("Send 10 USDC to 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045")
+        );
+        assert_eq!(
+            result.warnings,
+            vec!["No registry descriptor found; formatted with the built-in ERC-20 template"]
+        );
+
+        // Unlimited approvals use the threshold message
+        let sig = decoder::parse_signature("approve(address,uint256)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        calldata.extend_from_slice(&recipient);
+        calldata.extend_from_slice(&[0xff; 32]);
+        let result = format(1, usdc, &calldata, None, &source, &tokens)
+            .await
+            .unwrap();
+        assert_eq!(
+            result.interpolated_intent.as_deref(),
+            Some("Approve 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045 to spend Unlimited USDC")
+        );
+        let risks: Vec<_> = result.risks.iter().map(|r| r.kind).collect();
+        assert_eq!(
+            risks,
+            vec![RiskKind::UnlimitedAllowance, RiskKind::UnknownSpender]
+        );
+
+        // An NFT approve shares the selector; an unknown collection is not read as a token
+        let collection = "0x00000000000000000000000000000000000000c1";
+        let mut calldata = sig.selector.to_vec();
+        calldata.extend_from_slice(&recipient);
+        calldata.extend_from_slice(&amount);
+        let result = format(1, collection, &calldata, None, &source, &tokens)
+            .await
+            .unwrap();
+        assert_eq!(result.intent, "approve(address,uint256)");
+        assert!(result.interpolated_intent.is_none());
+        assert!(result.risks.is_empty());
+        assert!(!result.warnings.iter().any(|w| w.contains("template")));
+    }
+
+    #[tokio::test]
+    async fn test_lookalike_recipient_is_flagged() {
+        let usdc = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        let mut known = address_book::AddressBook::empty();
+        known.insert(
+            "0xd8da6bf26964af9d7eed9e03e53415d37aa96045".to_string(),
+            "vitalik.eth".to_string(),
+        );
+        let options = FormatOptions {
+            known_addresses: Some(&known),
+            ..FormatOptions::default()
+        };
+        let sig = decoder::parse_signature("transfer(address,uint256)").unwrap();
+        let transfer_to = |recipient: &str| {
+            let mut calldata = sig.selector.to_vec();
+            let mut word = [0u8; 32];
+            word[12..].copy_from_slice(&hex::decode(recipient).unwrap());
+            calldata.extend_from_slice(&word);
+            calldata.extend_from_slice(&[0u8; 32]);
+            calldata
+        };
+        let source = resolver::StaticSource::new();
+        let mut tokens = StaticTokenSource::new();
+        tokens.insert(
+            1,
+            usdc,
+            TokenMeta {
+                symbol: "USDC".to_string(),
+                decimals: 6,
+                name: "USD Coin".to_string(),
+                logo_uri: None,
+            },
+        );
+
+        let calldata = transfer_to("d8da0000000000000000000000000000000a6045");
+        let result = format_with_options(1, usdc, &calldata, None, &source, &tokens, &options)
+            .await
+            .unwrap();
+        let DisplayEntry::Item(to) = &result.entries[0] else {
+            panic!("expected item");
+        };
+        assert_eq!(to.label, "To");
+        assert_eq!(to.warnings.len(), 1);
+        assert_eq!(to.warnings[0].severity, WarningSeverity::Danger);
+        assert_eq!(
+            to.warnings[0].kind,
+            FieldWarningKind::LookAlikeAddress {
+                known_address: "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045".to_string(),
+                known_label: "vitalik.eth".to_string(),
+            }
+        );
+        assert!(result.warnings.contains(&to.warnings[0].message));
+
+        // The known address itself is not flagged
+        let calldata = transfer_to("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let result = format_with_options(1, usdc, &calldata, None, &source, &tokens, &options)
+            .await
+            .unwrap();
+        let DisplayEntry::Item(to) = &result.entries[0] else {
+            panic!("expected item");
+        };
+        assert!(to.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lookalike_detection_covers_nested_and_typed_addresses() {
+        let lookalike = "0xd8da0000000000000000000000000000000a6045";
+        let mut known = address_book::AddressBook::empty();
+        known.insert(
+            "0xd8da6bf26964af9d7eed9e03e53415d37aa96045".to_string(),
+            "vitalik.eth".to_string(),
+        );
+        let options = FormatOptions {
+            known_addresses: Some(&known),
+            ..FormatOptions::default()
+        };
+        let flagged = |model: &DisplayModel| -> Vec<String> {
+            model
+                .entries
+                .iter()
+                .flat_map(|entry| match entry {
+                    DisplayEntry::Item(item) => vec![item.clone()],
+                    DisplayEntry::Group { items, .. } => items.clone(),
+                })
+                .filter(|item| {
+                    item.warnings
+                        .iter()
+                        .any(|w| matches!(w.kind, FieldWarningKind::LookAlikeAddress { .. }))
+                })
+                .map(|item| item.label)
+                .collect()
+        };
+
+        // Addresses inside arrays
+        let contract = "0x00000000000000000000000000000000000000d1";
+        let mut source = resolver::StaticSource::new();
+        source
+            .add_calldata_json(
+                1,
+                contract,
+                &format!(
+                    r#"{{
+                "context": {{"contract": {{"deployments": [{{"chainId": 1, "address": "{contract}"}}]}}}},
+                "metadata": {{"owner": "Acme"}},
+                "display": {{"formats": {{"multisend(address[])": {{"intent": "Send", "fields": [
+                    {{"path": "@.0", "label": "Recipients"}}
+                ]}}}}}}
+            }}"#
+                ),
+            )
+            .unwrap();
+        let sig = decoder::parse_signature("multisend(address[])").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        for word in [
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "00000000000000000000000000000000000000000000000000000000000000e1",
+            "000000000000000000000000d8da0000000000000000000000000000000a6045",
+        ] {
+            calldata.extend_from_slice(&hex::decode(word).unwrap());
+        }
+        let result = format_with_options(
+            1,
+            contract,
+            &calldata,
+            None,
+            &source,
+            &EmptyTokenSource,
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(flagged(&result), vec!["Recipients"]);
+
+        // A longer comparison no longer matches this vanity address
+        let strict = FormatOptions {
+            lookalike_chars: Some(5),
+            ..options
+        };
+        let result = format_with_options(
+            1,
+            contract,
+            &calldata,
+            None,
+            &source,
+            &EmptyTokenSource,
+            &strict,
+        )
+        .await
+        .unwrap();
+        assert!(flagged(&result).is_empty());
+
+        // EIP-712 message fields
+        let verifier = "0x00000000000000000000000000000000000000e2";
+        source
+            .add_typed_json(
+                1,
+                verifier,
+                &format!(
+                    r#"{{
+                "context": {{"eip712": {{"deployments": [{{"chainId": 1, "address": "{verifier}"}}]}}}},
+                "metadata": {{"owner": "Acme"}},
+                "display": {{"formats": {{"Order": {{"fields": [
+                    {{"path": "recipient", "label": "Recipient", "format": "addressName"}}
+                ]}}}}}}
+            }}"#
+                ),
+            )
+            .unwrap();
+        let data: eip712::TypedData = serde_json::from_value(serde_json::json!({
+            "types": {"Order": [{"name": "recipient", "type": "address"}]},
+            "primaryType": "Order",
+            "domain": {"chainId": 1, "verifyingContract": verifier},
+            "message": {"recipient": lookalike}
+        }))
+        .unwrap();
+        let result = format_typed_with_options(&data, &source, &EmptyTokenSource, &options)
+            .await
+            .unwrap();
+        assert_eq!(flagged(&result), vec!["Recipient"]);
+        assert_eq!(result.warnings.len(), 1);
+
+        // Permit2 spenders
+        let data: eip712::TypedData = serde_json::from_value(serde_json::json!({
+            "types": {
+                "PermitTransferFrom": [
+                    {"name": "permitted", "type": "TokenPermissions"},
+                    {"name": "spender", "type": "address"},
+                    {"name": "nonce", "type": "uint256"},
+                    {"name": "deadline", "type": "uint256"}
+                ],
+                "TokenPermissions": [
+                    {"name": "token", "type": "address"},
+                    {"name": "amount", "type": "uint256"}
+                ]
+            },
+            "primaryType": "PermitTransferFrom",
+            "domain": {"name": "Permit2", "chainId": 1, "verifyingContract": permit2::PERMIT2_ADDRESS},
+            "message": {
+                "permitted": {"token": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", "amount": "1"},
+                "spender": lookalike,
+                "nonce": "1",
+                "deadline": "1718000000"
+            }
+        }))
+        .unwrap();
+        let result = format_typed_with_options(&data, &source, &EmptyTokenSource, &options)
+            .await
+            .unwrap();
+        assert_eq!(flagged(&result), vec!["Spender"]);
+    }
+
+    #[tokio::test]
+    async fn test_permit2_witness_uses_spender_descriptor() {
+        let reactor = "0x6000da47483062a0d734ba3dc7576ce6a0b645c4";
+        let usdc = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        let typed_json = format!(
+            r#"{{
+            "types": {{
+                "PermitWitnessTransferFrom": [
+                    {{"name": "permitted", "type": "TokenPermissions"}},
+                    {{"name": "spender", "type": "address"}},
+                    {{"name": "nonce", "type": "uint256"}},
+                    {{"name": "deadline", "type": "uint256"}},
+                    {{"name": "witness", "type": "LimitOrder"}}
+                ],
+                "TokenPermissions": [
+                    {{"name": "token", "type": "address"}},
+                    {{"name": "amount", "type": "uint256"}}
+                ],
+                "LimitOrder": [
+                    {{"name": "recipient", "type": "address"}},
+                    {{"name": "minAmountOut", "type": "uint256"}}
+                ]
+            }},
+            "primaryType": "PermitWitnessTransferFrom",
+            "domain": {{"name": "Permit2", "chainId": 1, "verifyingContract": "{}"}},
+            "message": {{
+                "permitted": {{"token": "{usdc}", "amount": "5000000"}},
+                "spender": "{reactor}",
+                "nonce": "1",
+                "deadline": "1718000000",
+                "witness": {{
+                    "recipient": "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+                    "minAmountOut": "1000"
+                }}
+            }}
+        }}"#,
+            permit2::PERMIT2_ADDRESS
+        );
+        let data: eip712::TypedData = serde_json::from_str(&typed_json).unwrap();
+
+        // Without a spender descriptor the witness falls back to raw fields
+        let source = resolver::StaticSource::new();
+        let result = format_typed(&data, &source, &WellKnownTokenSource::new())
+            .await
+            .unwrap();
+        assert_eq!(result.intent, "Authorize transfer");
+        assert!(matches!(
+            result.entries.last(),
+            Some(DisplayEntry::Group { label, items, .. })
+                if label == "LimitOrder" && items[1].value == "1000"
+        ));
+        assert_eq!(
+            result.warnings,
+            vec![
+                "No descriptor format for witness type LimitOrder; showing raw fields",
+                "No registry descriptor found; formatted with the built-in Permit2 template",
+            ]
+        );
+
+        let mut source = resolver::StaticSource::new();
+        source
+            .add_typed_json(
+                1,
+                reactor,
+                &format!(
+                    r#"{{
+                "context": {{"eip712": {{"deployments": [{{"chainId": 1, "address": "{reactor}"}}]}}}},
+                "metadata": {{"owner": "Acme", "contractName": "Acme Reactor"}},
+                "display": {{"formats": {{"LimitOrder": {{
+                    "intent": "Limit order",
+                    "fields": [
+                        {{"path": "recipient", "label": "Recipient", "format": "addressName"}},
+                        {{"path": "minAmountOut", "label": "Minimum received", "format": "number"}}
+                    ]
+                }}}}}}
+            }}"#
+                ),
+            )
+            .unwrap();
+        let result = format_typed(&data, &source, &WellKnownTokenSource::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            result.interpolated_intent.as_deref(),
+            Some("Allow Acme Reactor to transfer 5 USDC")
+        );
+        let DisplayEntry::Item(spender) = &result.entries[0] else {
+            panic!("expected spender item");
+        };
+        assert_eq!(spender.name_source, Some(NameSource::Descriptor));
+        assert!(matches!(
+            result.entries.last(),
+            Some(DisplayEntry::Group { label, items, .. })
+                if label == "Limit order" && items[1].label == "Minimum received"
+        ));
+        assert!(!result
+            .risks
+            .iter()
+            .any(|r| r.kind == RiskKind::UnknownSpender));
+    }
+
+    #[tokio::test]
+    async fn test_registry_descriptor_outranks_token_template() {
+        let descriptor = Descriptor::from_json(test_descriptor_json()).unwrap();
+        let mut source = resolver::StaticSource::new();
+        source.add_calldata(1, "0xdac17f958d2ee523a2206206994597c13d831ec7", descriptor);
+
+        let sig = decoder::parse_signature("transfer(address,uint256)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        calldata.extend_from_slice(&[0u8; 64]);
+
+        let result = format(
+            1,
+            "0xdac17f958d2ee523a2206206994597c13d831ec7",
+            &calldata,
+            None,
+            &source,
+            &EmptyTokenSource,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.intent, "Transfer tokens");
+        assert!(result.warnings.is_empty());
+
+        // NFT-only selectors use the ERC-721 template
+        let sig = decoder::parse_signature("setApprovalForAll(address,bool)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        calldata.extend_from_slice(&[0u8; 32]);
+        let mut approved = [0u8; 32];
+        approved[31] = 1;
+        calldata.extend_from_slice(&approved);
+        let collection = "0x00000000000000000000000000000000000000c1";
+        let result = format(1, collection, &calldata, None, &source, &EmptyTokenSource)
+            .await
+            .unwrap();
+        assert_eq!(result.intent, "Set approval for all");
+        assert_eq!(
+            result.interpolated_intent.as_deref(),
+            Some(
+                "Grant 0x0000000000000000000000000000000000000000 access to all your NFTs in this collection"
+            )
+        );
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("ERC-721 template"));
+    }
+
+    /// Batch resolver answering from fixed tables and recording each batch it gets.
+    struct RecordingBatch {
+        results: prefetch::LookupResults,
+        batches: std::sync::Mutex<Vec<LookupRequests>>,
+    }
+
+    impl BatchLookup for RecordingBatch {
+        fn resolve_batch<'a>(
+            &'a self,
+            requests: &'a LookupRequests,
+        ) -> std::pin::Pin<
+            Box<
+                dyn std::future::Future<Output = Result<LookupResults, error::ResolveError>>
+                    + Send
+                    + 'a,
+            >,
+        > {
+            self.batches.lock().unwrap().push(requests.clone());
+            let results = self.results.clone();
+            Box::pin(async move { Ok(results) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_resolves_lookups_in_one_batch() {
+        let usdc = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        let vitalik = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+        let mut results = prefetch::LookupResults::default();
+        results.tokens.insert(
+            token::TokenLookupKey::new(1, usdc),
+            TokenMeta {
+                symbol: "USDC".to_string(),
+                decimals: 6,
+                name: "USD Coin".to_string(),
+                logo_uri: None,
+            },
+        );
+        results.address_names.insert(
+            prefetch::AddressLookup {
+                chain_id: 1,
+                address: vitalik.to_string(),
+            },
+            AddressName {
+                name: "vitalik.eth".to_string(),
+                source: NameSource::Ens,
+                kind: None,
+            },
+        );
+        let batch = RecordingBatch {
+            results,
+            batches: std::sync::Mutex::new(Vec::new()),
+        };
+
+        let sig = decoder::parse_signature("transfer(address,uint256)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        let mut recipient = [0u8; 32];
+        recipient[12..].copy_from_slice(&hex::decode(&vitalik[2..]).unwrap());
+        calldata.extend_from_slice(&recipient);
+        let mut amount = [0u8; 32];
+        amount[24..].copy_from_slice(&10_000_000u64.to_be_bytes());
+        calldata.extend_from_slice(&amount);
+
+        let options = FormatOptions {
+            lookups: Some(&batch),
+            ..FormatOptions::default()
+        };
+        let result = format_with_options(
+            1,
+            usdc,
+            &calldata,
+            None,
+            &resolver::StaticSource::new(),
+            &EmptyTokenSource,
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result.interpolated_intent.as_deref(),
+            Some("Send 10 USDC to vitalik.eth")
+        );
+        let recipient = result.entries.iter().find_map(|entry| match entry {
+            DisplayEntry::Item(item) if item.value == "vitalik.eth" => Some(item),
+            _ => None,
+        });
+        assert_eq!(recipient.unwrap().name_source, Some(NameSource::Ens));
+
+        {
+            let batches = batch.batches.lock().unwrap();
+            assert_eq!(batches.len(), 1);
+            assert_eq!(batches[0].tokens, vec![token::TokenLookupKey::new(1, usdc)]);
+            assert_eq!(batches[0].addresses.len(), 1);
+        }
+
+        // Tokens the synchronous source already knows are not requested again
+        let mut tokens = StaticTokenSource::new();
+        tokens.insert(
+            1,
+            usdc,
+            batch.results.tokens.values().next().unwrap().clone(),
+        );
+        format_with_options(
+            1,
+            usdc,
+            &calldata,
+            None,
+            &resolver::StaticSource::new(),
+            &tokens,
+            &options,
+        )
+        .await
+        .unwrap();
+        let batches = batch.batches.lock().unwrap();
+        assert!(batches[1].tokens.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_typed_prefetch_resolves_lookups_in_one_batch() {
+        let reactor = "0x6000da47483062a0d734ba3dc7576ce6a0b645c4";
+        let usdc = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        let vitalik = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+        let mut results = prefetch::LookupResults::default();
+        results.tokens.insert(
+            token::TokenLookupKey::new(1, usdc),
+            TokenMeta {
+                symbol: "USDC".to_string(),
+                decimals: 6,
+                name: "USD Coin".to_string(),
+                logo_uri: None,
+            },
+        );
+        results.address_names.insert(
+            prefetch::AddressLookup {
+                chain_id: 1,
+                address: vitalik.to_string(),
+            },
+            AddressName {
+                name: "vitalik.eth".to_string(),
+                source: NameSource::Ens,
+                kind: None,
+            },
+        );
+        let batch = RecordingBatch {
+            results,
+            batches: std::sync::Mutex::new(Vec::new()),
+        };
+        let options = FormatOptions {
+            lookups: Some(&batch),
+            ..FormatOptions::default()
+        };
+
+        let mut source = resolver::StaticSource::new();
+        source
+            .add_typed_json(
+                1,
+                reactor,
+                &format!(
+                    r#"{{
+                "context": {{"eip712": {{"deployments": [{{"chainId": 1, "address": "{reactor}"}}]}}}},
+                "metadata": {{"owner": "Acme", "contractName": "Acme Reactor"}},
+                "display": {{"formats": {{
+                    "LimitOrder": {{"fields": [
+                        {{"path": "recipient", "label": "Recipient", "format": "addressName"}}
+                    ]}},
+                    "Order": {{"intent": "Sell", "fields": [
+                        {{"path": "amount", "label": "Amount", "format": "tokenAmount", "params": {{"tokenPath": "token"}}}},
+                        {{"path": "recipient", "label": "Recipient", "format": "addressName"}}
+                    ]}}
+                }}}}
+            }}"#
+                ),
+            )
+            .unwrap();
+
+        // A descriptor's fields are resolved in one batch
+        let data: eip712::TypedData = serde_json::from_value(serde_json::json!({
+            "types": {"Order": [
+                {"name": "token", "type": "address"},
+                {"name": "amount", "type": "uint256"},
+                {"name": "recipient", "type": "address"}
+            ]},
+            "primaryType": "Order",
+            "domain": {"chainId": 1, "verifyingContract": reactor},
+            "message": {"token": usdc, "amount": "2500000", "recipient": vitalik}
+        }))
+        .unwrap();
+        let result = format_typed_with_options(&data, &source, &EmptyTokenSource, &options)
+            .await
+            .unwrap();
+        let values: Vec<_> = result
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                DisplayEntry::Item(item) => Some((item.value.as_str(), item.name_source)),
+                DisplayEntry::Group { .. } => None,
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![("2.5 USDC", None), ("vitalik.eth", Some(NameSource::Ens))]
+        );
+        {
+            let batches = batch.batches.lock().unwrap();
+            assert_eq!(batches.len(), 1);
+            assert_eq!(batches[0].tokens, vec![token::TokenLookupKey::new(1, usdc)]);
+            assert_eq!(batches[0].addresses.len(), 1);
+        }
+
+        // So are the Permit2 tokens, spender and witness fields
+        let data: eip712::TypedData = serde_json::from_value(serde_json::json!({
+            "types": {
+                "PermitWitnessTransferFrom": [
+                    {"name": "permitted", "type": "TokenPermissions"},
+                    {"name": "spender", "type": "address"},
+                    {"name": "nonce", "type": "uint256"},
+                    {"name": "deadline", "type": "uint256"},
+                    {"name": "witness", "type": "LimitOrder"}
+                ],
+                "TokenPermissions": [
+                    {"name": "token", "type": "address"},
+                    {"name": "amount", "type": "uint256"}
+                ],
+                "LimitOrder": [{"name": "recipient", "type": "address"}]
+            },
+            "primaryType": "PermitWitnessTransferFrom",
+            "domain": {"name": "Permit2", "chainId": 1, "verifyingContract": permit2::PERMIT2_ADDRESS},
+            "message": {
+                "permitted": {"token": usdc, "amount": "5000000"},
+                "spender": reactor,
+                "nonce": "1",
+                "deadline": "1718000000",
+                "witness": {"recipient": vitalik}
+            }
+        }))
+        .unwrap();
+        let result = format_typed_with_options(&data, &source, &EmptyTokenSource, &options)
+            .await
+            .unwrap();
+        assert_eq!(
+            result.interpolated_intent.as_deref(),
+            Some("Allow Acme Reactor to transfer 5 USDC")
+        );
+        assert!(matches!(
+            result.entries.last(),
+            Some(DisplayEntry::Group { items, .. }) if items[0].value == "vitalik.eth"
+        ));
+        let batches = batch.batches.lock().unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[1].tokens, vec![token::TokenLookupKey::new(1, usdc)]);
+        // The spender is named by its descriptor; only the witness recipient is looked up
+        assert_eq!(
+            batches[1].addresses,
+            vec![prefetch::AddressLookup {
+                chain_id: 1,
+                address: vitalik.to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spender_risks_use_code_presence_and_known_addresses() {
+        let usdc = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        let eoa = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+        let router = "0x000000000022d473030f116ddee9f6b43ac78ba3";
+        let mut transport = rpc::StaticEthCallTransport::new();
+        transport.insert_code(1, router, vec![0x60, 0x80]);
+        let batch = CodeBatchLookup::new(Box::new(transport));
+        let mut known = address_book::AddressBook::empty();
+        known.insert(router.to_string(), "Router".to_string());
+        let options = FormatOptions {
+            lookups: Some(&batch),
+            known_addresses: Some(&known),
+            ..FormatOptions::default()
+        };
+        let source = resolver::StaticSource::new();
+        let risks = |model: &DisplayModel| model.risks.iter().map(|r| r.kind).collect::<Vec<_>>();
+
+        // An approval to an address without code is an approval to an EOA
+        let sig = decoder::parse_signature("approve(address,uint256)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        let mut spender = [0u8; 32];
+        spender[12..].copy_from_slice(&hex::decode(&eoa[2..]).unwrap());
+        calldata.extend_from_slice(&spender);
+        calldata.extend_from_slice(&[0xff; 32]);
+        let model = format_with_options(
+            1,
+            usdc,
+            &calldata,
+            None,
+            &source,
+            &WellKnownTokenSource::new(),
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            risks(&model),
+            vec![RiskKind::UnlimitedAllowance, RiskKind::SpenderIsEoa]
+        );
+
+        // A permit spender in the caller's known addresses is known, and the
+        // allowance is large even though the token's decimals are unknown
+        let permit = |spender: &str| -> eip712::TypedData {
+            serde_json::from_value(serde_json::json!({
+                "types": {},
+                "primaryType": "Permit",
+                "domain": {"chainId": 1, "verifyingContract": "0x00000000000000000000000000000000000000c1"},
+                "message": {"owner": eoa, "spender": spender, "value": "5000000000000000000000000000", "nonce": 0, "deadline": "0"}
+            }))
+            .unwrap()
+        };
+        let model =
+            format_typed_with_options(&permit(router), &source, &EmptyTokenSource, &options)
+                .await
+                .unwrap();
+        assert_eq!(risks(&model), vec![RiskKind::LargeAllowance]);
+        let model = format_typed_with_options(&permit(eoa), &source, &EmptyTokenSource, &options)
+            .await
+            .unwrap();
+        assert_eq!(
+            risks(&model),
+            vec![RiskKind::LargeAllowance, RiskKind::SpenderIsEoa]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signature_fallback_warns_on_collision() {
+        let source = resolver::StaticSource::new();
+        let mut calldata = vec![0x42, 0x96, 0x6c, 0x68];
+        calldata.extend_from_slice(&[0u8; 32]);
+
+        // The embedded table knows a single signature for this selector
+        let result = format(1, "0xabc", &calldata, None, &source, &EmptyTokenSource)
+            .await
+            .unwrap();
+        assert_eq!(result.intent, "burn(uint256)");
+        assert_eq!(
+            result.warnings,
+            vec!["No matching descriptor format found".to_string()]
+        );
+
+        let mut signatures = signatures::StaticSignatureSource::new();
+        signatures.insert("burn(uint256)");
+        signatures.insert("collate_propagate_storage(bytes16)");
+        let options = FormatOptions {
+            signatures: Some(&signatures),
+            ..FormatOptions::default()
+        };
+        let result = format_with_options(
+            1,
+            "0xabc",
+            &calldata,
+            None,
+            &source,
+            &EmptyTokenSource,
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.intent, "burn(uint256)");
+        assert_eq!(result.warnings[0], "No matching descriptor format found");
+        assert!(result.warnings[1].contains("collate_propagate_storage(bytes16)"));
+
+        // A caller-supplied source takes over from the embedded table
+        let mut signatures = signatures::StaticSignatureSource::new();
+        signatures.insert("collate_propagate_storage(bytes16)");
+        let options = FormatOptions {
+            signatures: Some(&signatures),
+            ..FormatOptions::default()
+        };
+        let result = format_with_options(
+            1,
+            "0xabc",
+            &calldata,
+            None,
+            &source,
+            &EmptyTokenSource,
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.intent, "collate_propagate_storage(bytes16)");
+        assert_eq!(result.warnings.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_native_value_uses_chain_source() {
+        let descriptor = Descriptor::from_json(
+            r#"{
+                "context": {"contract": {"deployments": []}},
+                "metadata": {"owner": "test"},
+                "display": {"formats": {
+                    "deposit()": {
+                        "intent": "Deposit",
+                        "fields": [{"path": "@.value", "label": "Amount", "format": "amount"}]
+                    }
+                }}
+            }"#,
+        )
+        .unwrap();
+        let to = "0x00000000000000000000000000000000000000aa";
+        let mut source = resolver::StaticSource::new();
+        for chain_id in [100, 99999] {
+            source.add_calldata(chain_id, to, descriptor.clone());
+        }
+        let calldata = decoder::selector_from_signature("deposit()").to_vec();
+        let value = 1_500_000_000_000_000_000u64.to_be_bytes();
+
+        let result = format(100, to, &calldata, Some(&value), &source, &EmptyTokenSource)
+            .await
+            .unwrap();
+        assert_eq!(result.entries.len(), 1);
+        match &result.entries[0] {
+            DisplayEntry::Item(item) => assert_eq!(item.value, "1.5 XDAI"),
+            other => panic!("expected item, got {other:?}"),
+        }
+
+        // Unknown chains show the raw amount instead of assuming ETH
+        let result = format(
+            99999,
+            to,
+            &calldata,
+            Some(&value),
+            &source,
+            &EmptyTokenSource,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result.warnings,
+            vec!["native currency unknown for chain 99999"]
+        );
+
+        let mut chains = chain::StaticChainSource::new();
+        chains.insert(chain::ChainInfo {
+            chain_id: 99999,
+            name: "My L3".to_string(),
+            native_currency: chain::NativeCurrency {
+                name: "Gas".to_string(),
+                symbol: "GAS".to_string(),
+                decimals: 6,
+            },
+            explorer: None,
+        });
+        let options = FormatOptions {
+            chains: Some(&chains),
+            ..FormatOptions::default()
+        };
+        let result = format_with_options(
+            99999,
+            to,
+            &calldata,
+            Some(&value),
+            &source,
+            &EmptyTokenSource,
+            &options,
+        )
+        .await
+        .unwrap();
+        match &result.entries[0] {
+            DisplayEntry::Item(item) => assert_eq!(item.value, "1500000000000 GAS"),
+            other => panic!("expected item, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_typed_data_uses_caller_chains() {
+        let bridge = "0x00000000000000000000000000000000000000b2";
+        let mut chains = chain::StaticChainSource::new();
+        chains.insert(chain::ChainInfo {
+            chain_id: 99999,
+            name: "My L3".to_string(),
+            native_currency: chain::NativeCurrency {
+                name: "Gas".to_string(),
+                symbol: "GAS".to_string(),
+                decimals: 18,
+            },
+            explorer: None,
+        });
+        let options = FormatOptions {
+            chains: Some(&chains),
+            ..FormatOptions::default()
+        };
+        let descriptor = format!(
+            r#"{{
+            "context": {{"eip712": {{"deployments": [{{"chainId": 1, "address": "{bridge}"}}]}}}},
+            "metadata": {{"owner": "Acme"}},
+            "display": {{"formats": {{"Bridge": {{"fields": [
+                {{"path": "destination", "label": "To chain", "format": "chainId"}}
+            ]}}}}}}
+        }}"#
+        );
+        let mut source = resolver::StaticSource::new();
+        source.add_typed_json(1, bridge, &descriptor).unwrap();
+
+        let data: eip712::TypedData = serde_json::from_value(serde_json::json!({
+            "types": {"Bridge": [{"name": "destination", "type": "uint256"}]},
+            "primaryType": "Bridge",
+            "domain": {"chainId": 1, "verifyingContract": bridge},
+            "message": {"destination": 99999}
+        }))
+        .unwrap();
+        let result = format_typed_with_options(&data, &source, &EmptyTokenSource, &options)
+            .await
+            .unwrap();
+        match &result.entries[0] {
+            DisplayEntry::Item(item) => assert_eq!(item.value, "My L3"),
+            other => panic!("expected item, got {other:?}"),
+        }
+
+        // Permit2 witnesses are rendered with the same chains
+        let data: eip712::TypedData = serde_json::from_value(serde_json::json!({
+            "types": {
+                "PermitWitnessTransferFrom": [
+                    {"name": "permitted", "type": "TokenPermissions"},
+                    {"name": "spender", "type": "address"},
+                    {"name": "nonce", "type": "uint256"},
+                    {"name": "deadline", "type": "uint256"},
+                    {"name": "witness", "type": "Bridge"}
+                ],
+                "TokenPermissions": [
+                    {"name": "token", "type": "address"},
+                    {"name": "amount", "type": "uint256"}
+                ],
+                "Bridge": [{"name": "destination", "type": "uint256"}]
+            },
+            "primaryType": "PermitWitnessTransferFrom",
+            "domain": {"name": "Permit2", "chainId": 1, "verifyingContract": permit2::PERMIT2_ADDRESS},
+            "message": {
+                "permitted": {"token": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", "amount": "1"},
+                "spender": bridge,
+                "nonce": "1",
+                "deadline": "1718000000",
+                "witness": {"destination": 99999}
+            }
+        }))
+        .unwrap();
+        let result = format_typed_with_options(&data, &source, &EmptyTokenSource, &options)
+            .await
+            .unwrap();
+        assert!(matches!(
+            result.entries.last(),
+            Some(DisplayEntry::Group { items, .. }) if items[0].value == "My L3"
+        ));
+    }
+
+    #[test]
+    fn test_stakeweight_increase_unlock_time() {
+        let json = r#"{
+            "context": {
+                "contract": {
+                    "deployments": [
+                        { "chainId": 10, "address": "0x521B4C065Bbdbe3E20B3727340730936912DfA46" }
+                    ]
+                }
+            },
+            "metadata": {
+                "owner": "WalletConnect",
+                "contractName": "StakeWeight",
+                "enums": {},
+                "constants": {},
+                "addressBook": {},
+                "maps": {}
+            },
+            "display": {
+                "definitions": {},
+                "formats": {
+                    "increaseUnlockTime(uint256)": {
+                        "intent": "Increase Unlock Time",
+                        "interpolatedIntent": "Increase unlock time to ${@.0}",
+                        "fields": [
+                            {
+                                "path": "@.0",
+                                "label": "New Unlock Time",
+                                "format": "date"
+                            }
+                        ]
+                    }
+                }
+            }
+        }"#;
+
+        let descriptor = Descriptor::from_json(json).unwrap();
+        // Real calldata from yttrium test
+        let calldata =
+            hex::decode("7c616fe6000000000000000000000000000000000000000000000000000000006945563d")
+                .unwrap();
+
+        let result = format_calldata(
+            &descriptor,
+            10,
+            "0x521B4C065Bbdbe3E20B3727340730936912DfA46",
+            &calldata,
+            None,
+            &EmptyTokenSource,
+        )
+        .unwrap();
+
+        assert_eq!(result.intent, "Increase Unlock Time");
+        assert_eq!(result.entries.len(), 1);
+        if let DisplayEntry::Item(ref item) = result.entries[0] {
+            assert_eq!(item.label, "New Unlock Time");
+            assert_eq!(item.value, "2025-12-19 13:42:21 UTC");
+        } else {
+            panic!("expected Item");
+        }
+        assert_eq!(
+            result.interpolated_intent.as_deref(),
+            Some("Increase unlock time to 2025-12-19 13:42:21 UTC")
+        );
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_eip712_format() {
+        let json = r#"{
+            "context": {
+                "eip712": {
+                    "deployments": [
+                        { "chainId": 1, "address": "0xabc" }
+                    ]
+                }
+            },
+            "metadata": {
+                "owner": "test",
                 "enums": {},
                 "constants": {},
                 "addressBook": {},
@@ -843,4 +2590,39 @@ mod tests {
             assert_eq!(item.value, "1000000");
         }
     }
+
+    #[test]
+    fn test_descriptor_validation() {
+        let descriptor = Descriptor::from_json(
+            r##"{
+                "context": {"contract": {"deployments": [
+                    {"chainId": 1, "address": "0xdac17f958d2ee523a2206206994597c13d831ec7"},
+                    {"chainId": 10, "address": "0x1234"}
+                ]}},
+                "metadata": {"owner": "test"},
+                "display": {
+                    "definitions": {"amount": {"path": "@.1", "label": "Amount"}},
+                    "formats": {
+                        "transfer(address,uint256)": {"fields": [
+                            {"$ref": "#/definitions/amount"},
+                            {"fieldGroup": {"label": "G", "fields": [{"$ref": "#/definitions/missing"}]}}
+                        ]},
+                        "transfer(address,": {"fields": []}
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+        let problems = descriptor.validate();
+        assert_eq!(problems.len(), 3, "{problems:?}");
+        assert_eq!(problems[0], "invalid deployment address 0x1234 on chain 10");
+        assert!(problems[1].starts_with("format transfer(address,:"));
+        assert_eq!(
+            problems[2],
+            "format transfer(address,uint256): unresolved $ref #/definitions/missing"
+        );
+
+        let aave = Descriptor::from_json(include_str!("../tests/fixtures/aave-lpv3.json")).unwrap();
+        assert_eq!(aave.validate(), Vec::<String>::new());
+    }
 }
@@ -0,0 +1,528 @@
+//! Built-in formatter for Uniswap Permit2 signatures: `PermitSingle`, `PermitBatch`,
+//! `PermitTransferFrom`, `PermitBatchTransferFrom` and their witness variants.
+//!
+//! Used when no registry descriptor covers the Permit2 contract. The spender's own
+//! descriptor, when one exists, names the spender and decodes the witness struct.
+//!
+//! Witnesses are looked up through the spender rather than by type name: witness type
+//! names are chosen by each protocol and not unique across them, while Permit2 only
+//! lets the spender consume the permit, so the spender is the contract that defines
+//! and checks the witness.
+
+use crate::address_book::AddressBook;
+use crate::address_name::NameSource;
+use crate::eip712::{self, TypedData, TypedRiskLookup};
+use crate::engine::{self, DisplayEntry, DisplayItem, DisplayModel, GroupIteration, RenderSources};
+use crate::error::Error;
+use crate::prefetch::{AddressLookup, LookupRequests};
+use crate::prelude::*;
+use crate::risk;
+use crate::token::TokenLookupKey;
+use crate::types::descriptor::Descriptor;
+use num_bigint::BigUint;
+
+/// Canonical Permit2 deployment, at the same address on every chain.
+pub const PERMIT2_ADDRESS: &str = "0x000000000022D473030F116dDEE9F6b43aC78BA3";
+
+/// Whether typed data is verified by the Permit2 contract.
+pub fn is_permit2(data: &TypedData) -> bool {
+    data.domain
+        .verifying_contract
+        .as_deref()
+        .is_some_and(|c| c.eq_ignore_ascii_case(PERMIT2_ADDRESS))
+}
+
+/// Struct type of the `witness` field, for witness transfers.
+pub fn witness_type(data: &TypedData) -> Option<&str> {
+    data.types
+        .get(&data.primary_type)?
+        .iter()
+        .find(|f| f.name == "witness")
+        .map(|f| f.field_type.as_str())
+}
+
+/// Permit kind of a primary type: `(allowance, batch)`, `None` for types Permit2
+/// does not define.
+fn permit_kind(primary_type: &str) -> Option<(bool, bool)> {
+    // Allowance permits carry `details` with an expiration; transfers carry `permitted`
+    match primary_type {
+        "PermitSingle" => Some((true, false)),
+        "PermitBatch" => Some((true, true)),
+        "PermitTransferFrom" | "PermitWitnessTransferFrom" => Some((false, false)),
+        "PermitBatchTransferFrom" | "PermitBatchWitnessTransferFrom" => Some((false, true)),
+        _ => None,
+    }
+}
+
+/// The `PermitDetails` / `TokenPermissions` structs of a permit.
+fn permits(message: &serde_json::Value, allowance: bool) -> Vec<&serde_json::Value> {
+    let list_key = if allowance { "details" } else { "permitted" };
+    match message.get(list_key) {
+        Some(serde_json::Value::Array(items)) => items.iter().collect(),
+        Some(item) => vec![item],
+        None => Vec::new(),
+    }
+}
+
+/// Collect the token and address-name lookups needed to render a Permit2
+/// signature, so they can be resolved in one batch beforehand.
+///
+/// Tokens `sources.tokens` already knows are left out.
+pub fn collect_permit2_lookups(
+    data: &TypedData,
+    spender: Option<&Descriptor>,
+    sources: &RenderSources<'_>,
+) -> LookupRequests {
+    let mut requests = LookupRequests::default();
+    let Some((allowance, _)) = permit_kind(&data.primary_type) else {
+        return requests;
+    };
+    let chain_id = data.domain.chain_id.unwrap_or(1);
+    let message = &data.message;
+
+    for permit in permits(message, allowance) {
+        if let Some(token) = permit.get("token") {
+            requests.add_token(TokenLookupKey::new(
+                chain_id,
+                &eip712::json_value_to_string(token),
+            ));
+        }
+    }
+    if let Some(spender_address) = message.get("spender").map(eip712::json_value_to_string) {
+        let labelled = spender.is_some_and(|d| {
+            AddressBook::from_descriptor(&d.context, &d.metadata)
+                .resolve(&spender_address)
+                .is_some()
+        });
+        if !labelled {
+            requests.add_address(chain_id, &spender_address);
+        }
+        requests.add_code(chain_id, &spender_address);
+    }
+    if let (Some(witness_type), Some(descriptor)) = (witness_type(data), spender) {
+        if let (Some(format), Some(witness)) = (
+            descriptor.display.formats.get(witness_type),
+            message.get("witness"),
+        ) {
+            eip712::collect_typed_struct(
+                descriptor,
+                &format.fields,
+                witness,
+                chain_id,
+                &mut requests,
+            );
+        }
+    }
+    requests
+        .tokens
+        .retain(|key| sources.tokens.lookup(key).is_none());
+    requests
+}
+
+/// Format a Permit2 signature. `spender` is the descriptor registered for the spender,
+/// if any; its format for the witness type renders the witness.
+///
+/// Returns `None` for primary types Permit2 does not define.
+pub fn format_permit2(
+    data: &TypedData,
+    spender: Option<&Descriptor>,
+    sources: &RenderSources<'_>,
+) -> Result<Option<DisplayModel>, Error> {
+    let Some((allowance, batch)) = permit_kind(&data.primary_type) else {
+        return Ok(None);
+    };
+    let chain_id = data.domain.chain_id.unwrap_or(1);
+    let message = &data.message;
+    let address_book = spender
+        .map(|d| AddressBook::from_descriptor(&d.context, &d.metadata))
+        .unwrap_or_else(AddressBook::empty);
+    let mut warnings = Vec::new();
+
+    let spender_address = message
+        .get("spender")
+        .map(eip712::json_value_to_string)
+        .unwrap_or_else(|| "<missing>".to_string());
+    let spender_name = match address_book.resolve(&spender_address) {
+        Some(label) => Some((label.to_string(), NameSource::Descriptor)),
+        None => sources
+            .lookups
+            .address_names
+            .get(&AddressLookup {
+                chain_id,
+                address: spender_address.to_lowercase(),
+            })
+            .map(|name| (name.name.clone(), name.source)),
+    };
+    let spender_display = spender_name
+        .as_ref()
+        .map_or(spender_address.as_str(), |(name, _)| name.as_str())
+        .to_string();
+    let mut spender_warnings = Vec::new();
+    if let Some(spender) = message.get("spender") {
+        eip712::check_typed_lookalikes(spender, "Spender", sources, &mut spender_warnings);
+    }
+    warnings.extend(spender_warnings.iter().map(|w| w.message.clone()));
+    let mut entries = vec![DisplayEntry::Item(DisplayItem {
+        label: "Spender".to_string(),
+        value: spender_display.clone(),
+        name_source: spender_name.map(|(_, source)| source),
+        warnings: spender_warnings,
+    })];
+
+    let permits = permits(message, allowance);
+    let mut amounts = Vec::new();
+    for (i, permit) in permits.iter().enumerate() {
+        let (token, amount) = format_token_amount(permit, chain_id, sources);
+        amounts.push(amount.clone());
+        let mut items = vec![item("Token", token), item("Amount", amount)];
+        if allowance {
+            items.push(item(
+                "Expiration",
+                format_allowance_expiry(permit.get("expiration")),
+            ));
+        }
+        if batch {
+            entries.push(DisplayEntry::Group {
+                label: format!("Token {}", i + 1),
+                iteration: GroupIteration::Bundled,
+                items,
+            });
+        } else {
+            entries.extend(items.into_iter().map(DisplayEntry::Item));
+        }
+    }
+
+    let deadline_key = if allowance { "sigDeadline" } else { "deadline" };
+    entries.push(DisplayEntry::Item(item(
+        "Signature deadline",
+        format_expiry(message.get(deadline_key)),
+    )));
+
+    if let Some(witness_type) = witness_type(data) {
+        let witness = message
+            .get("witness")
+            .cloned()
+            .unwrap_or(serde_json::Value::Null);
+        let format = spender.and_then(|d| Some((d, d.display.formats.get(witness_type)?)));
+        let rendered = match format {
+            Some((descriptor, format)) => Some((
+                format.intent.clone(),
+                eip712::render_typed_struct(
+                    descriptor,
+                    &format.fields,
+                    &witness,
+                    chain_id,
+                    sources,
+                    &mut warnings,
+                )?,
+            )),
+            None => None,
+        };
+        let (label, items) = match rendered {
+            Some((intent, sub)) => (intent.unwrap_or_else(|| witness_type.to_string()), sub),
+            None => {
+                warnings.push(format!(
+                    "No descriptor format for witness type {witness_type}; showing raw fields"
+                ));
+                (
+                    witness_type.to_string(),
+                    raw_struct(data, witness_type, &witness),
+                )
+            }
+        };
+        let items: Vec<DisplayItem> = items
+            .into_iter()
+            .flat_map(|e| match e {
+                DisplayEntry::Item(i) => vec![i],
+                DisplayEntry::Group { items, .. } => items,
+            })
+            .collect();
+        if !items.is_empty() {
+            entries.push(DisplayEntry::Group {
+                label,
+                iteration: GroupIteration::Sequential,
+                items,
+            });
+        }
+    }
+
+    let (intent, interpolated_intent) = match (allowance, amounts.as_slice()) {
+        (true, [amount]) => (
+            "Approve",
+            format!("Allow {spender_display} to spend {amount}"),
+        ),
+        (true, _) => (
+            "Approve",
+            format!("Allow {spender_display} to spend {} tokens", amounts.len()),
+        ),
+        (false, [amount]) => (
+            "Authorize transfer",
+            format!("Allow {spender_display} to transfer {amount}"),
+        ),
+        (false, _) => (
+            "Authorize transfer",
+            format!(
+                "Allow {spender_display} to transfer {} tokens",
+                amounts.len()
+            ),
+        ),
+    };
+
+    Ok(Some(DisplayModel {
+        intent: intent.to_string(),
+        interpolated_intent: Some(interpolated_intent),
+        entries,
+        warnings,
+        risks: risk::analyze_typed_data(
+            data,
+            &TypedRiskLookup {
+                sources,
+                address_book: &address_book,
+            },
+        ),
+    }))
+}
+
+fn item(label: &str, value: String) -> DisplayItem {
+    DisplayItem {
+        label: label.to_string(),
+        value,
+        name_source: None,
+        warnings: Vec::new(),
+    }
+}
+
+/// Token display (symbol, or address when unknown) and amount of a
+/// `PermitDetails` / `TokenPermissions` struct.
+fn format_token_amount(
+    permit: &serde_json::Value,
+    chain_id: u64,
+    sources: &RenderSources<'_>,
+) -> (String, String) {
+    let token = permit
+        .get("token")
+        .map(eip712::json_value_to_string)
+        .unwrap_or_else(|| "<missing>".to_string());
+    let meta = sources.lookup_token(&TokenLookupKey::new(chain_id, &token));
+    let Some(amount) = permit.get("amount").and_then(risk::json_uint) else {
+        return (token, "<missing>".to_string());
+    };
+
+    let value = if risk::is_unlimited(&amount) {
+        "Unlimited".to_string()
+    } else if let Some(ref meta) = meta {
+        crate::engine::format_with_decimals(&amount, meta.decimals)
+    } else {
+        amount.to_string()
+    };
+    match meta {
+        Some(meta) => (meta.symbol.clone(), format!("{value} {}", meta.symbol)),
+        None => (token, value),
+    }
+}
+
+/// Expiration or deadline timestamp; values past the representable range
+/// (such as `type(uint48).max`) never expire.
+fn format_expiry(value: Option<&serde_json::Value>) -> String {
+    let Some(ts) = value.and_then(risk::json_uint) else {
+        return "<missing>".to_string();
+    };
+    i64::try_from(ts)
+        .ok()
+        .and_then(engine::format_timestamp)
+        .unwrap_or_else(|| "Never".to_string())
+}
+
+/// An allowance's `expiration`. Permit2 reads 0 as the time of the transaction
+/// using the permit, so the allowance only lasts for that block.
+fn format_allowance_expiry(value: Option<&serde_json::Value>) -> String {
+    match value.and_then(risk::json_uint) {
+        Some(ts) if ts == BigUint::from(0u8) => "Current block only".to_string(),
+        _ => format_expiry(value),
+    }
+}
+
+/// Raw preview of a struct using its type definition for field order.
+fn raw_struct(data: &TypedData, type_name: &str, value: &serde_json::Value) -> Vec<DisplayEntry> {
+    data.types
+        .get(type_name)
+        .map(|fields| {
+            fields
+                .iter()
+                .map(|field| {
+                    let value = value
+                        .get(&field.name)
+                        .map(eip712::json_value_to_string)
+                        .unwrap_or_else(|| "<missing>".to_string());
+                    DisplayEntry::Item(item(&field.name, value))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::WellKnownChainSource;
+    use crate::prefetch::LookupResults;
+    use crate::risk::RiskKind;
+    use crate::token::WellKnownTokenSource;
+
+    const USDC: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+    const ROUTER: &str = "0x3fc91a3afd70395cd496c647d5a6cc9d4b2b7fad";
+
+    fn typed(
+        primary_type: &str,
+        extra_types: serde_json::Value,
+        message: serde_json::Value,
+    ) -> TypedData {
+        let mut types = serde_json::json!({
+            "PermitDetails": [
+                {"name": "token", "type": "address"},
+                {"name": "amount", "type": "uint160"},
+                {"name": "expiration", "type": "uint48"},
+                {"name": "nonce", "type": "uint48"}
+            ],
+            "TokenPermissions": [
+                {"name": "token", "type": "address"},
+                {"name": "amount", "type": "uint256"}
+            ]
+        });
+        types
+            .as_object_mut()
+            .unwrap()
+            .extend(extra_types.as_object().unwrap().clone());
+        serde_json::from_value(serde_json::json!({
+            "types": types,
+            "primaryType": primary_type,
+            "domain": {"name": "Permit2", "chainId": 1, "verifyingContract": PERMIT2_ADDRESS},
+            "message": message
+        }))
+        .unwrap()
+    }
+
+    fn format(data: &TypedData) -> Option<DisplayModel> {
+        let sources = RenderSources {
+            tokens: &WellKnownTokenSource::new(),
+            chains: WellKnownChainSource::shared(),
+            lookups: &LookupResults::default(),
+            known_addresses: None,
+            lookalike_chars: engine::LOOKALIKE_CHARS,
+        };
+        format_permit2(data, None, &sources).unwrap()
+    }
+
+    fn items(model: &DisplayModel) -> Vec<(String, String)> {
+        model
+            .entries
+            .iter()
+            .flat_map(|e| match e {
+                DisplayEntry::Item(i) => vec![i.clone()],
+                DisplayEntry::Group { items, .. } => items.clone(),
+            })
+            .map(|i| (i.label, i.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_permit_single_unlimited_never_expiring() {
+        let data = typed(
+            "PermitSingle",
+            serde_json::json!({"PermitSingle": [
+                {"name": "details", "type": "PermitDetails"},
+                {"name": "spender", "type": "address"},
+                {"name": "sigDeadline", "type": "uint256"}
+            ]}),
+            serde_json::json!({
+                "details": {
+                    "token": USDC,
+                    "amount": "1461501637330902918203684832716283019655932542975",
+                    "expiration": "281474976710655",
+                    "nonce": "0"
+                },
+                "spender": ROUTER,
+                "sigDeadline": "1718000000"
+            }),
+        );
+        assert!(is_permit2(&data));
+
+        let model = format(&data).unwrap();
+        assert_eq!(model.intent, "Approve");
+        assert_eq!(
+            model.interpolated_intent.as_deref(),
+            Some(format!("Allow {ROUTER} to spend Unlimited USDC").as_str())
+        );
+        assert_eq!(
+            items(&model),
+            vec![
+                ("Spender".to_string(), ROUTER.to_string()),
+                ("Token".to_string(), "USDC".to_string()),
+                ("Amount".to_string(), "Unlimited USDC".to_string()),
+                ("Expiration".to_string(), "Never".to_string()),
+                (
+                    "Signature deadline".to_string(),
+                    "2024-06-10 06:13:20 UTC".to_string()
+                ),
+            ]
+        );
+        let kinds: Vec<RiskKind> = model.risks.iter().map(|r| r.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                RiskKind::UnlimitedAllowance,
+                RiskKind::UnknownSpender,
+                RiskKind::LongLivedPermit
+            ]
+        );
+
+        // An expiration of 0 limits the allowance to the block using it
+        let mut data = data;
+        data.message["details"]["expiration"] = serde_json::json!("0");
+        let model = format(&data).unwrap();
+        assert_eq!(
+            items(&model)[3],
+            ("Expiration".to_string(), "Current block only".to_string())
+        );
+        let kinds: Vec<RiskKind> = model.risks.iter().map(|r| r.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![RiskKind::UnlimitedAllowance, RiskKind::UnknownSpender]
+        );
+    }
+
+    #[test]
+    fn test_batch_transfer_and_unknown_types() {
+        let data = typed(
+            "PermitBatchTransferFrom",
+            serde_json::json!({"PermitBatchTransferFrom": [
+                {"name": "permitted", "type": "TokenPermissions[]"},
+                {"name": "spender", "type": "address"},
+                {"name": "nonce", "type": "uint256"},
+                {"name": "deadline", "type": "uint256"}
+            ]}),
+            serde_json::json!({
+                "permitted": [
+                    {"token": USDC, "amount": "2500000"},
+                    {"token": "0x1111111111111111111111111111111111111111", "amount": "42"}
+                ],
+                "spender": ROUTER,
+                "nonce": "7",
+                "deadline": "1718000000"
+            }),
+        );
+        let model = format(&data).unwrap();
+        assert_eq!(model.intent, "Authorize transfer");
+        assert!(matches!(
+            &model.entries[1],
+            DisplayEntry::Group { label, items, .. } if label == "Token 1" && items[1].value == "2.5 USDC"
+        ));
+        assert!(matches!(
+            &model.entries[2],
+            DisplayEntry::Group { items, .. } if items[0].value == "0x1111111111111111111111111111111111111111" && items[1].value == "42"
+        ));
+
+        let other = typed("Order", serde_json::json!({}), serde_json::json!({}));
+        assert!(format(&other).is_none());
+    }
+}
@@ -0,0 +1,424 @@
+//! Two-phase rendering support: the lookups a display needs (tokens, address
+//! names, NFTs, code presence) are collected up front, resolved in one batch through a
+//! [`BatchLookup`], and rendering then reads the filled [`LookupResults`].
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::address_name::{AddressName, AddressNameSource};
+use crate::error::ResolveError;
+use crate::prelude::*;
+use crate::rpc::CodeReader;
+use crate::token::{AsyncTokenSource, TokenLookupKey, TokenMeta};
+
+/// An address whose display name is needed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AddressLookup {
+    pub chain_id: u64,
+    /// Lowercase `0x`-prefixed address.
+    pub address: String,
+}
+
+/// An NFT whose display name is needed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NftLookup {
+    pub chain_id: u64,
+    /// Lowercase `0x`-prefixed collection address.
+    pub collection: String,
+    /// Decimal token ID.
+    pub token_id: String,
+}
+
+/// Everything a display needs from external sources, deduplicated.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LookupRequests {
+    pub tokens: Vec<TokenLookupKey>,
+    pub addresses: Vec<AddressLookup>,
+    pub nfts: Vec<NftLookup>,
+    /// Addresses whose kind must be known; whether they have code tells an EOA
+    /// from a contract.
+    pub code: Vec<AddressLookup>,
+}
+
+impl LookupRequests {
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+            && self.addresses.is_empty()
+            && self.nfts.is_empty()
+            && self.code.is_empty()
+    }
+
+    pub fn add_token(&mut self, key: TokenLookupKey) {
+        if !self.tokens.contains(&key) {
+            self.tokens.push(key);
+        }
+    }
+
+    pub fn add_address(&mut self, chain_id: u64, address: &str) {
+        let lookup = AddressLookup {
+            chain_id,
+            address: address.to_lowercase(),
+        };
+        if !self.addresses.contains(&lookup) {
+            self.addresses.push(lookup);
+        }
+    }
+
+    pub fn add_code(&mut self, chain_id: u64, address: &str) {
+        let lookup = AddressLookup {
+            chain_id,
+            address: address.to_lowercase(),
+        };
+        if !self.code.contains(&lookup) {
+            self.code.push(lookup);
+        }
+    }
+
+    pub fn add_nft(&mut self, chain_id: u64, collection: &str, token_id: String) {
+        let lookup = NftLookup {
+            chain_id,
+            collection: collection.to_lowercase(),
+            token_id,
+        };
+        if !self.nfts.contains(&lookup) {
+            self.nfts.push(lookup);
+        }
+    }
+}
+
+/// Resolved lookups, read by the engine during rendering.
+#[derive(Debug, Clone, Default)]
+pub struct LookupResults {
+    pub tokens: HashMap<TokenLookupKey, TokenMeta>,
+    /// Display names (e.g. ENS names) by address, with their provenance.
+    pub address_names: HashMap<AddressLookup, AddressName>,
+    /// Display names by NFT, e.g. `"Bored Ape #42"`.
+    pub nft_names: HashMap<NftLookup, String>,
+    /// Whether an address has code: `false` for an EOA, `true` for a contract
+    /// (including smart-contract wallets).
+    pub has_code: HashMap<AddressLookup, bool>,
+}
+
+/// Trait for resolving a whole [`LookupRequests`] batch at once — typically a
+/// single round-trip to the host application or a backend.
+pub trait BatchLookup: Send + Sync {
+    fn resolve_batch<'a>(
+        &'a self,
+        requests: &'a LookupRequests,
+    ) -> Pin<Box<dyn Future<Output = Result<LookupResults, ResolveError>> + Send + 'a>>;
+}
+
+/// [`BatchLookup`] resolving the token requests concurrently through an
+/// [`AsyncTokenSource`]. Address and NFT requests are left unresolved.
+pub struct AsyncTokenBatchLookup {
+    tokens: Box<dyn AsyncTokenSource>,
+}
+
+impl AsyncTokenBatchLookup {
+    pub fn new(tokens: Box<dyn AsyncTokenSource>) -> Self {
+        Self { tokens }
+    }
+}
+
+impl BatchLookup for AsyncTokenBatchLookup {
+    fn resolve_batch<'a>(
+        &'a self,
+        requests: &'a LookupRequests,
+    ) -> Pin<Box<dyn Future<Output = Result<LookupResults, ResolveError>> + Send + 'a>> {
+        Box::pin(async move {
+            let lookups = requests
+                .tokens
+                .iter()
+                .map(|key| self.tokens.lookup_async(key))
+                .collect();
+            let mut results = LookupResults::default();
+            for (key, meta) in requests.tokens.iter().zip(join_all(lookups).await) {
+                if let Some(meta) = meta? {
+                    results.tokens.insert(key.clone(), meta);
+                }
+            }
+            Ok(results)
+        })
+    }
+}
+
+/// [`BatchLookup`] resolving the address requests concurrently through an
+/// [`AddressNameSource`]. Token and NFT requests are left unresolved.
+///
+/// An address whose lookup fails is left unnamed rather than failing the batch,
+/// so one flaky name service does not hide every other name in the call.
+pub struct AddressNameBatchLookup {
+    names: Box<dyn AddressNameSource>,
+}
+
+impl AddressNameBatchLookup {
+    pub fn new(names: Box<dyn AddressNameSource>) -> Self {
+        Self { names }
+    }
+}
+
+impl BatchLookup for AddressNameBatchLookup {
+    fn resolve_batch<'a>(
+        &'a self,
+        requests: &'a LookupRequests,
+    ) -> Pin<Box<dyn Future<Output = Result<LookupResults, ResolveError>> + Send + 'a>> {
+        Box::pin(async move {
+            let lookups = requests
+                .addresses
+                .iter()
+                .map(|lookup| self.names.resolve_name(lookup.chain_id, &lookup.address))
+                .collect();
+            let mut results = LookupResults::default();
+            for (lookup, name) in requests.addresses.iter().zip(join_all(lookups).await) {
+                if let Ok(Some(name)) = name {
+                    results.address_names.insert(lookup.clone(), name);
+                }
+            }
+            Ok(results)
+        })
+    }
+}
+
+/// [`BatchLookup`] reading the code of the code requests concurrently through a
+/// [`CodeReader`]. Other requests are left unresolved.
+pub struct CodeBatchLookup {
+    reader: Box<dyn CodeReader>,
+}
+
+impl CodeBatchLookup {
+    pub fn new(reader: Box<dyn CodeReader>) -> Self {
+        Self { reader }
+    }
+}
+
+impl BatchLookup for CodeBatchLookup {
+    fn resolve_batch<'a>(
+        &'a self,
+        requests: &'a LookupRequests,
+    ) -> Pin<Box<dyn Future<Output = Result<LookupResults, ResolveError>> + Send + 'a>> {
+        Box::pin(async move {
+            let reads = requests
+                .code
+                .iter()
+                .map(|lookup| self.reader.get_code(lookup.chain_id, &lookup.address))
+                .collect();
+            let mut results = LookupResults::default();
+            for (lookup, code) in requests.code.iter().zip(join_all(reads).await) {
+                results.has_code.insert(lookup.clone(), !code?.is_empty());
+            }
+            Ok(results)
+        })
+    }
+}
+
+/// [`BatchLookup`] running several batch lookups concurrently and merging their
+/// results; for a lookup answered by several, the earliest one wins.
+pub struct CompositeBatchLookup {
+    lookups: Vec<Box<dyn BatchLookup>>,
+}
+
+impl CompositeBatchLookup {
+    pub fn new(lookups: Vec<Box<dyn BatchLookup>>) -> Self {
+        Self { lookups }
+    }
+}
+
+impl BatchLookup for CompositeBatchLookup {
+    fn resolve_batch<'a>(
+        &'a self,
+        requests: &'a LookupRequests,
+    ) -> Pin<Box<dyn Future<Output = Result<LookupResults, ResolveError>> + Send + 'a>> {
+        Box::pin(async move {
+            let batches = self
+                .lookups
+                .iter()
+                .map(|lookup| lookup.resolve_batch(requests))
+                .collect();
+            let mut merged = LookupResults::default();
+            for results in join_all(batches).await {
+                let results = results?;
+                for (key, meta) in results.tokens {
+                    merged.tokens.entry(key).or_insert(meta);
+                }
+                for (key, name) in results.address_names {
+                    merged.address_names.entry(key).or_insert(name);
+                }
+                for (key, name) in results.nft_names {
+                    merged.nft_names.entry(key).or_insert(name);
+                }
+                for (key, has_code) in results.has_code {
+                    merged.has_code.entry(key).or_insert(has_code);
+                }
+            }
+            Ok(merged)
+        })
+    }
+}
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Poll all futures concurrently, returning their outputs in order.
+pub(crate) fn join_all<T>(futures: Vec<BoxFuture<'_, T>>) -> JoinAll<'_, T> {
+    let results = futures.iter().map(|_| None).collect();
+    JoinAll {
+        futures: futures.into_iter().map(Some).collect(),
+        results,
+    }
+}
+
+pub(crate) struct JoinAll<'a, T> {
+    futures: Vec<Option<BoxFuture<'a, T>>>,
+    results: Vec<Option<T>>,
+}
+
+impl<T> Future for JoinAll<'_, T> {
+    type Output = Vec<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Vec<T>> {
+        // Only boxed futures and plain values are stored, so `Self` is `Unpin`.
+        let this = self.get_mut();
+        for (slot, result) in this.futures.iter_mut().zip(this.results.iter_mut()) {
+            if let Some(future) = slot {
+                if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                    *result = Some(output);
+                    *slot = None;
+                }
+            }
+        }
+        if this.futures.iter().any(Option::is_some) {
+            return Poll::Pending;
+        }
+        Poll::Ready(this.results.iter_mut().filter_map(Option::take).collect())
+    }
+}
+
+impl<T> Unpin for JoinAll<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Token source that only answers once every lookup of the batch has been polled.
+    struct BarrierTokenSource {
+        expected: usize,
+        started: std::sync::atomic::AtomicUsize,
+    }
+
+    impl AsyncTokenSource for BarrierTokenSource {
+        fn lookup_async(
+            &self,
+            key: &TokenLookupKey,
+        ) -> Pin<Box<dyn Future<Output = Result<Option<TokenMeta>, ResolveError>> + Send + '_>>
+        {
+            let symbol = key.address().unwrap_or_default().to_string();
+            let mut counted = false;
+            Box::pin(std::future::poll_fn(move |cx| {
+                if !counted {
+                    counted = true;
+                    self.started
+                        .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                }
+                if self.started.load(std::sync::atomic::Ordering::SeqCst) < self.expected {
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                Poll::Ready(Ok(Some(TokenMeta {
+                    symbol: symbol.clone(),
+                    decimals: 18,
+                    name: symbol.clone(),
+                    logo_uri: None,
+                })))
+            }))
+        }
+    }
+
+    #[test]
+    fn test_requests_dedupe() {
+        let mut requests = LookupRequests::default();
+        requests.add_token(TokenLookupKey::new(1, "0xAA"));
+        requests.add_token(TokenLookupKey::new(1, "0xaa"));
+        requests.add_address(1, "0xBB");
+        requests.add_address(1, "0xbb");
+        requests.add_nft(1, "0xCC", "1".to_string());
+        requests.add_nft(1, "0xcc", "1".to_string());
+        assert_eq!(
+            (
+                requests.tokens.len(),
+                requests.addresses.len(),
+                requests.nfts.len()
+            ),
+            (1, 1, 1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_token_batch_resolves_concurrently() {
+        let source = BarrierTokenSource {
+            expected: 3,
+            started: std::sync::atomic::AtomicUsize::new(0),
+        };
+        let batch = AsyncTokenBatchLookup::new(Box::new(source));
+        let mut requests = LookupRequests::default();
+        for addr in ["0x01", "0x02", "0x03"] {
+            requests.add_token(TokenLookupKey::new(1, addr));
+        }
+
+        // Sequential resolution would never get past the first lookup.
+        let results = batch.resolve_batch(&requests).await.unwrap();
+        assert_eq!(results.tokens.len(), 3);
+        assert_eq!(
+            results.tokens[&TokenLookupKey::new(1, "0x02")].symbol,
+            "0x02"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_composite_batch_merges_tokens_and_names() {
+        let mut names = crate::address_name::ContactsAddressNameSource::new();
+        names.insert("0xBB", "Alice");
+        let batch = CompositeBatchLookup::new(vec![
+            Box::new(AsyncTokenBatchLookup::new(Box::new(BarrierTokenSource {
+                expected: 1,
+                started: std::sync::atomic::AtomicUsize::new(0),
+            }))),
+            Box::new(AddressNameBatchLookup::new(Box::new(names))),
+        ]);
+        let mut requests = LookupRequests::default();
+        requests.add_token(TokenLookupKey::new(1, "0xaa"));
+        requests.add_address(1, "0xbb");
+        requests.add_address(1, "0xcc");
+
+        let results = batch.resolve_batch(&requests).await.unwrap();
+        assert_eq!(results.tokens.len(), 1);
+        let alice = &results.address_names[&AddressLookup {
+            chain_id: 1,
+            address: "0xbb".to_string(),
+        }];
+        assert_eq!(alice.name, "Alice");
+        assert_eq!(alice.source, crate::address_name::NameSource::Contact);
+        assert_eq!(results.address_names.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_code_batch_tells_eoas_from_contracts() {
+        let mut transport = crate::rpc::StaticEthCallTransport::new();
+        transport.insert_code(1, "0xCC", vec![0x60, 0x80]);
+        let batch = CodeBatchLookup::new(Box::new(transport));
+        let mut requests = LookupRequests::default();
+        requests.add_code(1, "0xaa");
+        requests.add_code(1, "0xcc");
+        requests.add_token(TokenLookupKey::new(1, "0xcc"));
+
+        let results = batch.resolve_batch(&requests).await.unwrap();
+        let has_code = |address: &str| {
+            results.has_code[&AddressLookup {
+                chain_id: 1,
+                address: address.to_string(),
+            }]
+        };
+        assert!(!has_code("0xaa"));
+        assert!(has_code("0xcc"));
+        assert!(results.tokens.is_empty());
+    }
+}
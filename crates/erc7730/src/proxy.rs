@@ -0,0 +1,498 @@
+//! Proxy detection for descriptor resolution — maps EIP-1967, EIP-1822 and Beacon
+//! proxies to their implementation via a pluggable [`StorageReader`].
+
+use core::future::Future;
+use core::pin::Pin;
+
+use crate::error::ResolveError;
+use crate::prelude::*;
+use crate::resolver::{DescriptorSource, FactoryOracle, ResolvedDescriptor};
+
+/// EIP-1967 implementation slot: `keccak256("eip1967.proxy.implementation") - 1`.
+pub const EIP1967_IMPLEMENTATION_SLOT: [u8; 32] =
+    hex_literal("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// EIP-1967 beacon slot: `keccak256("eip1967.proxy.beacon") - 1`.
+pub const EIP1967_BEACON_SLOT: [u8; 32] =
+    hex_literal("a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50");
+
+/// EIP-1822 (UUPS) slot: `keccak256("PROXIABLE")`.
+pub const EIP1822_PROXIABLE_SLOT: [u8; 32] =
+    hex_literal("c5f16f0fcc639fa48a6947836d9850f504798523bf8c9a3a87d5876cf622bcf7");
+
+/// Selector of the beacon's `implementation()` getter.
+const BEACON_IMPLEMENTATION_SELECTOR: [u8; 4] = [0x5c, 0x60, 0xda, 0x1b];
+
+const fn hex_literal(s: &str) -> [u8; 32] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("invalid hex literal"),
+        }
+    }
+    let bytes = s.as_bytes();
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        out[i] = (nibble(bytes[2 * i]) << 4) | nibble(bytes[2 * i + 1]);
+        i += 1;
+    }
+    out
+}
+
+/// Proxy standard through which the implementation was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyKind {
+    Eip1967,
+    Eip1822,
+    Beacon,
+}
+
+impl ProxyKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProxyKind::Eip1967 => "EIP-1967",
+            ProxyKind::Eip1822 => "EIP-1822",
+            ProxyKind::Beacon => "Beacon",
+        }
+    }
+}
+
+/// The implementation behind a proxy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyTarget {
+    pub kind: ProxyKind,
+    /// Lowercase `0x`-prefixed implementation address.
+    pub implementation: String,
+}
+
+/// Trait for mapping a proxy address to its implementation.
+pub trait ProxyResolver: Send + Sync {
+    /// Return the implementation behind `address`, or `None` if it is not a known proxy.
+    fn resolve_proxy(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<ProxyTarget>, ResolveError>> + Send + '_>>;
+}
+
+/// Chain state access needed for proxy detection (typically backed by an RPC node).
+pub trait StorageReader: Send + Sync {
+    /// Read a storage slot (`eth_getStorageAt`).
+    fn storage_at(
+        &self,
+        chain_id: u64,
+        address: &str,
+        slot: [u8; 32],
+    ) -> Pin<Box<dyn Future<Output = Result<[u8; 32], ResolveError>> + Send + '_>>;
+
+    /// Execute a read-only call (`eth_call`). Used for Beacon proxies.
+    fn call(
+        &self,
+        chain_id: u64,
+        to: &str,
+        data: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>, ResolveError>> + Send + '_>>;
+}
+
+/// [`ProxyResolver`] that inspects the standard proxy storage slots.
+///
+/// Checks EIP-1967 implementation, EIP-1822 `PROXIABLE`, then the EIP-1967
+/// beacon slot followed by the beacon's `implementation()`.
+pub struct SlotProxyResolver {
+    reader: Box<dyn StorageReader>,
+}
+
+impl SlotProxyResolver {
+    pub fn new(reader: Box<dyn StorageReader>) -> Self {
+        Self { reader }
+    }
+
+    async fn detect(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Result<Option<ProxyTarget>, ResolveError> {
+        for (kind, slot) in [
+            (ProxyKind::Eip1967, EIP1967_IMPLEMENTATION_SLOT),
+            (ProxyKind::Eip1822, EIP1822_PROXIABLE_SLOT),
+        ] {
+            let word = self.reader.storage_at(chain_id, address, slot).await?;
+            if let Some(implementation) = word_to_address(&word)? {
+                return Ok(Some(ProxyTarget {
+                    kind,
+                    implementation,
+                }));
+            }
+        }
+
+        let word = self
+            .reader
+            .storage_at(chain_id, address, EIP1967_BEACON_SLOT)
+            .await?;
+        let Some(beacon) = word_to_address(&word)? else {
+            return Ok(None);
+        };
+        let output = self
+            .reader
+            .call(chain_id, &beacon, BEACON_IMPLEMENTATION_SELECTOR.to_vec())
+            .await?;
+        let implementation = match output.get(..32) {
+            Some(word) => word_to_address(word.try_into().expect("32-byte slice"))?,
+            None => None,
+        };
+        Ok(implementation.map(|implementation| ProxyTarget {
+            kind: ProxyKind::Beacon,
+            implementation,
+        }))
+    }
+}
+
+impl ProxyResolver for SlotProxyResolver {
+    fn resolve_proxy(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<ProxyTarget>, ResolveError>> + Send + '_>> {
+        let address = address.to_lowercase();
+        Box::pin(async move { self.detect(chain_id, &address).await })
+    }
+}
+
+/// Interpret a storage word as an address; `None` for an empty slot.
+///
+/// A word with non-zero high-order bytes is not an address and is rejected
+/// rather than truncated.
+fn word_to_address(word: &[u8; 32]) -> Result<Option<String>, ResolveError> {
+    if word.iter().all(|b| *b == 0) {
+        return Ok(None);
+    }
+    if word[..12].iter().any(|b| *b != 0) {
+        return Err(ResolveError::Proxy(format!(
+            "slot word 0x{} is not an address",
+            hex::encode(word)
+        )));
+    }
+    Ok(Some(format!("0x{}", hex::encode(&word[12..]))))
+}
+
+/// [`DescriptorSource`] that, when `inner` has no descriptor for an address, asks a
+/// [`ProxyResolver`] for the implementation behind it and resolves that instead.
+///
+/// Descriptors found this way keep the proxy as their `address` and carry the
+/// implementation in [`ResolvedDescriptor::proxy`]; [`format`](crate::format) and
+/// [`format_typed`](crate::format_typed) then add a "Via proxy" entry to the display.
+/// Proxy lookup failures are reported as [`ResolveError::Proxy`].
+pub struct ProxyAwareSource {
+    inner: Box<dyn DescriptorSource>,
+    proxy: Box<dyn ProxyResolver>,
+}
+
+impl ProxyAwareSource {
+    pub fn new(inner: Box<dyn DescriptorSource>, proxy: Box<dyn ProxyResolver>) -> Self {
+        Self { inner, proxy }
+    }
+}
+
+/// Resolve a descriptor for `address` with `resolve`, retrying against the
+/// implementation `proxy` reports when `address` itself is not indexed.
+pub(crate) async fn resolve_through_proxy<'a, F>(
+    proxy: &dyn ProxyResolver,
+    chain_id: u64,
+    address: &str,
+    resolve: F,
+) -> Result<ResolvedDescriptor, ResolveError>
+where
+    F: Fn(
+        String,
+    )
+        -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + 'a>>,
+{
+    let not_found = match resolve(address.to_string()).await {
+        Err(e @ ResolveError::NotFound { .. }) => e,
+        other => return other,
+    };
+    let target = match proxy.resolve_proxy(chain_id, address).await {
+        Ok(Some(target)) => target,
+        Ok(None) => return Err(not_found),
+        Err(ResolveError::Proxy(e)) => return Err(ResolveError::Proxy(e)),
+        Err(e) => return Err(ResolveError::Proxy(e.to_string())),
+    };
+    let mut resolved = match resolve(target.implementation.clone()).await {
+        Err(ResolveError::NotFound { .. }) => return Err(not_found),
+        other => other?,
+    };
+    resolved.address = address.to_lowercase();
+    resolved.proxy = Some(target);
+    Ok(resolved)
+}
+
+impl DescriptorSource for ProxyAwareSource {
+    fn resolve_calldata(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        let address = address.to_string();
+        Box::pin(async move {
+            resolve_through_proxy(self.proxy.as_ref(), chain_id, &address, |address| {
+                Box::pin(async move { self.inner.resolve_calldata(chain_id, &address).await })
+            })
+            .await
+        })
+    }
+
+    fn resolve_typed(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        let address = address.to_string();
+        Box::pin(async move {
+            resolve_through_proxy(self.proxy.as_ref(), chain_id, &address, |address| {
+                Box::pin(async move { self.inner.resolve_typed(chain_id, &address).await })
+            })
+            .await
+        })
+    }
+
+    fn resolve_calldata_by_factory(
+        &self,
+        chain_id: u64,
+        factory: &str,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        self.inner
+            .resolve_calldata_by_factory(chain_id, factory, address)
+    }
+
+    fn resolve_calldata_by_matcher<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &str,
+        oracle: &'a dyn FactoryOracle,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + 'a>> {
+        self.inner
+            .resolve_calldata_by_matcher(chain_id, address, oracle)
+    }
+}
+
+/// In-memory [`StorageReader`] for testing.
+#[derive(Debug, Clone, Default)]
+pub struct StaticStorageReader {
+    /// Map of `"{chain_id}:{address}:{slot_hex}"` → storage word.
+    slots: HashMap<String, [u8; 32]>,
+    /// Map of `"{chain_id}:{address}"` → beacon implementation address.
+    beacons: HashMap<String, [u8; 20]>,
+}
+
+impl StaticStorageReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn make_key(chain_id: u64, address: &str) -> String {
+        format!("{}:{}", chain_id, address.to_lowercase())
+    }
+
+    /// Store an address in a slot of `address`.
+    pub fn insert_slot_address(
+        &mut self,
+        chain_id: u64,
+        address: &str,
+        slot: [u8; 32],
+        value: &str,
+    ) {
+        let mut word = [0u8; 32];
+        if let Some(bytes) = parse_address(value) {
+            word[12..].copy_from_slice(&bytes);
+        }
+        self.insert_slot(chain_id, address, slot, word);
+    }
+
+    /// Store a raw word in a slot of `address`.
+    pub fn insert_slot(&mut self, chain_id: u64, address: &str, slot: [u8; 32], word: [u8; 32]) {
+        self.slots.insert(
+            format!(
+                "{}:{}",
+                Self::make_key(chain_id, address),
+                hex::encode(slot)
+            ),
+            word,
+        );
+    }
+
+    /// Register a beacon whose `implementation()` returns `implementation`.
+    pub fn insert_beacon(&mut self, chain_id: u64, beacon: &str, implementation: &str) {
+        if let Some(bytes) = parse_address(implementation) {
+            self.beacons.insert(Self::make_key(chain_id, beacon), bytes);
+        }
+    }
+}
+
+impl StorageReader for StaticStorageReader {
+    fn storage_at(
+        &self,
+        chain_id: u64,
+        address: &str,
+        slot: [u8; 32],
+    ) -> Pin<Box<dyn Future<Output = Result<[u8; 32], ResolveError>> + Send + '_>> {
+        let key = format!(
+            "{}:{}",
+            Self::make_key(chain_id, address),
+            hex::encode(slot)
+        );
+        let word = self.slots.get(&key).copied().unwrap_or([0u8; 32]);
+        Box::pin(async move { Ok(word) })
+    }
+
+    fn call(
+        &self,
+        chain_id: u64,
+        to: &str,
+        data: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>, ResolveError>> + Send + '_>> {
+        let result = if data == BEACON_IMPLEMENTATION_SELECTOR {
+            self.beacons
+                .get(&Self::make_key(chain_id, to))
+                .map(|addr| {
+                    let mut word = vec![0u8; 12];
+                    word.extend_from_slice(addr);
+                    word
+                })
+                .ok_or_else(|| ResolveError::Io(format!("execution reverted: {to}")))
+        } else {
+            Err(ResolveError::Io(format!("unsupported call to {to}")))
+        };
+        Box::pin(async move { result })
+    }
+}
+
+fn parse_address(addr: &str) -> Option<[u8; 20]> {
+    let hex_str = addr
+        .strip_prefix("0x")
+        .or_else(|| addr.strip_prefix("0X"))
+        .unwrap_or(addr);
+    hex::decode(hex_str).ok()?.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROXY: &str = "0x00000000000000000000000000000000000000a1";
+    const IMPL: &str = "0x00000000000000000000000000000000000000b2";
+
+    #[test]
+    fn test_slot_constants_match_spec() {
+        use tiny_keccak::{Hasher, Keccak};
+        let hash = |s: &str| {
+            let mut hasher = Keccak::v256();
+            hasher.update(s.as_bytes());
+            let mut out = [0u8; 32];
+            hasher.finalize(&mut out);
+            out
+        };
+        assert_eq!(hash("PROXIABLE"), EIP1822_PROXIABLE_SLOT);
+        let mut implementation = hash("eip1967.proxy.implementation");
+        implementation[31] -= 1;
+        assert_eq!(implementation, EIP1967_IMPLEMENTATION_SLOT);
+        let mut beacon = hash("eip1967.proxy.beacon");
+        beacon[31] -= 1;
+        assert_eq!(beacon, EIP1967_BEACON_SLOT);
+    }
+
+    #[tokio::test]
+    async fn test_detects_each_proxy_kind() {
+        let mut reader = StaticStorageReader::new();
+        reader.insert_slot_address(1, PROXY, EIP1967_IMPLEMENTATION_SLOT, IMPL);
+        reader.insert_slot_address(
+            1,
+            "0x00000000000000000000000000000000000000a2",
+            EIP1822_PROXIABLE_SLOT,
+            IMPL,
+        );
+        reader.insert_slot_address(
+            1,
+            "0x00000000000000000000000000000000000000a3",
+            EIP1967_BEACON_SLOT,
+            "0x00000000000000000000000000000000000000c3",
+        );
+        reader.insert_beacon(1, "0x00000000000000000000000000000000000000c3", IMPL);
+        let resolver = SlotProxyResolver::new(Box::new(reader));
+
+        let cases = [
+            (PROXY, ProxyKind::Eip1967),
+            (
+                "0x00000000000000000000000000000000000000A2",
+                ProxyKind::Eip1822,
+            ),
+            (
+                "0x00000000000000000000000000000000000000a3",
+                ProxyKind::Beacon,
+            ),
+        ];
+        for (address, kind) in cases {
+            let target = resolver.resolve_proxy(1, address).await.unwrap().unwrap();
+            assert_eq!(target.kind, kind);
+            assert_eq!(target.implementation, IMPL);
+        }
+
+        assert!(resolver.resolve_proxy(1, IMPL).await.unwrap().is_none());
+        assert!(resolver.resolve_proxy(10, PROXY).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_dirty_slot_words() {
+        let mut word = [0u8; 32];
+        word[0] = 0x01;
+        word[31] = 0xb2;
+        let mut reader = StaticStorageReader::new();
+        reader.insert_slot(1, PROXY, EIP1967_IMPLEMENTATION_SLOT, word);
+        let resolver = SlotProxyResolver::new(Box::new(reader));
+
+        assert!(matches!(
+            resolver.resolve_proxy(1, PROXY).await,
+            Err(ResolveError::Proxy(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_proxy_aware_source_resolves_implementation() {
+        use crate::resolver::StaticSource;
+        use crate::types::descriptor::Descriptor;
+
+        let descriptor = Descriptor::from_json(
+            r#"{ "context": { "contract": { "deployments": [] } }, "metadata": {}, "display": { "formats": {} } }"#,
+        )
+        .unwrap();
+        let mut inner = StaticSource::new();
+        inner.add_calldata(1, IMPL, descriptor.clone());
+        inner.add_typed(1, IMPL, descriptor);
+        let mut reader = StaticStorageReader::new();
+        reader.insert_slot_address(1, PROXY, EIP1967_IMPLEMENTATION_SLOT, IMPL);
+        let source = ProxyAwareSource::new(
+            Box::new(inner),
+            Box::new(SlotProxyResolver::new(Box::new(reader))),
+        );
+
+        let direct = source.resolve_calldata(1, IMPL).await.unwrap();
+        assert!(direct.proxy.is_none());
+        for resolved in [
+            source.resolve_calldata(1, PROXY).await.unwrap(),
+            source.resolve_typed(1, PROXY).await.unwrap(),
+        ] {
+            assert_eq!(resolved.address, PROXY);
+            let target = resolved.proxy.unwrap();
+            assert_eq!(target.kind, ProxyKind::Eip1967);
+            assert_eq!(target.implementation, IMPL);
+        }
+        assert!(matches!(
+            source
+                .resolve_calldata(1, "0x00000000000000000000000000000000000000a9")
+                .await,
+            Err(ResolveError::NotFound { .. })
+        ));
+    }
+}
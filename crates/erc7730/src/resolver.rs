@@ -1,11 +1,14 @@
 //! Pluggable descriptor resolution via the [`DescriptorSource`] trait.
-//! Includes [`StaticSource`] for testing and embedded use cases.
+//! Includes [`StaticSource`] for testing and embedded use cases, and
+//! [`FactoryAwareSource`] for factory-deployed and address-matched contracts.
 
-use std::collections::HashMap;
-use std::future::Future;
-use std::pin::Pin;
+use core::future::Future;
+use core::pin::Pin;
 
 use crate::error::ResolveError;
+#[cfg(feature = "std")]
+use crate::include::{self, IncludeChain};
+use crate::prelude::*;
 use crate::types::descriptor::Descriptor;
 
 /// A resolved descriptor ready for use.
@@ -14,6 +17,8 @@ pub struct ResolvedDescriptor {
     pub descriptor: Descriptor,
     pub chain_id: u64,
     pub address: String,
+    /// Set when the descriptor is the implementation's, found through a proxy at `address`.
+    pub proxy: Option<crate::proxy::ProxyTarget>,
 }
 
 /// Trait for descriptor sources (embedded, filesystem, GitHub API, etc.).
@@ -31,6 +36,187 @@ pub trait DescriptorSource: Send + Sync {
         chain_id: u64,
         address: &str,
     ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>>;
+
+    /// Resolve a calldata descriptor whose `factory` deployments include `factory`.
+    ///
+    /// `address` is the deployed instance being signed for. Sources without factory
+    /// support return [`ResolveError::NotFound`].
+    fn resolve_calldata_by_factory(
+        &self,
+        chain_id: u64,
+        factory: &str,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        let _ = factory;
+        let result = Err(ResolveError::NotFound {
+            chain_id,
+            address: address.to_string(),
+        });
+        Box::pin(async move { result })
+    }
+
+    /// Resolve a calldata descriptor whose `addressMatcher` accepts `address`,
+    /// asking `oracle` to evaluate each matcher.
+    fn resolve_calldata_by_matcher<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &str,
+        oracle: &'a dyn FactoryOracle,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + 'a>> {
+        let _ = oracle;
+        let result = Err(ResolveError::NotFound {
+            chain_id,
+            address: address.to_string(),
+        });
+        Box::pin(async move { result })
+    }
+}
+
+/// Answers which factory deployed a contract and whether an address satisfies
+/// a descriptor's `addressMatcher` (typically backed by an indexer or RPC node).
+pub trait FactoryOracle: Send + Sync {
+    /// Return the factory address that deployed `address` on `chain_id`, if known.
+    fn deployed_by(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Option<String>> + Send + '_>>;
+
+    /// Whether `address` satisfies the `addressMatcher` URL `matcher`.
+    fn matches_address(
+        &self,
+        matcher: &str,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = bool> + Send + '_>> {
+        let _ = (matcher, chain_id, address);
+        Box::pin(async { false })
+    }
+}
+
+/// In-memory [`FactoryOracle`] for testing and caller-supplied hints.
+#[derive(Debug, Clone, Default)]
+pub struct StaticFactoryOracle {
+    /// Map of `"{chain_id}:{address}"` → factory address.
+    deployers: HashMap<String, String>,
+    /// Set of `"{matcher}|{chain_id}:{address}"` accepted by a matcher.
+    matches: HashSet<String>,
+}
+
+impl StaticFactoryOracle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `factory` deployed `address` on `chain_id`.
+    pub fn insert_deployer(&mut self, chain_id: u64, address: &str, factory: &str) {
+        self.deployers
+            .insert(make_key(chain_id, address), factory.to_lowercase());
+    }
+
+    /// Record that `matcher` accepts `address` on `chain_id`.
+    pub fn insert_match(&mut self, matcher: &str, chain_id: u64, address: &str) {
+        self.matches
+            .insert(format!("{matcher}|{}", make_key(chain_id, address)));
+    }
+
+    fn lookup_deployer(&self, chain_id: u64, address: &str) -> Option<String> {
+        self.deployers.get(&make_key(chain_id, address)).cloned()
+    }
+}
+
+impl FactoryOracle for StaticFactoryOracle {
+    fn deployed_by(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Option<String>> + Send + '_>> {
+        let result = self.lookup_deployer(chain_id, address);
+        Box::pin(async move { result })
+    }
+
+    fn matches_address(
+        &self,
+        matcher: &str,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = bool> + Send + '_>> {
+        let result = self
+            .matches
+            .contains(&format!("{matcher}|{}", make_key(chain_id, address)));
+        Box::pin(async move { result })
+    }
+}
+
+fn make_key(chain_id: u64, address: &str) -> String {
+    format!("{}:{}", chain_id, address.to_lowercase())
+}
+
+/// Descriptors indexed by factory deployment and by address matcher.
+#[derive(Default)]
+struct InstanceIndex {
+    /// Map of `"{chain_id}:{factory}"` → Descriptor.
+    factories: HashMap<String, Descriptor>,
+    /// `(addressMatcher, descriptor)` pairs, in insertion order.
+    matchers: Vec<(String, Descriptor)>,
+}
+
+impl InstanceIndex {
+    fn insert(&mut self, descriptor: &Descriptor) {
+        for deployment in descriptor.context.factory_deployments() {
+            self.factories.insert(
+                make_key(deployment.chain_id, &deployment.address),
+                descriptor.clone(),
+            );
+        }
+        if let Some(matcher) = descriptor.context.address_matcher() {
+            self.matchers
+                .push((matcher.to_string(), descriptor.clone()));
+        }
+    }
+
+    fn resolve_factory(
+        &self,
+        chain_id: u64,
+        factory: &str,
+        address: &str,
+    ) -> Result<ResolvedDescriptor, ResolveError> {
+        self.factories
+            .get(&make_key(chain_id, factory))
+            .cloned()
+            .map(|descriptor| ResolvedDescriptor {
+                descriptor,
+                chain_id,
+                address: address.to_lowercase(),
+                proxy: None,
+            })
+            .ok_or_else(|| ResolveError::NotFound {
+                chain_id,
+                address: address.to_string(),
+            })
+    }
+
+    async fn resolve_matcher(
+        &self,
+        chain_id: u64,
+        address: &str,
+        oracle: &dyn FactoryOracle,
+    ) -> Result<ResolvedDescriptor, ResolveError> {
+        for (matcher, descriptor) in &self.matchers {
+            if oracle.matches_address(matcher, chain_id, address).await {
+                return Ok(ResolvedDescriptor {
+                    descriptor: descriptor.clone(),
+                    chain_id,
+                    address: address.to_lowercase(),
+                    proxy: None,
+                });
+            }
+        }
+        Err(ResolveError::NotFound {
+            chain_id,
+            address: address.to_string(),
+        })
+    }
 }
 
 /// Static in-memory descriptor source for testing.
@@ -38,6 +224,7 @@ pub struct StaticSource {
     /// Map of `"{chain_id}:{address}"` → Descriptor.
     calldata: HashMap<String, Descriptor>,
     typed: HashMap<String, Descriptor>,
+    instances: InstanceIndex,
 }
 
 impl StaticSource {
@@ -45,6 +232,7 @@ impl StaticSource {
         Self {
             calldata: HashMap::new(),
             typed: HashMap::new(),
+            instances: InstanceIndex::default(),
         }
     }
 
@@ -64,6 +252,30 @@ impl StaticSource {
             .insert(Self::make_key(chain_id, address), descriptor);
     }
 
+    /// Add a calldata descriptor keyed by its `factory` deployments and `addressMatcher`,
+    /// so it covers every instance rather than a fixed address.
+    pub fn add_calldata_factory(&mut self, descriptor: Descriptor) {
+        self.instances.insert(&descriptor);
+    }
+
+    /// Add a descriptor under every deployment in its context, as a calldata or
+    /// typed data descriptor depending on the context kind.
+    pub fn add_descriptor(&mut self, descriptor: Descriptor) {
+        let context = &descriptor.context;
+        if context.is_contract()
+            && (!context.factory_deployments().is_empty() || context.address_matcher().is_some())
+        {
+            self.add_calldata_factory(descriptor.clone());
+        }
+        for deployment in descriptor.context.deployments() {
+            if descriptor.context.is_contract() {
+                self.add_calldata(deployment.chain_id, &deployment.address, descriptor.clone());
+            } else {
+                self.add_typed(deployment.chain_id, &deployment.address, descriptor.clone());
+            }
+        }
+    }
+
     /// Add a calldata descriptor from JSON.
     pub fn add_calldata_json(
         &mut self,
@@ -112,6 +324,7 @@ impl DescriptorSource for StaticSource {
                 descriptor,
                 chain_id,
                 address: address.to_lowercase(),
+                proxy: None,
             })
             .ok_or_else(|| ResolveError::NotFound {
                 chain_id,
@@ -134,6 +347,7 @@ impl DescriptorSource for StaticSource {
                 descriptor,
                 chain_id,
                 address: address.to_lowercase(),
+                proxy: None,
             })
             .ok_or_else(|| ResolveError::NotFound {
                 chain_id,
@@ -141,54 +355,91 @@ impl DescriptorSource for StaticSource {
             });
         Box::pin(async move { result })
     }
+
+    fn resolve_calldata_by_factory(
+        &self,
+        chain_id: u64,
+        factory: &str,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        let result = self.instances.resolve_factory(chain_id, factory, address);
+        Box::pin(async move { result })
+    }
+
+    fn resolve_calldata_by_matcher<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &str,
+        oracle: &'a dyn FactoryOracle,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + 'a>> {
+        let address = address.to_string();
+        Box::pin(async move {
+            self.instances
+                .resolve_matcher(chain_id, &address, oracle)
+                .await
+        })
+    }
 }
 
 /// Filesystem-based descriptor source — reads and indexes all JSON descriptors from a directory.
+#[cfg(feature = "std")]
 pub struct FilesystemSource {
     index: HashMap<String, Descriptor>,
+    instances: InstanceIndex,
+    skipped: Vec<(std::path::PathBuf, ResolveError)>,
 }
 
+#[cfg(feature = "std")]
 impl FilesystemSource {
     /// Load and index all descriptor JSON files recursively from a directory.
+    ///
+    /// A file that fails to load (bad JSON, broken or cyclic `includes`) is skipped
+    /// and reported by [`skipped`](Self::skipped) rather than failing the directory.
     pub fn from_directory(path: &std::path::Path) -> Result<Self, ResolveError> {
-        let mut index = HashMap::new();
+        let mut source = Self {
+            index: HashMap::new(),
+            instances: InstanceIndex::default(),
+            skipped: Vec::new(),
+        };
 
         fn walk_dir(
             dir: &std::path::Path,
-            index: &mut HashMap<String, Descriptor>,
+            source: &mut FilesystemSource,
         ) -> Result<(), ResolveError> {
             let entries = std::fs::read_dir(dir).map_err(|e| ResolveError::Io(e.to_string()))?;
             for entry in entries {
                 let entry = entry.map_err(|e| ResolveError::Io(e.to_string()))?;
                 let path = entry.path();
                 if path.is_dir() {
-                    walk_dir(&path, index)?;
+                    walk_dir(&path, source)?;
                 } else if path.extension().and_then(|e| e.to_str()) == Some("json") {
-                    let content = std::fs::read_to_string(&path)
-                        .map_err(|e| ResolveError::Io(e.to_string()))?;
-                    match serde_json::from_str::<Descriptor>(&content) {
-                        Ok(descriptor) => {
-                            for deployment in descriptor.context.deployments() {
-                                let key = format!(
-                                    "{}:{}",
-                                    deployment.chain_id,
-                                    deployment.address.to_lowercase()
-                                );
-                                index.insert(key, descriptor.clone());
-                            }
-                        }
-                        Err(_) => {
-                            // Skip non-descriptor JSON files
+                    let descriptor = match load_descriptor_file(&path) {
+                        Ok(Some(descriptor)) => descriptor,
+                        // Not a descriptor (e.g. a shared include base)
+                        Ok(None) => continue,
+                        Err(e) => {
+                            source.skipped.push((path, e));
                             continue;
                         }
+                    };
+                    for deployment in descriptor.context.deployments() {
+                        let key =
+                            FilesystemSource::make_key(deployment.chain_id, &deployment.address);
+                        source.index.insert(key, descriptor.clone());
                     }
+                    source.instances.insert(&descriptor);
                 }
             }
             Ok(())
         }
 
-        walk_dir(path, &mut index)?;
-        Ok(Self { index })
+        walk_dir(path, &mut source)?;
+        Ok(source)
+    }
+
+    /// Descriptor files that could not be loaded, with the reason.
+    pub fn skipped(&self) -> &[(std::path::PathBuf, ResolveError)] {
+        &self.skipped
     }
 
     fn make_key(chain_id: u64, address: &str) -> String {
@@ -196,6 +447,53 @@ impl FilesystemSource {
     }
 }
 
+/// Read one descriptor file, resolving its `includes` chain relative to the file.
+#[cfg(feature = "std")]
+pub fn load_descriptor(path: &std::path::Path) -> Result<Descriptor, ResolveError> {
+    if !path.is_file() {
+        return Err(ResolveError::Io(format!("{}: not a file", path.display())));
+    }
+    load_descriptor_file(path)?.ok_or_else(|| {
+        ResolveError::Parse(format!("{} is not an ERC-7730 descriptor", path.display()))
+    })
+}
+
+/// Read a descriptor file, resolving its `includes` chain relative to the file.
+///
+/// Returns `Ok(None)` for JSON files that are not descriptors: unparsable files and
+/// include bases, which have no `context` of their own.
+#[cfg(feature = "std")]
+fn load_descriptor_file(path: &std::path::Path) -> Result<Option<Descriptor>, ResolveError> {
+    fn read_json(path: &std::path::Path) -> Result<serde_json::Value, ResolveError> {
+        let content = std::fs::read_to_string(path).map_err(|e| ResolveError::Io(e.to_string()))?;
+        serde_json::from_str(&content).map_err(|e| ResolveError::Parse(e.to_string()))
+    }
+
+    let Ok(root) = read_json(path) else {
+        return Ok(None);
+    };
+    if root.get("context").is_none() {
+        return Ok(None);
+    }
+    if include::include_ref(&root).is_none() {
+        return Ok(serde_json::from_value(root).ok());
+    }
+
+    let location = std::fs::canonicalize(path).map_err(|e| ResolveError::Io(e.to_string()))?;
+    let mut chain = IncludeChain::new(&location.to_string_lossy(), root);
+    while let Some((including, reference)) = chain.pending() {
+        let dir = std::path::Path::new(including)
+            .parent()
+            .unwrap_or(std::path::Path::new("."));
+        let included = std::fs::canonicalize(dir.join(reference))
+            .map_err(|e| ResolveError::Io(format!("include {reference}: {e}")))?;
+        let document = read_json(&included)?;
+        chain.push(included.to_string_lossy().into_owned(), document)?;
+    }
+    chain.finish().map(Some)
+}
+
+#[cfg(feature = "std")]
 impl DescriptorSource for FilesystemSource {
     fn resolve_calldata(
         &self,
@@ -211,6 +509,7 @@ impl DescriptorSource for FilesystemSource {
                 descriptor,
                 chain_id,
                 address: address.to_lowercase(),
+                proxy: None,
             })
             .ok_or_else(|| ResolveError::NotFound {
                 chain_id,
@@ -226,6 +525,30 @@ impl DescriptorSource for FilesystemSource {
     ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
         self.resolve_calldata(chain_id, address)
     }
+
+    fn resolve_calldata_by_factory(
+        &self,
+        chain_id: u64,
+        factory: &str,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        let result = self.instances.resolve_factory(chain_id, factory, address);
+        Box::pin(async move { result })
+    }
+
+    fn resolve_calldata_by_matcher<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &str,
+        oracle: &'a dyn FactoryOracle,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + 'a>> {
+        let address = address.to_string();
+        Box::pin(async move {
+            self.instances
+                .resolve_matcher(chain_id, &address, oracle)
+                .await
+        })
+    }
 }
 
 /// HTTP-based descriptor source that fetches from a GitHub registry.
@@ -289,8 +612,24 @@ impl GitHubRegistrySource {
         format!("eip155:{}:{}", chain_id, address.to_lowercase())
     }
 
+    /// Fetch a descriptor and resolve its `includes` chain relative to the registry path.
     async fn fetch_descriptor(&self, rel_path: &str) -> Result<Descriptor, ResolveError> {
-        let url = format!("{}/{}", self.base_url, rel_path);
+        let root = self.fetch_json(rel_path).await?;
+        let mut chain = IncludeChain::new(rel_path, root);
+        while let Some((including, reference)) = chain.pending() {
+            let location = include::join_location(including, reference);
+            let document = self.fetch_json(&location).await?;
+            chain.push(location, document)?;
+        }
+        chain.finish()
+    }
+
+    async fn fetch_json(&self, location: &str) -> Result<serde_json::Value, ResolveError> {
+        let url = if location.starts_with("https://") || location.starts_with("http://") {
+            location.to_string()
+        } else {
+            format!("{}/{}", self.base_url, location.trim_start_matches('/'))
+        };
         let response = reqwest::get(&url).await.map_err(|e| {
             if e.status() == Some(reqwest::StatusCode::NOT_FOUND) {
                 ResolveError::NotFound {
@@ -332,6 +671,7 @@ impl DescriptorSource for GitHubRegistrySource {
                     descriptor: cached.clone(),
                     chain_id,
                     address: address_owned,
+                    proxy: None,
                 });
             }
 
@@ -347,6 +687,7 @@ impl DescriptorSource for GitHubRegistrySource {
                 descriptor,
                 chain_id,
                 address: address_owned,
+                proxy: None,
             })
         })
     }
@@ -358,6 +699,237 @@ impl DescriptorSource for GitHubRegistrySource {
     ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
         self.resolve_calldata(chain_id, address)
     }
+
+    /// Registry index keys also cover factory addresses; the fetched descriptor
+    /// must list the factory under `context.contract.factory`.
+    fn resolve_calldata_by_factory(
+        &self,
+        chain_id: u64,
+        factory: &str,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        let factory = factory.to_lowercase();
+        let address = address.to_lowercase();
+        Box::pin(async move {
+            let resolved = self.resolve_calldata(chain_id, &factory).await?;
+            let declares_factory = resolved
+                .descriptor
+                .context
+                .factory_deployments()
+                .iter()
+                .any(|d| d.chain_id == chain_id && d.address.to_lowercase() == factory);
+            if !declares_factory {
+                return Err(ResolveError::NotFound { chain_id, address });
+            }
+            Ok(ResolvedDescriptor {
+                descriptor: resolved.descriptor,
+                chain_id,
+                address,
+                proxy: None,
+            })
+        })
+    }
+}
+
+/// Descriptor source that also resolves per-instance contracts (factory deployments,
+/// address matchers) when the wrapped source has no exact `chain:address` match.
+///
+/// The deploying factory comes from caller-supplied hints first, then the optional
+/// [`FactoryOracle`], which also evaluates `addressMatcher` descriptors.
+pub struct FactoryAwareSource {
+    inner: Box<dyn DescriptorSource>,
+    oracle: Option<Box<dyn FactoryOracle>>,
+    hints: StaticFactoryOracle,
+}
+
+impl FactoryAwareSource {
+    pub fn new(inner: Box<dyn DescriptorSource>, oracle: Option<Box<dyn FactoryOracle>>) -> Self {
+        Self {
+            inner,
+            oracle,
+            hints: StaticFactoryOracle::new(),
+        }
+    }
+
+    /// Add a "deployed by" hint: `address` on `chain_id` was created by `factory`.
+    pub fn add_deployed_by(&mut self, chain_id: u64, address: &str, factory: &str) {
+        self.hints.insert_deployer(chain_id, address, factory);
+    }
+
+    async fn resolve_instance(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Result<ResolvedDescriptor, ResolveError> {
+        let factory = match self.hints.lookup_deployer(chain_id, address) {
+            Some(factory) => Some(factory),
+            None => match &self.oracle {
+                Some(oracle) => oracle.deployed_by(chain_id, address).await,
+                None => None,
+            },
+        };
+
+        if let Some(factory) = factory {
+            match self
+                .inner
+                .resolve_calldata_by_factory(chain_id, &factory, address)
+                .await
+            {
+                Err(ResolveError::NotFound { .. }) => {}
+                other => return other,
+            }
+        }
+
+        match &self.oracle {
+            Some(oracle) => {
+                self.inner
+                    .resolve_calldata_by_matcher(chain_id, address, oracle.as_ref())
+                    .await
+            }
+            None => Err(ResolveError::NotFound {
+                chain_id,
+                address: address.to_string(),
+            }),
+        }
+    }
+}
+
+impl DescriptorSource for FactoryAwareSource {
+    fn resolve_calldata(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        let address = address.to_string();
+        Box::pin(async move {
+            match self.inner.resolve_calldata(chain_id, &address).await {
+                Err(ResolveError::NotFound { .. }) => {
+                    self.resolve_instance(chain_id, &address).await
+                }
+                other => other,
+            }
+        })
+    }
+
+    fn resolve_typed(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        self.inner.resolve_typed(chain_id, address)
+    }
+
+    fn resolve_calldata_by_factory(
+        &self,
+        chain_id: u64,
+        factory: &str,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>> {
+        self.inner
+            .resolve_calldata_by_factory(chain_id, factory, address)
+    }
+
+    fn resolve_calldata_by_matcher<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &str,
+        oracle: &'a dyn FactoryOracle,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + 'a>> {
+        self.inner
+            .resolve_calldata_by_matcher(chain_id, address, oracle)
+    }
+}
+
+/// Descriptor source that tries each source in order, returning the first match.
+///
+/// Put local sources before remote ones to override registry descriptors. A failing
+/// source does not hide descriptors from later ones; its error is only returned
+/// when no source has a descriptor.
+pub struct CompositeDescriptorSource {
+    sources: Vec<Box<dyn DescriptorSource>>,
+}
+
+type ResolveFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + 'a>>;
+
+impl CompositeDescriptorSource {
+    pub fn new(sources: Vec<Box<dyn DescriptorSource>>) -> Self {
+        Self { sources }
+    }
+
+    async fn first_found<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &str,
+        resolve: impl Fn(&'a dyn DescriptorSource) -> ResolveFuture<'a> + Send,
+    ) -> Result<ResolvedDescriptor, ResolveError> {
+        let mut first_error = None;
+        for source in &self.sources {
+            match resolve(source.as_ref()).await {
+                Ok(resolved) => return Ok(resolved),
+                Err(ResolveError::NotFound { .. }) => {}
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        Err(first_error.unwrap_or_else(|| ResolveError::NotFound {
+            chain_id,
+            address: address.to_string(),
+        }))
+    }
+}
+
+impl DescriptorSource for CompositeDescriptorSource {
+    fn resolve_calldata(&self, chain_id: u64, address: &str) -> ResolveFuture<'_> {
+        let address = address.to_string();
+        Box::pin(async move {
+            self.first_found(chain_id, &address, |source| {
+                source.resolve_calldata(chain_id, &address)
+            })
+            .await
+        })
+    }
+
+    fn resolve_typed(&self, chain_id: u64, address: &str) -> ResolveFuture<'_> {
+        let address = address.to_string();
+        Box::pin(async move {
+            self.first_found(chain_id, &address, |source| {
+                source.resolve_typed(chain_id, &address)
+            })
+            .await
+        })
+    }
+
+    fn resolve_calldata_by_factory(
+        &self,
+        chain_id: u64,
+        factory: &str,
+        address: &str,
+    ) -> ResolveFuture<'_> {
+        let (factory, address) = (factory.to_string(), address.to_string());
+        Box::pin(async move {
+            self.first_found(chain_id, &address, |source| {
+                source.resolve_calldata_by_factory(chain_id, &factory, &address)
+            })
+            .await
+        })
+    }
+
+    fn resolve_calldata_by_matcher<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &str,
+        oracle: &'a dyn FactoryOracle,
+    ) -> ResolveFuture<'a> {
+        let address = address.to_string();
+        Box::pin(async move {
+            self.first_found(chain_id, &address, |source| {
+                source.resolve_calldata_by_matcher(chain_id, &address, oracle)
+            })
+            .await
+        })
+    }
 }
 
 #[cfg(test)]
@@ -370,4 +942,294 @@ mod tests {
         let result = source.resolve_calldata(1, "0xabc").await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_composite_source_first_match_wins() {
+        let json = |owner: &str| {
+            format!(
+                r#"{{"context": {{"contract": {{"deployments": []}}}}, "metadata": {{"owner": "{owner}"}}, "display": {{"formats": {{}}}}}}"#
+            )
+        };
+        let mut local = StaticSource::new();
+        local.add_calldata_json(1, "0xabc", &json("local")).unwrap();
+        let mut remote = StaticSource::new();
+        remote
+            .add_calldata_json(1, "0xabc", &json("remote"))
+            .unwrap();
+        remote
+            .add_calldata_json(1, "0xdef", &json("remote"))
+            .unwrap();
+
+        let source = CompositeDescriptorSource::new(vec![Box::new(local), Box::new(remote)]);
+        let resolved = source.resolve_calldata(1, "0xABC").await.unwrap();
+        assert_eq!(resolved.descriptor.metadata.owner.as_deref(), Some("local"));
+        let resolved = source.resolve_calldata(1, "0xdef").await.unwrap();
+        assert_eq!(
+            resolved.descriptor.metadata.owner.as_deref(),
+            Some("remote")
+        );
+        assert!(matches!(
+            source.resolve_typed(1, "0xabc").await,
+            Err(ResolveError::NotFound { .. })
+        ));
+    }
+
+    fn vault_descriptor() -> Descriptor {
+        Descriptor::from_json(
+            r#"{
+                "context": {
+                    "contract": {
+                        "factory": {
+                            "deployments": [
+                                { "chainId": 1, "address": "0xFAC7000000000000000000000000000000000001" }
+                            ],
+                            "deployEvent": "event VaultCreated(address vault)"
+                        },
+                        "addressMatcher": "https://example.com/vaults"
+                    }
+                },
+                "metadata": { "owner": "test" },
+                "display": { "formats": { "deposit(uint256)": { "intent": "Deposit" } } }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    const VAULT: &str = "0x00000000000000000000000000000000000000aa";
+
+    #[tokio::test]
+    async fn test_factory_aware_source_with_hint() {
+        let mut inner = StaticSource::new();
+        inner.add_calldata_factory(vault_descriptor());
+        let mut source = FactoryAwareSource::new(Box::new(inner), None);
+
+        let err = source.resolve_calldata(1, VAULT).await;
+        assert!(matches!(err, Err(ResolveError::NotFound { .. })));
+
+        source.add_deployed_by(1, VAULT, "0xfac7000000000000000000000000000000000001");
+        let resolved = source.resolve_calldata(1, VAULT).await.unwrap();
+        assert_eq!(resolved.address, VAULT);
+        assert!(resolved
+            .descriptor
+            .display
+            .formats
+            .contains_key("deposit(uint256)"));
+
+        // Factory deployments are chain-specific
+        source.add_deployed_by(10, VAULT, "0xfac7000000000000000000000000000000000001");
+        assert!(source.resolve_calldata(10, VAULT).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_factory_aware_source_with_oracle() {
+        let mut inner = StaticSource::new();
+        inner.add_calldata_factory(vault_descriptor());
+
+        let mut oracle = StaticFactoryOracle::new();
+        oracle.insert_deployer(1, VAULT, "0xFAC7000000000000000000000000000000000001");
+        oracle.insert_match(
+            "https://example.com/vaults",
+            1,
+            "0x00000000000000000000000000000000000000bb",
+        );
+        let source = FactoryAwareSource::new(Box::new(inner), Some(Box::new(oracle)));
+
+        assert!(source.resolve_calldata(1, VAULT).await.is_ok());
+
+        // Not deployed by a known factory, but accepted by the address matcher
+        let resolved = source
+            .resolve_calldata(1, "0x00000000000000000000000000000000000000BB")
+            .await
+            .unwrap();
+        assert_eq!(
+            resolved.address,
+            "0x00000000000000000000000000000000000000bb"
+        );
+
+        assert!(source
+            .resolve_calldata(1, "0x00000000000000000000000000000000000000cc")
+            .await
+            .is_err());
+    }
+
+    fn write_fixture_dir(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("erc7730-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        for (rel, content) in files {
+            let path = dir.join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_filesystem_source_resolves_includes() {
+        let dir = write_fixture_dir(
+            "includes",
+            &[
+                (
+                    "common/erc20.json",
+                    r#"{
+                        "metadata": { "owner": "common", "constants": { "max": "0xff" } },
+                        "display": { "formats": { "transfer(address,uint256)": {
+                            "intent": "Send",
+                            "fields": [
+                                { "path": "@.0", "label": "To", "format": "address" },
+                                { "path": "@.1", "label": "Amount", "format": "number" }
+                            ]
+                        } } }
+                    }"#,
+                ),
+                (
+                    "tether/calldata-usdt.json",
+                    r#"{
+                        "includes": "../common/erc20.json",
+                        "context": { "contract": { "deployments": [
+                            { "chainId": 1, "address": "0xdAC17F958D2ee523a2206206994597C13D831ec7" }
+                        ] } },
+                        "metadata": { "owner": "Tether" },
+                        "display": { "formats": { "transfer(address,uint256)": {
+                            "fields": [{ "path": "@.1", "label": "Amount", "format": "tokenAmount" }]
+                        } } }
+                    }"#,
+                ),
+            ],
+        );
+
+        let source = FilesystemSource::from_directory(&dir).unwrap();
+        let resolved = source
+            .resolve_calldata(1, "0xdac17f958d2ee523a2206206994597c13d831ec7")
+            .await
+            .unwrap();
+        let descriptor = resolved.descriptor;
+        assert_eq!(descriptor.metadata.owner.as_deref(), Some("Tether"));
+        assert!(descriptor.metadata.constants.contains_key("max"));
+        let format = &descriptor.display.formats["transfer(address,uint256)"];
+        assert_eq!(format.intent.as_deref(), Some("Send"));
+        assert_eq!(format.fields.len(), 2);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_filesystem_source_resolves_multi_level_includes() {
+        let dir = write_fixture_dir(
+            "multi-level-includes",
+            &[
+                (
+                    "common/base.json",
+                    r#"{
+                        "metadata": { "owner": "base", "constants": { "max": "0xff" } },
+                        "display": { "formats": { "transfer(address,uint256)": {
+                            "intent": "Send",
+                            "fields": [{ "path": "@.0", "label": "To", "format": "address" }]
+                        } } }
+                    }"#,
+                ),
+                (
+                    "common/erc20.json",
+                    r#"{
+                        "includes": "base.json",
+                        "metadata": { "owner": "erc20" },
+                        "display": { "formats": { "transfer(address,uint256)": {
+                            "fields": [{ "path": "@.1", "label": "Amount", "format": "number" }]
+                        } } }
+                    }"#,
+                ),
+                (
+                    "tether/calldata-usdt.json",
+                    r#"{
+                        "includes": "../common/erc20.json",
+                        "context": { "contract": { "deployments": [
+                            { "chainId": 1, "address": "0xdAC17F958D2ee523a2206206994597C13D831ec7" }
+                        ] } },
+                        "metadata": { "owner": "Tether" },
+                        "display": { "formats": { "transfer(address,uint256)": {
+                            "fields": [{ "path": "@.1", "label": "Amount", "format": "tokenAmount" }]
+                        } } }
+                    }"#,
+                ),
+            ],
+        );
+
+        let source = FilesystemSource::from_directory(&dir).unwrap();
+        // The intermediate base includes another base but is not itself a descriptor
+        assert!(source.skipped().is_empty());
+        let descriptor = source
+            .resolve_calldata(1, "0xdac17f958d2ee523a2206206994597c13d831ec7")
+            .await
+            .unwrap()
+            .descriptor;
+        assert_eq!(descriptor.metadata.owner.as_deref(), Some("Tether"));
+        assert!(descriptor.metadata.constants.contains_key("max"));
+        let format = &descriptor.display.formats["transfer(address,uint256)"];
+        assert_eq!(format.intent.as_deref(), Some("Send"));
+        assert_eq!(format.fields.len(), 2);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_filesystem_source_skips_broken_includes() {
+        let dir = write_fixture_dir(
+            "broken-include",
+            &[
+                (
+                    "broken.json",
+                    r#"{ "includes": "missing.json", "context": { "contract": { "deployments": [
+                        { "chainId": 1, "address": "0x0000000000000000000000000000000000000001" }
+                    ] } } }"#,
+                ),
+                (
+                    "usdt.json",
+                    r#"{
+                        "context": { "contract": { "deployments": [
+                            { "chainId": 1, "address": "0xdAC17F958D2ee523a2206206994597C13D831ec7" }
+                        ] } },
+                        "metadata": { "owner": "Tether" },
+                        "display": { "formats": {} }
+                    }"#,
+                ),
+            ],
+        );
+
+        let source = FilesystemSource::from_directory(&dir).unwrap();
+        assert!(source
+            .resolve_calldata(1, "0xdac17f958d2ee523a2206206994597c13d831ec7")
+            .await
+            .is_ok());
+        let skipped = source.skipped();
+        assert_eq!(skipped.len(), 1);
+        assert!(skipped[0].0.ends_with("broken.json"));
+        assert!(matches!(skipped[0].1, ResolveError::Io(_)));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_filesystem_source_include_cycle() {
+        let dir = write_fixture_dir(
+            "include-cycle",
+            &[
+                (
+                    "a.json",
+                    r#"{ "includes": "b.json", "context": { "contract": { "deployments": [] } } }"#,
+                ),
+                ("b.json", r#"{ "includes": "a.json" }"#),
+            ],
+        );
+
+        let err = load_descriptor(&dir.join("a.json")).expect_err("include cycle should fail");
+        assert!(matches!(err, ResolveError::IncludeCycle(_)));
+
+        // A cycle only costs the directory the descriptors involved
+        let source = FilesystemSource::from_directory(&dir).unwrap();
+        assert!(matches!(
+            source.skipped(),
+            [(_, ResolveError::IncludeCycle(_))]
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
 }
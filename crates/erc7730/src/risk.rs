@@ -0,0 +1,584 @@
+//! Approval risk analysis — inspects token approvals, operator approvals and
+//! permit signatures, and reports [`RiskFinding`]s alongside the rendered model.
+
+#[cfg(all(feature = "std", not(all(feature = "wasm", target_arch = "wasm32"))))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use num_bigint::BigUint;
+use serde::Serialize;
+
+use crate::decoder::{ArgumentValue, DecodedArguments};
+use crate::eip712::TypedData;
+use crate::engine::WarningSeverity;
+use crate::prelude::*;
+
+/// Allowances of at least this many whole tokens are reported as large.
+pub const LARGE_ALLOWANCE_TOKENS: u64 = 1_000_000_000;
+
+/// Decimals assumed by the large-allowance check when a token's are unknown.
+/// 18 is the most common and the largest in wide use, so a token with fewer
+/// decimals is only flagged at a larger amount than it should be.
+pub const FALLBACK_ALLOWANCE_DECIMALS: u8 = 18;
+
+/// Permits valid for longer than this many seconds (30 days) are reported as long-lived.
+pub const MAX_PERMIT_LIFETIME_SECS: u64 = 30 * 24 * 60 * 60;
+
+const APPROVE_SELECTOR: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+const INCREASE_ALLOWANCE_SELECTOR: [u8; 4] = [0x39, 0x50, 0x93, 0x51];
+const SET_APPROVAL_FOR_ALL_SELECTOR: [u8; 4] = [0xa2, 0x2c, 0xb4, 0x65];
+/// ERC-2612 `permit(address,address,uint256,uint256,uint8,bytes32,bytes32)`.
+const PERMIT_SELECTOR: [u8; 4] = [0xd5, 0x05, 0xac, 0xcf];
+/// Permit2 `approve(address,address,uint160,uint48)`.
+const PERMIT2_APPROVE_SELECTOR: [u8; 4] = [0x87, 0x51, 0x7c, 0x45];
+
+/// Category of an approval risk.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RiskKind {
+    /// The allowance is the maximum value (or close to it).
+    UnlimitedAllowance,
+    /// The allowance is at least [`LARGE_ALLOWANCE_TOKENS`] whole tokens, assuming
+    /// [`FALLBACK_ALLOWANCE_DECIMALS`] when the token's decimals are unknown.
+    LargeAllowance,
+    /// An operator is approved for every token of a collection.
+    ApprovalForAll,
+    /// The spender is an externally owned account rather than a contract.
+    SpenderIsEoa,
+    /// The spender is in neither the descriptor nor the caller's address book.
+    UnknownSpender,
+    /// The permit stays valid longer than [`MAX_PERMIT_LIFETIME_SECS`].
+    LongLivedPermit,
+}
+
+/// A risk found in a transaction or signature.
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RiskFinding {
+    pub kind: RiskKind,
+    pub severity: WarningSeverity,
+    pub message: String,
+}
+
+/// What is known about a spender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SpenderStatus {
+    /// Named by the descriptor, the caller's address book or a trusted source.
+    Known,
+    Eoa,
+    Unknown,
+}
+
+/// Lookups the analyzer needs from the rendering context.
+pub(crate) trait RiskLookup {
+    fn token_decimals(&self, chain_id: u64, token: &str) -> Option<u8>;
+    fn spender_status(&self, chain_id: u64, spender: &str) -> SpenderStatus;
+}
+
+/// What an approval lets the spender take.
+enum Allowance {
+    /// A fungible amount.
+    Amount(BigUint),
+    /// Every token of a collection (an operator approval).
+    Collection,
+    /// An amount that is not known to be fungible, e.g. `approve` on a contract
+    /// that may be an ERC-721, where it is a token ID. Only the spender is checked.
+    Unchecked,
+}
+
+impl From<Option<BigUint>> for Allowance {
+    fn from(amount: Option<BigUint>) -> Self {
+        amount.map_or(Self::Unchecked, Self::Amount)
+    }
+}
+
+/// A normalized approval or permit.
+struct Approval {
+    chain_id: u64,
+    token: Option<String>,
+    spender: String,
+    allowance: Allowance,
+    /// Unix time until which the approval or signature stays usable.
+    valid_until: Option<BigUint>,
+}
+
+/// Analyze an approval-like call.
+pub(crate) fn analyze_calldata(
+    chain_id: u64,
+    to: &str,
+    decoded: &DecodedArguments,
+    lookup: &dyn RiskLookup,
+) -> Vec<RiskFinding> {
+    let approval = calldata_approval(chain_id, to, decoded).map(|mut approval| {
+        // ERC-721 shares `approve(address,uint256)`, with a token ID as the
+        // second argument: only a known ERC-20 makes it an allowance
+        if is_allowance_call(decoded) && lookup.token_decimals(chain_id, to).is_none() {
+            approval.allowance = Allowance::Unchecked;
+        }
+        approval
+    });
+    assess(approval.into_iter().collect(), lookup)
+}
+
+/// Whether the call is an `approve` or `increaseAllowance`, whose amount is only
+/// checked when the called contract is a known ERC-20.
+pub(crate) fn is_allowance_call(decoded: &DecodedArguments) -> bool {
+    matches!(
+        decoded.selector,
+        APPROVE_SELECTOR | INCREASE_ALLOWANCE_SELECTOR
+    )
+}
+
+/// The spender of an approval-like call, whose name and code the analysis reads.
+pub(crate) fn calldata_spender(
+    chain_id: u64,
+    to: &str,
+    decoded: &DecodedArguments,
+) -> Option<String> {
+    calldata_approval(chain_id, to, decoded).map(|approval| approval.spender)
+}
+
+/// The spender of a permit signature, whose name and code the analysis reads.
+pub(crate) fn typed_spender(data: &TypedData) -> Option<String> {
+    let analyzed = matches!(
+        data.primary_type.as_str(),
+        "Permit"
+            | "PermitSingle"
+            | "PermitBatch"
+            | "PermitTransferFrom"
+            | "PermitBatchTransferFrom"
+            | "PermitWitnessTransferFrom"
+            | "PermitBatchWitnessTransferFrom"
+    );
+    analyzed
+        .then(|| data.message.get("spender").and_then(json_address))
+        .flatten()
+}
+
+fn calldata_approval(chain_id: u64, to: &str, decoded: &DecodedArguments) -> Option<Approval> {
+    let arg = |i: usize| decoded.args.get(i).map(|a| &a.value);
+    match decoded.selector {
+        APPROVE_SELECTOR | INCREASE_ALLOWANCE_SELECTOR => {
+            match (arg(0).and_then(address), arg(1).and_then(uint)) {
+                (Some(spender), Some(amount)) => Some(Approval {
+                    chain_id,
+                    token: Some(to.to_lowercase()),
+                    spender,
+                    allowance: Allowance::Amount(amount),
+                    valid_until: None,
+                }),
+                _ => None,
+            }
+        }
+        SET_APPROVAL_FOR_ALL_SELECTOR => match (arg(0).and_then(address), arg(1)) {
+            (Some(spender), Some(ArgumentValue::Bool(true))) => Some(Approval {
+                chain_id,
+                token: Some(to.to_lowercase()),
+                spender,
+                allowance: Allowance::Collection,
+                valid_until: None,
+            }),
+            _ => None,
+        },
+        PERMIT_SELECTOR => arg(1).and_then(address).map(|spender| Approval {
+            chain_id,
+            token: Some(to.to_lowercase()),
+            spender,
+            allowance: arg(2).and_then(uint).into(),
+            valid_until: arg(3).and_then(uint),
+        }),
+        PERMIT2_APPROVE_SELECTOR => arg(1).and_then(address).map(|spender| Approval {
+            chain_id,
+            token: arg(0).and_then(address),
+            spender,
+            allowance: arg(2).and_then(uint).into(),
+            valid_until: arg(3).and_then(uint),
+        }),
+        _ => None,
+    }
+}
+
+/// Analyze an ERC-2612 `Permit` or a Permit2 signature.
+pub(crate) fn analyze_typed_data(data: &TypedData, lookup: &dyn RiskLookup) -> Vec<RiskFinding> {
+    let chain_id = data.domain.chain_id.unwrap_or(1);
+    let message = &data.message;
+    let field = |value: &serde_json::Value, name: &str| value.get(name).cloned();
+    let Some(spender) = message.get("spender").and_then(json_address) else {
+        return Vec::new();
+    };
+    let approval = |token: Option<String>, amount: Option<BigUint>, valid_until| Approval {
+        chain_id,
+        token,
+        spender: spender.clone(),
+        allowance: amount.into(),
+        valid_until,
+    };
+
+    let approvals = match data.primary_type.as_str() {
+        "Permit" => vec![approval(
+            data.domain
+                .verifying_contract
+                .as_ref()
+                .map(|c| c.to_lowercase()),
+            field(message, "value").as_ref().and_then(json_uint),
+            field(message, "deadline").as_ref().and_then(json_uint),
+        )],
+        // Permit2 allowance permits: the allowance lives until `expiration`
+        "PermitSingle" | "PermitBatch" => {
+            let details = match message.get("details") {
+                Some(serde_json::Value::Array(items)) => items.clone(),
+                Some(item) => vec![item.clone()],
+                None => Vec::new(),
+            };
+            details
+                .iter()
+                .map(|d| {
+                    approval(
+                        d.get("token").and_then(json_address),
+                        field(d, "amount").as_ref().and_then(json_uint),
+                        field(d, "expiration").as_ref().and_then(json_uint),
+                    )
+                })
+                .collect()
+        }
+        // Permit2 signature transfers: usable until `deadline`
+        "PermitTransferFrom"
+        | "PermitBatchTransferFrom"
+        | "PermitWitnessTransferFrom"
+        | "PermitBatchWitnessTransferFrom" => {
+            let permitted = match message.get("permitted") {
+                Some(serde_json::Value::Array(items)) => items.clone(),
+                Some(item) => vec![item.clone()],
+                None => Vec::new(),
+            };
+            let deadline = field(message, "deadline").as_ref().and_then(json_uint);
+            permitted
+                .iter()
+                .map(|p| {
+                    approval(
+                        p.get("token").and_then(json_address),
+                        field(p, "amount").as_ref().and_then(json_uint),
+                        deadline.clone(),
+                    )
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+    assess(approvals, lookup)
+}
+
+fn assess(approvals: Vec<Approval>, lookup: &dyn RiskLookup) -> Vec<RiskFinding> {
+    let mut findings = Vec::new();
+    let mut push = |kind, severity, message: String| {
+        let finding = RiskFinding {
+            kind,
+            severity,
+            message,
+        };
+        if !findings.contains(&finding) {
+            findings.push(finding);
+        }
+    };
+    for approval in &approvals {
+        // Zero allowances revoke; they carry no risk
+        if matches!(&approval.allowance, Allowance::Amount(a) if *a == BigUint::ZERO) {
+            continue;
+        }
+        let spender = &approval.spender;
+        match &approval.allowance {
+            Allowance::Unchecked => {}
+            Allowance::Collection => push(
+                RiskKind::ApprovalForAll,
+                WarningSeverity::Warning,
+                format!("{spender} can transfer every token of this collection"),
+            ),
+            Allowance::Amount(amount) if is_unlimited(amount) => push(
+                RiskKind::UnlimitedAllowance,
+                WarningSeverity::Danger,
+                format!("{spender} can spend an unlimited amount"),
+            ),
+            Allowance::Amount(amount) => {
+                let decimals = approval
+                    .token
+                    .as_deref()
+                    .and_then(|token| lookup.token_decimals(approval.chain_id, token))
+                    .unwrap_or(FALLBACK_ALLOWANCE_DECIMALS);
+                let large = BigUint::from(LARGE_ALLOWANCE_TOKENS)
+                    * BigUint::from(10u8).pow(u32::from(decimals));
+                if *amount >= large {
+                    push(
+                        RiskKind::LargeAllowance,
+                        WarningSeverity::Warning,
+                        format!("{spender} can spend a very large amount"),
+                    );
+                }
+            }
+        }
+
+        match lookup.spender_status(approval.chain_id, spender) {
+            SpenderStatus::Known => {}
+            SpenderStatus::Eoa => push(
+                RiskKind::SpenderIsEoa,
+                WarningSeverity::Danger,
+                format!("spender {spender} is a personal account, not a contract"),
+            ),
+            SpenderStatus::Unknown => push(
+                RiskKind::UnknownSpender,
+                WarningSeverity::Warning,
+                format!("spender {spender} is not a known contract"),
+            ),
+        }
+
+        if let (Some(valid_until), Some(now)) = (&approval.valid_until, now()) {
+            let limit = BigUint::from(now + MAX_PERMIT_LIFETIME_SECS);
+            if *valid_until > limit {
+                push(
+                    RiskKind::LongLivedPermit,
+                    WarningSeverity::Warning,
+                    "permit stays valid for more than 30 days".to_string(),
+                );
+            }
+        }
+    }
+    findings
+}
+
+/// Whether an allowance is effectively unlimited (at least `type(uint160).max`,
+/// the Permit2 maximum).
+pub(crate) fn is_unlimited(amount: &BigUint) -> bool {
+    *amount >= (BigUint::from(1u8) << 160u32) - 1u8
+}
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+fn now() -> Option<u64> {
+    // `SystemTime::now` panics on wasm32-unknown-unknown
+    Some((js_sys::Date::now() / 1000.0) as u64)
+}
+
+#[cfg(all(feature = "std", not(all(feature = "wasm", target_arch = "wasm32"))))]
+fn now() -> Option<u64> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
+/// Without `std` there is no wall clock, so permit lifetimes are not checked.
+#[cfg(not(feature = "std"))]
+fn now() -> Option<u64> {
+    None
+}
+
+fn address(value: &ArgumentValue) -> Option<String> {
+    match value {
+        ArgumentValue::Address(addr) => Some(format!("0x{}", hex::encode(addr))),
+        _ => None,
+    }
+}
+
+fn uint(value: &ArgumentValue) -> Option<BigUint> {
+    match value {
+        ArgumentValue::Uint(bytes) => Some(BigUint::from_bytes_be(bytes)),
+        _ => None,
+    }
+}
+
+pub(crate) fn json_address(value: &serde_json::Value) -> Option<String> {
+    let s = value.as_str()?;
+    let hex_part = s.strip_prefix("0x")?;
+    (hex_part.len() == 40 && hex_part.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| s.to_lowercase())
+}
+
+pub(crate) fn json_uint(value: &serde_json::Value) -> Option<BigUint> {
+    match value {
+        serde_json::Value::Number(n) => n.as_u64().map(BigUint::from),
+        serde_json::Value::String(s) => match s.strip_prefix("0x") {
+            Some(hex_part) => BigUint::parse_bytes(hex_part.as_bytes(), 16),
+            None => BigUint::parse_bytes(s.as_bytes(), 10),
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPENDER: &str = "0x000000000022d473030f116ddee9f6b43ac78ba3";
+
+    struct Lookup(SpenderStatus);
+
+    impl RiskLookup for Lookup {
+        fn token_decimals(&self, _chain_id: u64, _token: &str) -> Option<u8> {
+            Some(6)
+        }
+
+        fn spender_status(&self, _chain_id: u64, _spender: &str) -> SpenderStatus {
+            self.0
+        }
+    }
+
+    /// A spender approved on a contract the token sources do not know.
+    struct UnknownToken(SpenderStatus);
+
+    impl RiskLookup for UnknownToken {
+        fn token_decimals(&self, _chain_id: u64, _token: &str) -> Option<u8> {
+            None
+        }
+
+        fn spender_status(&self, _chain_id: u64, _spender: &str) -> SpenderStatus {
+            self.0
+        }
+    }
+
+    fn kinds(findings: &[RiskFinding]) -> Vec<RiskKind> {
+        findings.iter().map(|f| f.kind).collect()
+    }
+
+    fn typed(primary_type: &str, message: serde_json::Value) -> TypedData {
+        serde_json::from_value(serde_json::json!({
+            "types": {},
+            "primaryType": primary_type,
+            "domain": {"chainId": 1, "verifyingContract": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"},
+            "message": message,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_calldata_approvals() {
+        let sig = crate::decoder::parse_signature("approve(address,uint256)").unwrap();
+        let approve = |amount: [u8; 32]| {
+            let mut calldata = sig.selector.to_vec();
+            let mut spender = [0u8; 32];
+            spender[12..].copy_from_slice(&hex::decode(&SPENDER[2..]).unwrap());
+            calldata.extend_from_slice(&spender);
+            calldata.extend_from_slice(&amount);
+            crate::decoder::decode_calldata(&sig, &calldata).unwrap()
+        };
+        let token = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+
+        let findings = analyze_calldata(
+            1,
+            token,
+            &approve([0xff; 32]),
+            &Lookup(SpenderStatus::Unknown),
+        );
+        assert_eq!(
+            kinds(&findings),
+            vec![RiskKind::UnlimitedAllowance, RiskKind::UnknownSpender]
+        );
+        assert_eq!(findings[0].severity, WarningSeverity::Danger);
+
+        // 2 billion USDC (6 decimals) to an EOA
+        let mut amount = [0u8; 32];
+        amount[24..].copy_from_slice(&2_000_000_000_000_000u64.to_be_bytes());
+        let findings = analyze_calldata(1, token, &approve(amount), &Lookup(SpenderStatus::Eoa));
+        assert_eq!(
+            kinds(&findings),
+            vec![RiskKind::LargeAllowance, RiskKind::SpenderIsEoa]
+        );
+
+        // Without decimals, Permit2 allowances assume 18: the same raw amount
+        // is not large, 2 billion 18-decimal tokens are
+        let sig =
+            crate::decoder::parse_signature("approve(address,address,uint160,uint48)").unwrap();
+        let permit2_approve = |amount: &BigUint| {
+            let mut calldata = sig.selector.to_vec();
+            let mut word = [0u8; 32];
+            word[12..].copy_from_slice(&hex::decode(&token[2..]).unwrap());
+            calldata.extend_from_slice(&word);
+            word[12..].copy_from_slice(&hex::decode(&SPENDER[2..]).unwrap());
+            calldata.extend_from_slice(&word);
+            let raw = amount.to_bytes_be();
+            let mut word = [0u8; 32];
+            word[32 - raw.len()..].copy_from_slice(&raw);
+            calldata.extend_from_slice(&word);
+            calldata.extend_from_slice(&[0u8; 32]);
+            crate::decoder::decode_calldata(&sig, &calldata).unwrap()
+        };
+        let permit2 = "0x000000000022d473030f116ddee9f6b43ac78ba3";
+        let unknown = UnknownToken(SpenderStatus::Known);
+        let small = BigUint::from(2_000_000_000_000_000u64);
+        let findings = analyze_calldata(1, permit2, &permit2_approve(&small), &unknown);
+        assert!(findings.is_empty());
+        let large = BigUint::from(2_000_000_000u64) * BigUint::from(10u8).pow(18);
+        let findings = analyze_calldata(1, permit2, &permit2_approve(&large), &unknown);
+        assert_eq!(kinds(&findings), vec![RiskKind::LargeAllowance]);
+
+        // Revocations are fine
+        let findings = analyze_calldata(
+            1,
+            token,
+            &approve([0u8; 32]),
+            &Lookup(SpenderStatus::Unknown),
+        );
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_nft_approve_is_not_an_allowance() {
+        // ERC-721 `approve(address,uint256)` of an ENS name: the token ID is a
+        // labelhash, far above the unlimited threshold
+        let sig = crate::decoder::parse_signature("approve(address,uint256)").unwrap();
+        let mut calldata = sig.selector.to_vec();
+        let mut spender = [0u8; 32];
+        spender[12..].copy_from_slice(&hex::decode(&SPENDER[2..]).unwrap());
+        calldata.extend_from_slice(&spender);
+        calldata.extend_from_slice(
+            &hex::decode("af2caa1c2ca1d027f1ac823b529d0a67cd144264b2789fa2ea4d63a67c7103cc")
+                .unwrap(),
+        );
+        let decoded = crate::decoder::decode_calldata(&sig, &calldata).unwrap();
+        let registrar = "0x57f1887a8bf19b14fc0df6fd9b2acc9af147ea85";
+
+        let findings =
+            analyze_calldata(1, registrar, &decoded, &UnknownToken(SpenderStatus::Known));
+        assert!(findings.is_empty());
+
+        // The spender is still checked
+        let findings = analyze_calldata(1, registrar, &decoded, &UnknownToken(SpenderStatus::Eoa));
+        assert_eq!(kinds(&findings), vec![RiskKind::SpenderIsEoa]);
+    }
+
+    #[test]
+    fn test_permits() {
+        let far = (now().unwrap() + 365 * 24 * 60 * 60).to_string();
+        let soon = (now().unwrap() + 60 * 60).to_string();
+
+        let permit = typed(
+            "Permit",
+            serde_json::json!({"owner": SPENDER, "spender": SPENDER, "value": "1000000", "nonce": 0, "deadline": far}),
+        );
+        assert_eq!(
+            kinds(&analyze_typed_data(&permit, &Lookup(SpenderStatus::Known))),
+            vec![RiskKind::LongLivedPermit]
+        );
+
+        let single = typed(
+            "PermitSingle",
+            serde_json::json!({
+                "details": {"token": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", "amount": "1461501637330902918203684832716283019655932542975", "expiration": soon, "nonce": 0},
+                "spender": SPENDER,
+                "sigDeadline": soon,
+            }),
+        );
+        assert_eq!(
+            kinds(&analyze_typed_data(&single, &Lookup(SpenderStatus::Known))),
+            vec![RiskKind::UnlimitedAllowance]
+        );
+
+        let transfer = typed(
+            "PermitTransferFrom",
+            serde_json::json!({
+                "permitted": {"token": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", "amount": "5"},
+                "spender": SPENDER,
+                "nonce": 0,
+                "deadline": soon,
+            }),
+        );
+        assert!(analyze_typed_data(&transfer, &Lookup(SpenderStatus::Known)).is_empty());
+    }
+}
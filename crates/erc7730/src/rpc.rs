@@ -0,0 +1,179 @@
+//! Read-only chain access via the [`EthCallTransport`] trait — implemented by the
+//! host application on top of its RPC client.
+
+use core::future::Future;
+use core::pin::Pin;
+
+use crate::error::ResolveError;
+use crate::prelude::*;
+
+/// Trait for executing `eth_call` against a chain.
+pub trait EthCallTransport: Send + Sync {
+    /// Execute a read-only call of `data` on `to` at the latest block.
+    ///
+    /// A reverted call is reported as `Ok` with empty output; `Err` is reserved for
+    /// transport failures, which callers do not cache.
+    fn eth_call(
+        &self,
+        chain_id: u64,
+        to: &str,
+        data: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>, ResolveError>> + Send + '_>>;
+}
+
+/// Trait for reading the code deployed at an address (`eth_getCode`), used to
+/// tell externally owned accounts from contracts.
+pub trait CodeReader: Send + Sync {
+    /// Code at `address` at the latest block; empty for an EOA.
+    fn get_code(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>, ResolveError>> + Send + '_>>;
+}
+
+/// In-memory [`EthCallTransport`] and [`CodeReader`] for testing.
+///
+/// Unregistered calls revert (empty output); unregistered addresses have no code.
+#[derive(Debug, Clone, Default)]
+pub struct StaticEthCallTransport {
+    /// Map of `"{chain_id}:{address}:{calldata_hex}"` → return data.
+    responses: HashMap<String, Vec<u8>>,
+    /// Map of `"{chain_id}:{address}:"` → deployed code.
+    code: HashMap<String, Vec<u8>>,
+    calls: alloc::sync::Arc<core::sync::atomic::AtomicUsize>,
+}
+
+impl StaticEthCallTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn make_key(chain_id: u64, to: &str, data: &[u8]) -> String {
+        format!("{}:{}:{}", chain_id, to.to_lowercase(), hex::encode(data))
+    }
+
+    /// Register the return data of calling `data` on `to`.
+    pub fn insert(&mut self, chain_id: u64, to: &str, data: &[u8], output: Vec<u8>) {
+        self.responses
+            .insert(Self::make_key(chain_id, to, data), output);
+    }
+
+    /// Register the code deployed at `address`.
+    pub fn insert_code(&mut self, chain_id: u64, address: &str, code: Vec<u8>) {
+        self.code
+            .insert(Self::make_key(chain_id, address, &[]), code);
+    }
+
+    /// Number of calls executed so far (shared between clones).
+    pub fn call_count(&self) -> usize {
+        self.calls.load(core::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+impl EthCallTransport for StaticEthCallTransport {
+    fn eth_call(
+        &self,
+        chain_id: u64,
+        to: &str,
+        data: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>, ResolveError>> + Send + '_>> {
+        self.calls
+            .fetch_add(1, core::sync::atomic::Ordering::SeqCst);
+        let result = self
+            .responses
+            .get(&Self::make_key(chain_id, to, &data))
+            .cloned()
+            .unwrap_or_default();
+        Box::pin(async move { Ok(result) })
+    }
+}
+
+impl CodeReader for StaticEthCallTransport {
+    fn get_code(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>, ResolveError>> + Send + '_>> {
+        let code = self
+            .code
+            .get(&Self::make_key(chain_id, address, &[]))
+            .cloned()
+            .unwrap_or_default();
+        Box::pin(async move { Ok(code) })
+    }
+}
+
+/// ABI-encode a string return value (`offset`, `length`, padded bytes).
+pub fn encode_string_return(value: &str) -> Vec<u8> {
+    let bytes = value.as_bytes();
+    let mut out = vec![0u8; 64];
+    out[31] = 0x20;
+    out[56..64].copy_from_slice(&(bytes.len() as u64).to_be_bytes());
+    out.extend_from_slice(bytes);
+    out.resize(64 + bytes.len().div_ceil(32) * 32, 0);
+    out
+}
+
+/// Decode a `string` return value, accepting legacy `bytes32` returns (e.g. MKR).
+pub fn decode_string_return(output: &[u8]) -> Option<String> {
+    if output.len() == 32 {
+        let end = output.iter().position(|b| *b == 0).unwrap_or(32);
+        return String::from_utf8(output[..end].to_vec()).ok();
+    }
+    let offset = word_to_usize(output.get(..32)?)?;
+    let len_end = offset.checked_add(32)?;
+    let len = word_to_usize(output.get(offset..len_end)?)?;
+    let data = output.get(len_end..len_end.checked_add(len)?)?;
+    String::from_utf8(data.to_vec()).ok()
+}
+
+/// Decode a `uint8` return value.
+pub fn decode_u8_return(output: &[u8]) -> Option<u8> {
+    let word = output.get(..32)?;
+    if word[..31].iter().any(|b| *b != 0) {
+        return None;
+    }
+    Some(word[31])
+}
+
+fn word_to_usize(word: &[u8]) -> Option<usize> {
+    if word[..24].iter().any(|b| *b != 0) {
+        return None;
+    }
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&word[24..32]);
+    usize::try_from(u64::from_be_bytes(buf)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_returns() {
+        assert_eq!(
+            decode_string_return(&encode_string_return("USD Coin")).as_deref(),
+            Some("USD Coin")
+        );
+
+        let mut mkr = [0u8; 32];
+        mkr[..3].copy_from_slice(b"MKR");
+        assert_eq!(decode_string_return(&mkr).as_deref(), Some("MKR"));
+
+        // Offset pointing past the end
+        let mut bad = encode_string_return("X");
+        bad[31] = 0xff;
+        assert!(decode_string_return(&bad).is_none());
+        assert!(decode_string_return(&[]).is_none());
+    }
+
+    #[test]
+    fn test_u8_return() {
+        let mut word = [0u8; 32];
+        word[31] = 18;
+        assert_eq!(decode_u8_return(&word), Some(18));
+        word[30] = 1;
+        assert_eq!(decode_u8_return(&word), None);
+    }
+}
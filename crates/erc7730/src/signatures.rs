@@ -0,0 +1,170 @@
+//! Selector → function signature lookup via the [`SignatureSource`] trait.
+//! Used to decode calldata for which no descriptor exists.
+
+use once_cell::race::OnceBox;
+
+use crate::decoder::selector_from_signature;
+use crate::prelude::*;
+
+/// Trait for 4-byte signature providers (embedded table, 4byte-style databases, etc.).
+pub trait SignatureSource: Send + Sync {
+    /// All known signatures for `selector`, most likely first.
+    fn lookup(&self, selector: &[u8; 4]) -> Vec<String>;
+}
+
+/// A no-op signature source that knows no selectors.
+pub struct EmptySignatureSource;
+
+impl SignatureSource for EmptySignatureSource {
+    fn lookup(&self, _selector: &[u8; 4]) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Embedded signature table for common functions (ERC-20/721/1155, Permit2, WETH, multicall).
+pub struct WellKnownSignatureSource {
+    signatures: HashMap<[u8; 4], Vec<String>>,
+}
+
+impl WellKnownSignatureSource {
+    pub fn new() -> Self {
+        let json_str = include_str!("assets/signatures.json");
+        let raw: Vec<String> =
+            serde_json::from_str(json_str).expect("embedded signatures.json is valid");
+        let mut source = StaticSignatureSource::new();
+        for signature in raw {
+            source.insert(&signature);
+        }
+        Self {
+            signatures: source.signatures,
+        }
+    }
+
+    /// Shared instance of the embedded table.
+    pub fn shared() -> &'static Self {
+        static SHARED: OnceBox<WellKnownSignatureSource> = OnceBox::new();
+        SHARED.get_or_init(|| Box::new(Self::new()))
+    }
+}
+
+impl Default for WellKnownSignatureSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SignatureSource for WellKnownSignatureSource {
+    fn lookup(&self, selector: &[u8; 4]) -> Vec<String> {
+        self.signatures.get(selector).cloned().unwrap_or_default()
+    }
+}
+
+/// Composite signature source that merges candidates from multiple sources in order.
+pub struct CompositeSignatureSource {
+    sources: Vec<Box<dyn SignatureSource + Send + Sync>>,
+}
+
+impl CompositeSignatureSource {
+    pub fn new(sources: Vec<Box<dyn SignatureSource + Send + Sync>>) -> Self {
+        Self { sources }
+    }
+}
+
+impl SignatureSource for CompositeSignatureSource {
+    fn lookup(&self, selector: &[u8; 4]) -> Vec<String> {
+        let mut result: Vec<String> = Vec::new();
+        for source in &self.sources {
+            for signature in source.lookup(selector) {
+                if !result.contains(&signature) {
+                    result.push(signature);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// In-memory signature source for testing and caller-supplied tables.
+pub struct StaticSignatureSource {
+    signatures: HashMap<[u8; 4], Vec<String>>,
+}
+
+impl StaticSignatureSource {
+    pub fn new() -> Self {
+        Self {
+            signatures: HashMap::new(),
+        }
+    }
+
+    /// Add a canonical signature such as `"transfer(address,uint256)"`.
+    pub fn insert(&mut self, signature: &str) {
+        let selector = selector_from_signature(signature);
+        let entry = self.signatures.entry(selector).or_default();
+        if !entry.iter().any(|s| s == signature) {
+            entry.push(signature.to_string());
+        }
+    }
+}
+
+impl Default for StaticSignatureSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SignatureSource for StaticSignatureSource {
+    fn lookup(&self, selector: &[u8; 4]) -> Vec<String> {
+        self.signatures.get(selector).cloned().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_known_transfer() {
+        let source = WellKnownSignatureSource::new();
+        assert_eq!(
+            source.lookup(&[0xa9, 0x05, 0x9c, 0xbb]),
+            vec!["transfer(address,uint256)".to_string()]
+        );
+        assert!(source.lookup(&[0xde, 0xad, 0xbe, 0xef]).is_empty());
+    }
+
+    #[test]
+    fn test_static_collision_keeps_every_candidate() {
+        let mut source = StaticSignatureSource::new();
+        source.insert("burn(uint256)");
+        source.insert("collate_propagate_storage(bytes16)");
+        source.insert("burn(uint256)");
+        assert_eq!(
+            source.lookup(&[0x42, 0x96, 0x6c, 0x68]),
+            vec![
+                "burn(uint256)".to_string(),
+                "collate_propagate_storage(bytes16)".to_string()
+            ]
+        );
+        assert_eq!(
+            WellKnownSignatureSource::new().lookup(&[0x42, 0x96, 0x6c, 0x68]),
+            vec!["burn(uint256)".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_composite_dedupes_in_order() {
+        let mut external = StaticSignatureSource::new();
+        external.insert("transfer(address,uint256)");
+        external.insert("withdraw(bytes32)");
+
+        let composite = CompositeSignatureSource::new(vec![
+            Box::new(external),
+            Box::new(WellKnownSignatureSource::new()),
+        ]);
+        assert_eq!(composite.lookup(&[0xa9, 0x05, 0x9c, 0xbb]).len(), 1);
+        assert_eq!(
+            composite.lookup(&[0x8e, 0x19, 0x89, 0x9e]),
+            vec!["withdraw(bytes32)".to_string()]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Built-in descriptors for standard token interfaces (ERC-20, ERC-721, ERC-1155).
+//!
+//! Used when no registry descriptor exists for the called contract. The ERC-20
+//! template applies only when the [`crate::TokenSource`] knows the token at `@.to`,
+//! whose metadata renders the amounts.
+
+use once_cell::race::OnceBox;
+
+use crate::prelude::*;
+use crate::types::descriptor::Descriptor;
+
+/// Token standard a built-in template covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenStandard {
+    Erc20,
+    Erc721,
+    Erc1155,
+}
+
+impl TokenStandard {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TokenStandard::Erc20 => "ERC-20",
+            TokenStandard::Erc721 => "ERC-721",
+            TokenStandard::Erc1155 => "ERC-1155",
+        }
+    }
+}
+
+/// The set of built-in templates, matched by function selector.
+///
+/// ERC-721 shares the `approve` and `transferFrom` selectors with ERC-20, where the
+/// last argument is a token ID rather than an amount, so callers pick the standards
+/// to consider: ERC-20 only for a known token, the NFT standards otherwise. The NFT
+/// templates only cover selectors no other standard uses.
+pub struct TokenTemplates {
+    templates: Vec<(TokenStandard, Descriptor)>,
+}
+
+impl TokenTemplates {
+    pub fn new() -> Self {
+        let sources = [
+            (
+                TokenStandard::Erc20,
+                include_str!("assets/templates/erc20.json"),
+            ),
+            (
+                TokenStandard::Erc721,
+                include_str!("assets/templates/erc721.json"),
+            ),
+            (
+                TokenStandard::Erc1155,
+                include_str!("assets/templates/erc1155.json"),
+            ),
+        ];
+        let templates = sources
+            .into_iter()
+            .map(|(standard, json)| {
+                let descriptor =
+                    Descriptor::from_json(json).expect("embedded token template is valid");
+                (standard, descriptor)
+            })
+            .collect();
+        Self { templates }
+    }
+
+    /// Shared instance of the built-in templates.
+    pub fn shared() -> &'static Self {
+        static SHARED: OnceBox<TokenTemplates> = OnceBox::new();
+        SHARED.get_or_init(|| Box::new(Self::new()))
+    }
+
+    /// Find the template among `standards` with a format for `selector`.
+    pub fn find(
+        &self,
+        selector: &[u8],
+        standards: &[TokenStandard],
+    ) -> Option<(TokenStandard, &Descriptor)> {
+        self.templates
+            .iter()
+            .filter(|(standard, _)| standards.contains(standard))
+            .find(|(_, descriptor)| crate::find_matching_signature(descriptor, selector).is_ok())
+            .map(|(standard, descriptor)| (*standard, descriptor))
+    }
+
+    /// The template for a given standard.
+    pub fn get(&self, standard: TokenStandard) -> Option<&Descriptor> {
+        self.templates
+            .iter()
+            .find(|(s, _)| *s == standard)
+            .map(|(_, descriptor)| descriptor)
+    }
+}
+
+impl Default for TokenTemplates {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::selector_from_signature;
+
+    const ALL: [TokenStandard; 3] = [
+        TokenStandard::Erc20,
+        TokenStandard::Erc721,
+        TokenStandard::Erc1155,
+    ];
+
+    #[test]
+    fn test_templates_match_standard_selectors() {
+        let templates = TokenTemplates::new();
+        let cases = [
+            ("transfer(address,uint256)", TokenStandard::Erc20),
+            ("approve(address,uint256)", TokenStandard::Erc20),
+            (
+                "transferFrom(address,address,uint256)",
+                TokenStandard::Erc20,
+            ),
+            (
+                "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+                TokenStandard::Erc20,
+            ),
+            ("setApprovalForAll(address,bool)", TokenStandard::Erc721),
+            (
+                "safeTransferFrom(address,address,uint256)",
+                TokenStandard::Erc721,
+            ),
+            (
+                "safeTransferFrom(address,address,uint256,uint256,bytes)",
+                TokenStandard::Erc1155,
+            ),
+        ];
+        for (signature, standard) in cases {
+            let (found, _) = templates
+                .find(&selector_from_signature(signature), &ALL)
+                .unwrap_or_else(|| panic!("no template for {signature}"));
+            assert_eq!(found, standard, "{signature}");
+        }
+        assert!(templates.find(&[0xde, 0xad, 0xbe, 0xef], &ALL).is_none());
+    }
+
+    #[test]
+    fn test_nft_templates_skip_shared_selectors() {
+        let templates = TokenTemplates::new();
+        let nft = [TokenStandard::Erc721, TokenStandard::Erc1155];
+        for signature in [
+            "approve(address,uint256)",
+            "transferFrom(address,address,uint256)",
+            "transfer(address,uint256)",
+        ] {
+            assert!(
+                templates
+                    .find(&selector_from_signature(signature), &nft)
+                    .is_none(),
+                "{signature}"
+            );
+        }
+    }
+}
@@ -1,12 +1,34 @@
 //! Token metadata resolution via the [`TokenSource`] trait.
 //! Uses CAIP-19 keys (`eip155:{chain}/erc20:{addr}`) for cross-chain lookups.
 
+use core::future::Future;
+use core::pin::Pin;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+use crate::error::ResolveError;
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use crate::rpc::{decode_string_return, decode_u8_return, EthCallTransport};
+
+/// `symbol()` selector.
+#[cfg(feature = "std")]
+const SYMBOL_SELECTOR: [u8; 4] = [0x95, 0xd8, 0x9b, 0x41];
+/// `decimals()` selector.
+#[cfg(feature = "std")]
+const DECIMALS_SELECTOR: [u8; 4] = [0x31, 0x3c, 0xe5, 0x67];
+/// `name()` selector.
+#[cfg(feature = "std")]
+const NAME_SELECTOR: [u8; 4] = [0x06, 0xfd, 0xde, 0x03];
+
 /// Token metadata.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TokenMeta {
     pub symbol: String,
     pub decimals: u8,
     pub name: String,
+    /// Token logo (`logoURI` in token lists), for wallet UI.
+    pub logo_uri: Option<String>,
 }
 
 /// Normalized token lookup key (CAIP-19 style: `eip155:{chain_id}/erc20:{address}`).
@@ -19,6 +41,17 @@ impl TokenLookupKey {
         let addr = address.to_lowercase();
         Self(format!("eip155:{chain_id}/erc20:{addr}"))
     }
+
+    /// The chain ID component of the key.
+    pub fn chain_id(&self) -> Option<u64> {
+        let (chain, _) = self.0.strip_prefix("eip155:")?.split_once('/')?;
+        chain.parse().ok()
+    }
+
+    /// The lowercase token address component of the key.
+    pub fn address(&self) -> Option<&str> {
+        self.0.split_once("/erc20:").map(|(_, addr)| addr)
+    }
 }
 
 /// Trait for token metadata providers.
@@ -26,6 +59,17 @@ pub trait TokenSource: Send + Sync {
     fn lookup(&self, key: &TokenLookupKey) -> Option<TokenMeta>;
 }
 
+/// Trait for token metadata providers that need I/O (RPC, HTTP).
+///
+/// The engine renders synchronously, so async sources are used ahead of rendering:
+/// resolve the needed keys, then format with a [`TokenSource`] that reads the results.
+pub trait AsyncTokenSource: Send + Sync {
+    fn lookup_async(
+        &self,
+        key: &TokenLookupKey,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<TokenMeta>, ResolveError>> + Send + '_>>;
+}
+
 /// A no-op token source that always returns None.
 pub struct EmptyTokenSource;
 
@@ -37,15 +81,15 @@ impl TokenSource for EmptyTokenSource {
 
 /// Well-known token source with embedded metadata for common tokens.
 pub struct WellKnownTokenSource {
-    tokens: std::collections::HashMap<TokenLookupKey, TokenMeta>,
+    tokens: HashMap<TokenLookupKey, TokenMeta>,
 }
 
 impl WellKnownTokenSource {
     pub fn new() -> Self {
         let json_str = include_str!("assets/tokens.json");
-        let raw: std::collections::HashMap<String, WellKnownEntry> =
+        let raw: HashMap<String, WellKnownEntry> =
             serde_json::from_str(json_str).expect("embedded tokens.json is valid");
-        let mut tokens = std::collections::HashMap::new();
+        let mut tokens = HashMap::new();
         for (key, entry) in raw {
             tokens.insert(
                 TokenLookupKey(key),
@@ -53,6 +97,7 @@ impl WellKnownTokenSource {
                     symbol: entry.symbol,
                     decimals: entry.decimals,
                     name: entry.name,
+                    logo_uri: None,
                 },
             );
         }
@@ -103,13 +148,13 @@ impl TokenSource for CompositeTokenSource {
 
 /// In-memory token source for testing.
 pub struct StaticTokenSource {
-    tokens: std::collections::HashMap<TokenLookupKey, TokenMeta>,
+    tokens: HashMap<TokenLookupKey, TokenMeta>,
 }
 
 impl StaticTokenSource {
     pub fn new() -> Self {
         Self {
-            tokens: std::collections::HashMap::new(),
+            tokens: HashMap::new(),
         }
     }
 
@@ -131,6 +176,121 @@ impl TokenSource for StaticTokenSource {
     }
 }
 
+/// Token source reading ERC-20 metadata from the token contract itself.
+///
+/// Calls `symbol()`, `decimals()` and `name()` through an [`EthCallTransport`],
+/// accepting legacy `bytes32` returns. Results, including "not a token", are cached;
+/// transport errors are not. The synchronous [`TokenSource`] impl only reads the
+/// cache, so call [`prefetch`](Self::prefetch) before rendering.
+#[cfg(feature = "std")]
+pub struct OnChainTokenSource {
+    transport: Box<dyn EthCallTransport>,
+    cache: Mutex<HashMap<TokenLookupKey, Option<TokenMeta>>>,
+}
+
+#[cfg(feature = "std")]
+impl OnChainTokenSource {
+    pub fn new(transport: Box<dyn EthCallTransport>) -> Self {
+        Self {
+            transport,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Resolve `keys` into the cache concurrently.
+    pub async fn prefetch(&self, keys: &[TokenLookupKey]) -> Result<(), ResolveError> {
+        let lookups = keys.iter().map(|key| self.lookup_async(key)).collect();
+        for result in crate::prefetch::join_all(lookups).await {
+            result?;
+        }
+        Ok(())
+    }
+
+    fn cached(&self, key: &TokenLookupKey) -> Option<Option<TokenMeta>> {
+        self.cache.lock().ok()?.get(key).cloned()
+    }
+
+    async fn resolve(&self, key: &TokenLookupKey) -> Result<Option<TokenMeta>, ResolveError> {
+        if let Some(hit) = self.cached(key) {
+            return Ok(hit);
+        }
+        let (Some(chain_id), Some(address)) = (key.chain_id(), key.address()) else {
+            return Ok(None);
+        };
+        let meta = self.fetch(chain_id, address).await?;
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(key.clone(), meta.clone());
+        }
+        Ok(meta)
+    }
+
+    async fn fetch(&self, chain_id: u64, address: &str) -> Result<Option<TokenMeta>, ResolveError> {
+        let Some(decimals) = self
+            .call(chain_id, address, DECIMALS_SELECTOR)
+            .await?
+            .and_then(|out| decode_u8_return(&out))
+        else {
+            return Ok(None);
+        };
+        let Some(symbol) = self
+            .call(chain_id, address, SYMBOL_SELECTOR)
+            .await?
+            .and_then(|out| decode_string_return(&out))
+            .filter(|s| !s.is_empty())
+        else {
+            return Ok(None);
+        };
+        let name = self
+            .call(chain_id, address, NAME_SELECTOR)
+            .await?
+            .and_then(|out| decode_string_return(&out))
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| symbol.clone());
+        Ok(Some(TokenMeta {
+            symbol,
+            decimals,
+            name,
+            logo_uri: None,
+        }))
+    }
+
+    /// Call a getter; `None` when the call reverts.
+    async fn call(
+        &self,
+        chain_id: u64,
+        address: &str,
+        selector: [u8; 4],
+    ) -> Result<Option<Vec<u8>>, ResolveError> {
+        match self
+            .transport
+            .eth_call(chain_id, address, selector.to_vec())
+            .await
+        {
+            Ok(out) if out.is_empty() => Ok(None),
+            Ok(out) => Ok(Some(out)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TokenSource for OnChainTokenSource {
+    fn lookup(&self, key: &TokenLookupKey) -> Option<TokenMeta> {
+        self.cached(key).flatten()
+    }
+}
+
+#[cfg(feature = "std")]
+impl AsyncTokenSource for OnChainTokenSource {
+    fn lookup_async(
+        &self,
+        key: &TokenLookupKey,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<TokenMeta>, ResolveError>> + Send + '_>> {
+        let key = key.clone();
+        Box::pin(async move { self.resolve(&key).await })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,6 +332,7 @@ mod tests {
                 symbol: "CUSTOM_USDC".to_string(),
                 decimals: 6,
                 name: "Custom USDC".to_string(),
+                logo_uri: None,
             },
         );
 
@@ -190,4 +351,64 @@ mod tests {
         let meta2 = composite.lookup(&key2).unwrap();
         assert_eq!(meta2.symbol, "USDT");
     }
+
+    fn onchain_transport() -> crate::rpc::StaticEthCallTransport {
+        use crate::rpc::encode_string_return;
+        let mut transport = crate::rpc::StaticEthCallTransport::new();
+        let usdc = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        let mkr = "0x9f8f72aa9304c8b593d555f12ef6589cc3a579a2";
+        let mut six = vec![0u8; 32];
+        six[31] = 6;
+        let mut eighteen = vec![0u8; 32];
+        eighteen[31] = 18;
+        transport.insert(1, usdc, &DECIMALS_SELECTOR, six);
+        transport.insert(1, usdc, &SYMBOL_SELECTOR, encode_string_return("USDC"));
+        transport.insert(1, usdc, &NAME_SELECTOR, encode_string_return("USD Coin"));
+        // MKR returns bytes32 for symbol() and name()
+        let mut symbol = vec![0u8; 32];
+        symbol[..3].copy_from_slice(b"MKR");
+        let mut name = vec![0u8; 32];
+        name[..5].copy_from_slice(b"Maker");
+        transport.insert(1, mkr, &DECIMALS_SELECTOR, eighteen);
+        transport.insert(1, mkr, &SYMBOL_SELECTOR, symbol);
+        transport.insert(1, mkr, &NAME_SELECTOR, name);
+        transport
+    }
+
+    #[tokio::test]
+    async fn test_onchain_source_reads_and_caches() {
+        let transport = onchain_transport();
+        let source = OnChainTokenSource::new(Box::new(transport.clone()));
+        let usdc = TokenLookupKey::new(1, "0xA0b86991c6218b36c1D19D4a2e9Eb0cE3606eB48");
+        let mkr = TokenLookupKey::new(1, "0x9f8f72aa9304c8b593d555f12ef6589cc3a579a2");
+        let not_token = TokenLookupKey::new(1, "0x0000000000000000000000000000000000000001");
+
+        // Sync lookups only see the cache
+        assert!(source.lookup(&usdc).is_none());
+
+        let meta = source.lookup_async(&usdc).await.unwrap().unwrap();
+        assert_eq!((meta.symbol.as_str(), meta.decimals), ("USDC", 6));
+        assert_eq!(meta.name, "USD Coin");
+
+        source
+            .prefetch(&[usdc.clone(), mkr.clone(), not_token.clone()])
+            .await
+            .unwrap();
+        let mkr_meta = source.lookup(&mkr).unwrap();
+        assert_eq!((mkr_meta.symbol.as_str(), mkr_meta.decimals), ("MKR", 18));
+        assert_eq!(mkr_meta.name, "Maker");
+        assert!(source.lookup(&not_token).is_none());
+
+        // USDC (3) + MKR (3) + not-a-token (reverting decimals) = 7 calls
+        assert_eq!(transport.call_count(), 7);
+        source.lookup_async(&not_token).await.unwrap();
+        assert_eq!(transport.call_count(), 7);
+    }
+
+    #[test]
+    fn test_lookup_key_components() {
+        let key = TokenLookupKey::new(8453, "0xABC");
+        assert_eq!(key.chain_id(), Some(8453));
+        assert_eq!(key.address(), Some("0xabc"));
+    }
 }
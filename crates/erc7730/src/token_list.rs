@@ -0,0 +1,337 @@
+//! Token metadata from lists in the Uniswap token-list format
+//! (<https://github.com/Uniswap/token-lists>), exposed as a [`TokenSource`].
+
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::Error;
+use crate::prelude::*;
+use crate::token::{TokenLookupKey, TokenMeta, TokenSource};
+
+/// Maximum number of tokens in a single list (per the token-list schema).
+pub const MAX_TOKENS_PER_LIST: usize = 10_000;
+
+/// Semantic version of a token list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+pub struct TokenListVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl core::fmt::Display for TokenListVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A token entry in a token list.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenListEntry {
+    pub chain_id: u64,
+    pub address: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub name: String,
+    #[serde(rename = "logoURI")]
+    pub logo_uri: Option<String>,
+}
+
+/// A parsed and validated token list.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenList {
+    pub name: String,
+    pub timestamp: String,
+    pub version: TokenListVersion,
+    pub tokens: Vec<TokenListEntry>,
+    #[serde(rename = "logoURI")]
+    pub logo_uri: Option<String>,
+}
+
+impl TokenList {
+    /// Parse and validate a token list from JSON bytes.
+    pub fn from_json(bytes: &[u8]) -> Result<Self, Error> {
+        let list: TokenList = serde_json::from_slice(bytes)
+            .map_err(|e| Error::TokenRegistry(format!("invalid token list JSON: {e}")))?;
+        list.validate()?;
+        Ok(list)
+    }
+
+    /// Load a token list from a JSON file.
+    #[cfg(feature = "std")]
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let bytes = std::fs::read(path).map_err(|e| {
+            Error::TokenRegistry(format!("read token list {}: {e}", path.display()))
+        })?;
+        Self::from_json(&bytes)
+    }
+
+    /// Fetch a token list over HTTP(S).
+    #[cfg(feature = "github-registry")]
+    pub async fn fetch(url: &str) -> Result<Self, Error> {
+        let response = reqwest::get(url)
+            .await
+            .map_err(|e| Error::TokenRegistry(format!("fetch token list {url}: {e}")))?;
+        if !response.status().is_success() {
+            return Err(Error::TokenRegistry(format!(
+                "fetch token list {url}: HTTP {}",
+                response.status()
+            )));
+        }
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| Error::TokenRegistry(format!("read token list {url}: {e}")))?;
+        Self::from_json(&bytes)
+    }
+
+    /// Check the list against the token-list schema constraints.
+    fn validate(&self) -> Result<(), Error> {
+        let invalid = |msg: String| Err(Error::TokenRegistry(format!("{}: {msg}", self.name)));
+
+        if self.name.trim().is_empty() || self.name.chars().count() > 30 {
+            return Err(Error::TokenRegistry(format!(
+                "token list name must be 1-30 characters: {:?}",
+                self.name
+            )));
+        }
+        if self.timestamp.is_empty() {
+            return invalid("missing timestamp".to_string());
+        }
+        if self.tokens.is_empty() || self.tokens.len() > MAX_TOKENS_PER_LIST {
+            return invalid(format!(
+                "token count {} outside 1..={MAX_TOKENS_PER_LIST}",
+                self.tokens.len()
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        for token in &self.tokens {
+            if token.chain_id == 0 {
+                return invalid(format!("token {} has chainId 0", token.address));
+            }
+            if !is_address(&token.address) {
+                return invalid(format!("invalid token address {:?}", token.address));
+            }
+            if token.symbol.is_empty() || token.symbol.chars().count() > 20 {
+                return invalid(format!(
+                    "token {} symbol must be 1-20 characters",
+                    token.address
+                ));
+            }
+            if token.name.is_empty() || token.name.chars().count() > 60 {
+                return invalid(format!(
+                    "token {} name must be 1-60 characters",
+                    token.address
+                ));
+            }
+            if let Some(ref uri) = token.logo_uri {
+                if !uri.contains(':') {
+                    return invalid(format!(
+                        "token {} logoURI is not a URI: {uri}",
+                        token.address
+                    ));
+                }
+            }
+            if !seen.insert(TokenLookupKey::new(token.chain_id, &token.address)) {
+                return invalid(format!(
+                    "duplicate token {} on chain {}",
+                    token.address, token.chain_id
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn is_address(address: &str) -> bool {
+    address
+        .strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Token source backed by one or more token lists.
+///
+/// Lists added first take priority when several lists contain the same token.
+pub struct TokenListSource {
+    lists: Vec<TokenList>,
+    tokens: HashMap<TokenLookupKey, TokenMeta>,
+}
+
+impl TokenListSource {
+    pub fn new() -> Self {
+        Self {
+            lists: Vec::new(),
+            tokens: HashMap::new(),
+        }
+    }
+
+    /// Add a list below the already-loaded ones in priority.
+    ///
+    /// A list with the same name as a loaded one replaces it in place, provided its
+    /// version is not older.
+    pub fn add_list(&mut self, list: TokenList) -> Result<(), Error> {
+        match self.lists.iter().position(|l| l.name == list.name) {
+            Some(i) if list.version < self.lists[i].version => {
+                return Err(Error::TokenRegistry(format!(
+                    "token list {} version {} is older than loaded {}",
+                    list.name, list.version, self.lists[i].version
+                )));
+            }
+            Some(i) => self.lists[i] = list,
+            None => self.lists.push(list),
+        }
+        self.reindex();
+        Ok(())
+    }
+
+    /// Names and versions of the loaded lists, in priority order.
+    pub fn lists(&self) -> impl Iterator<Item = (&str, TokenListVersion)> {
+        self.lists.iter().map(|l| (l.name.as_str(), l.version))
+    }
+
+    fn reindex(&mut self) {
+        self.tokens.clear();
+        for list in &self.lists {
+            for token in &list.tokens {
+                self.tokens
+                    .entry(TokenLookupKey::new(token.chain_id, &token.address))
+                    .or_insert_with(|| TokenMeta {
+                        symbol: token.symbol.clone(),
+                        decimals: token.decimals,
+                        name: token.name.clone(),
+                        logo_uri: token.logo_uri.clone(),
+                    });
+            }
+        }
+    }
+}
+
+impl Default for TokenListSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TokenSource for TokenListSource {
+    fn lookup(&self, key: &TokenLookupKey) -> Option<TokenMeta> {
+        self.tokens.get(key).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDC: &str = "0xA0b86991c6218b36c1D19D4a2e9Eb0cE3606eB48";
+
+    fn list_json(name: &str, minor: u32, symbol: &str) -> String {
+        format!(
+            r#"{{
+                "name": "{name}",
+                "timestamp": "2024-01-01T00:00:00.000Z",
+                "version": {{ "major": 1, "minor": {minor}, "patch": 0 }},
+                "keywords": ["default"],
+                "tokens": [
+                    {{
+                        "chainId": 1,
+                        "address": "{USDC}",
+                        "symbol": "{symbol}",
+                        "decimals": 6,
+                        "name": "USD Coin",
+                        "logoURI": "ipfs://QmXfzKRvjZz3u5JRgC4v5mGVbm9ahrUiB4DgzHBsnWbTMM"
+                    }},
+                    {{
+                        "chainId": 10,
+                        "address": "0x4200000000000000000000000000000000000042",
+                        "symbol": "OP",
+                        "decimals": 18,
+                        "name": "Optimism"
+                    }}
+                ]
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_load_and_lookup() {
+        let list = TokenList::from_json(list_json("Test List", 0, "USDC").as_bytes()).unwrap();
+        let mut source = TokenListSource::new();
+        source.add_list(list).unwrap();
+
+        let meta = source.lookup(&TokenLookupKey::new(1, USDC)).unwrap();
+        assert_eq!(meta.symbol, "USDC");
+        assert_eq!(meta.decimals, 6);
+        assert_eq!(
+            meta.logo_uri.as_deref(),
+            Some("ipfs://QmXfzKRvjZz3u5JRgC4v5mGVbm9ahrUiB4DgzHBsnWbTMM")
+        );
+        let op = source
+            .lookup(&TokenLookupKey::new(
+                10,
+                "0x4200000000000000000000000000000000000042",
+            ))
+            .unwrap();
+        assert!(op.logo_uri.is_none());
+        assert!(source.lookup(&TokenLookupKey::new(137, USDC)).is_none());
+    }
+
+    #[test]
+    fn test_priority_and_version_updates() {
+        let mut source = TokenListSource::new();
+        source
+            .add_list(TokenList::from_json(list_json("Primary", 1, "USDC").as_bytes()).unwrap())
+            .unwrap();
+        source
+            .add_list(TokenList::from_json(list_json("Secondary", 0, "USDC2").as_bytes()).unwrap())
+            .unwrap();
+        let key = TokenLookupKey::new(1, USDC);
+        assert_eq!(source.lookup(&key).unwrap().symbol, "USDC");
+
+        // A newer version replaces the list in place, keeping its priority
+        source
+            .add_list(TokenList::from_json(list_json("Primary", 2, "USDC.new").as_bytes()).unwrap())
+            .unwrap();
+        assert_eq!(source.lookup(&key).unwrap().symbol, "USDC.new");
+
+        // An older version is rejected
+        let err = source
+            .add_list(TokenList::from_json(list_json("Primary", 0, "OLD").as_bytes()).unwrap())
+            .unwrap_err();
+        assert!(err.to_string().contains("older than loaded 1.2.0"));
+
+        let lists: Vec<_> = source.lists().map(|(name, _)| name).collect();
+        assert_eq!(lists, vec!["Primary", "Secondary"]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_lists() {
+        let missing_version = r#"{ "name": "X", "timestamp": "t", "tokens": [] }"#;
+        assert!(TokenList::from_json(missing_version.as_bytes()).is_err());
+
+        let bad_address = list_json("Bad", 0, "USDC").replace(USDC, "0x1234");
+        let err = TokenList::from_json(bad_address.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("invalid token address"));
+
+        let long_symbol = list_json("Bad", 0, "ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        assert!(TokenList::from_json(long_symbol.as_bytes()).is_err());
+
+        let bad_decimals =
+            list_json("Bad", 0, "USDC").replace("\"decimals\": 6", "\"decimals\": 300");
+        assert!(TokenList::from_json(bad_decimals.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_from_file() {
+        let path =
+            std::env::temp_dir().join(format!("erc7730-token-list-{}.json", std::process::id()));
+        std::fs::write(&path, list_json("File List", 0, "USDC")).unwrap();
+        let list = TokenList::from_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(list.name, "File List");
+        assert_eq!(list.tokens.len(), 2);
+    }
+}
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Top-level context discriminator — either contract (calldata) or eip712.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -25,6 +27,27 @@ pub struct ContractContext {
 pub struct ContractInfo {
     #[serde(default)]
     pub deployments: Vec<Deployment>,
+
+    /// Factory whose deployed instances all share this descriptor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub factory: Option<FactoryInfo>,
+
+    /// URL of a service deciding whether an address is covered by this descriptor.
+    #[serde(rename = "addressMatcher")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_matcher: Option<String>,
+}
+
+/// Factory constraint: instances created by one of the factory deployments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactoryInfo {
+    #[serde(default)]
+    pub deployments: Vec<Deployment>,
+
+    /// Event emitted by the factory on deployment, e.g. `"event PoolCreated(address pool)"`.
+    #[serde(rename = "deployEvent")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deploy_event: Option<String>,
 }
 
 /// Context for EIP-712 typed data clear signing.
@@ -74,6 +97,26 @@ impl DescriptorContext {
         }
     }
 
+    /// Factory deployments for contract contexts (empty for EIP-712).
+    pub fn factory_deployments(&self) -> &[Deployment] {
+        match self {
+            DescriptorContext::Contract(c) => c
+                .contract
+                .factory
+                .as_ref()
+                .map_or(&[], |f| f.deployments.as_slice()),
+            DescriptorContext::Eip712(_) => &[],
+        }
+    }
+
+    /// Address matcher URL for contract contexts.
+    pub fn address_matcher(&self) -> Option<&str> {
+        match self {
+            DescriptorContext::Contract(c) => c.contract.address_matcher.as_deref(),
+            DescriptorContext::Eip712(_) => None,
+        }
+    }
+
     pub fn is_contract(&self) -> bool {
         matches!(self, DescriptorContext::Contract(_))
     }
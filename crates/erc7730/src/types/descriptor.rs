@@ -3,8 +3,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::context::DescriptorContext;
-use super::display::DescriptorDisplay;
+use super::display::{DescriptorDisplay, DisplayField};
 use super::metadata::Metadata;
+use crate::prelude::*;
 
 /// Top-level ERC-7730 v2 descriptor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +14,11 @@ pub struct Descriptor {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema: Option<String>,
 
+    /// Relative path or URL of a shared base descriptor this one extends.
+    /// Resolved and merged by descriptor sources; see [`crate::include`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub includes: Option<String>,
+
     pub context: DescriptorContext,
 
     pub metadata: Metadata,
@@ -30,4 +36,74 @@ impl Descriptor {
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(self)
     }
+
+    /// Problems that would stop this descriptor from resolving or rendering:
+    /// missing deployments, malformed addresses, unparseable calldata format keys
+    /// and dangling `$ref`s. Empty when the descriptor is usable.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let context = &self.context;
+        if context.deployments().is_empty()
+            && context.factory_deployments().is_empty()
+            && context.address_matcher().is_none()
+        {
+            problems.push("context has no deployments".to_string());
+        }
+        for deployment in context
+            .deployments()
+            .iter()
+            .chain(context.factory_deployments())
+        {
+            if !crate::token_list::is_address(&deployment.address) {
+                problems.push(format!(
+                    "invalid deployment address {} on chain {}",
+                    deployment.address, deployment.chain_id
+                ));
+            }
+        }
+
+        if self.display.formats.is_empty() {
+            problems.push("display has no formats".to_string());
+        }
+        let mut keys: Vec<&String> = self.display.formats.keys().collect();
+        keys.sort();
+        for key in keys {
+            if context.is_contract() {
+                if let Err(e) = crate::decoder::parse_signature(key) {
+                    problems.push(format!("format {key}: {e}"));
+                }
+            }
+            check_refs(
+                &self.display.formats[key].fields,
+                &self.display.definitions,
+                key,
+                &mut problems,
+            );
+        }
+        problems
+    }
+}
+
+fn check_refs(
+    fields: &[DisplayField],
+    definitions: &HashMap<String, DisplayField>,
+    format_key: &str,
+    problems: &mut Vec<String>,
+) {
+    for field in fields {
+        match field {
+            DisplayField::Reference { reference } => {
+                let found = reference
+                    .strip_prefix("#/definitions/")
+                    .is_some_and(|name| definitions.contains_key(name));
+                if !found {
+                    problems.push(format!("format {format_key}: unresolved $ref {reference}"));
+                }
+            }
+            DisplayField::Group { field_group } => {
+                check_refs(&field_group.fields, definitions, format_key, problems)
+            }
+            DisplayField::Simple { .. } => {}
+        }
+    }
 }
@@ -1,7 +1,8 @@
 //! Display configuration types: field formats, visibility rules, and layout groups.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+
+use crate::prelude::*;
 
 /// Top-level display section of a descriptor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,7 +18,7 @@ pub struct DescriptorDisplay {
 }
 
 /// A single display format for a function or message type.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DisplayFormat {
     /// Optional format identifier (v2).
     #[serde(rename = "$id")]
@@ -43,7 +44,7 @@ pub struct DisplayFormat {
 }
 
 /// A display field — can be a simple field, a field group, or a reference.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 #[allow(clippy::large_enum_variant)]
 pub enum DisplayField {
@@ -81,7 +82,7 @@ fn default_visible() -> VisibleRule {
 }
 
 /// A field group — replaces v1's `nestedFields`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FieldGroup {
     pub label: String,
 
@@ -92,7 +93,7 @@ pub struct FieldGroup {
 }
 
 /// How grouped fields should be iterated for display.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Iteration {
     #[default]
@@ -101,7 +102,7 @@ pub enum Iteration {
 }
 
 /// Visibility rule for a field.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum VisibleRule {
     /// Boolean shorthand: true = Always, false = Never.
@@ -131,7 +132,7 @@ impl VisibleRule {
 }
 
 /// Conditional visibility: `ifNotIn` or `mustBe`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VisibleCondition {
     #[serde(rename = "ifNotIn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -159,7 +160,7 @@ impl VisibleCondition {
 }
 
 /// Field format types.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum FieldFormat {
     TokenAmount,
@@ -179,8 +180,40 @@ pub enum FieldFormat {
     Unit,
 }
 
+/// Address kinds accepted by the `addressName` format's `types` param.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressType {
+    Eoa,
+    Contract,
+    Token,
+    Wallet,
+    Collection,
+}
+
+impl AddressType {
+    /// Whether an address of this kind is acceptable where `expected` is;
+    /// tokens and collections are also contracts, and an EOA is also a wallet.
+    pub fn satisfies(self, expected: AddressType) -> bool {
+        self == expected
+            || (expected == AddressType::Contract
+                && matches!(self, AddressType::Token | AddressType::Collection))
+            || (expected == AddressType::Wallet && self == AddressType::Eoa)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AddressType::Eoa => "eoa",
+            AddressType::Contract => "contract",
+            AddressType::Token => "token",
+            AddressType::Wallet => "wallet",
+            AddressType::Collection => "collection",
+        }
+    }
+}
+
 /// Format parameters — varies by format type.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FormatParams {
     /// Token address for tokenAmount/tokenTicker.
     #[serde(rename = "tokenPath")]
@@ -202,6 +235,19 @@ pub struct FormatParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chain_id_path: Option<String>,
 
+    /// Accepted address kinds for `addressName`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<Vec<AddressType>>,
+
+    /// Accepted name sources for `addressName` (e.g. `"local"`, `"ens"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<String>>,
+
+    /// Collection address path for `nftName` (defaults to `@.to`).
+    #[serde(rename = "collectionPath")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_path: Option<String>,
+
     /// Enum lookup key in metadata.enums.
     #[serde(rename = "enumPath")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -244,7 +290,7 @@ pub struct FormatParams {
 }
 
 /// Encryption parameters for encrypted fields.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionParams {
     #[serde(rename = "fallbackLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1,7 +1,8 @@
 //! Descriptor metadata: owner info, token details, enums, constants, and address books.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+
+use crate::prelude::*;
 
 /// Metadata section of a descriptor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
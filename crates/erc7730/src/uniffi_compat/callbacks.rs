@@ -0,0 +1,255 @@
+//! Foreign-implemented sources: Swift/Kotlin code implements these callback
+//! traits and plugs them into a [`ClearSigner`](super::ClearSigner).
+//!
+//! Callback failures never abort formatting on their own: a failed token or name
+//! lookup is treated as "unknown", and a failed descriptor lookup surfaces as a
+//! resolve error. Exceptions the foreign code did not declare arrive as
+//! [`CallbackError::Unexpected`] instead of panicking.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::address_name::{AddressName, AddressNameSource, NameSource};
+use crate::error::ResolveError;
+use crate::resolver::{DescriptorSource, ResolvedDescriptor};
+use crate::token::{TokenLookupKey, TokenMeta, TokenSource};
+use crate::types::descriptor::Descriptor;
+
+use super::TokenMetaInput;
+
+/// Error raised by a foreign callback.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+pub enum CallbackError {
+    /// The callback failed; `reason` is shown in warnings and errors.
+    #[error("{reason}")]
+    Failed { reason: String },
+    /// The callback threw an exception it did not declare.
+    #[error("unexpected callback error: {reason}")]
+    Unexpected { reason: String },
+}
+
+impl From<uniffi::UnexpectedUniFFICallbackError> for CallbackError {
+    fn from(value: uniffi::UnexpectedUniFFICallbackError) -> Self {
+        Self::Unexpected {
+            reason: value.reason,
+        }
+    }
+}
+
+/// Token metadata provider implemented by the host app, e.g. over its token cache.
+///
+/// Called synchronously while rendering, so answer from memory rather than the network.
+#[uniffi::export(with_foreign)]
+pub trait TokenSourceCallback: Send + Sync {
+    /// Metadata of the token at `address` (lowercase `0x`-prefixed) on `chain_id`.
+    /// The returned `chain_id` and `address` are ignored.
+    fn token(
+        &self,
+        chain_id: u64,
+        address: String,
+    ) -> Result<Option<TokenMetaInput>, CallbackError>;
+}
+
+/// Descriptor provider implemented by the host app, e.g. over its own descriptor cache.
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait DescriptorSourceCallback: Send + Sync {
+    /// Descriptor JSON for contract calls to `address` on `chain_id`, if known.
+    async fn calldata_descriptor(
+        &self,
+        chain_id: u64,
+        address: String,
+    ) -> Result<Option<String>, CallbackError>;
+
+    /// Descriptor JSON for EIP-712 messages verified by `address` on `chain_id`, if known.
+    async fn typed_descriptor(
+        &self,
+        chain_id: u64,
+        address: String,
+    ) -> Result<Option<String>, CallbackError>;
+}
+
+/// An address name returned by an [`AddressNameCallback`].
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct AddressNameResult {
+    pub name: String,
+    /// Provenance shown to the user. `Descriptor` is reserved for descriptor
+    /// address books and is downgraded to `Trusted`.
+    pub source: NameSource,
+}
+
+/// Address name provider implemented by the host app: contacts, a name service client.
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait AddressNameCallback: Send + Sync {
+    /// Display name of `address` (lowercase `0x`-prefixed) on `chain_id`, if any.
+    async fn address_name(
+        &self,
+        chain_id: u64,
+        address: String,
+    ) -> Result<Option<AddressNameResult>, CallbackError>;
+}
+
+/// [`TokenSource`] backed by a [`TokenSourceCallback`].
+pub(super) struct CallbackTokenSource(pub(super) Arc<dyn TokenSourceCallback>);
+
+impl TokenSource for CallbackTokenSource {
+    fn lookup(&self, key: &TokenLookupKey) -> Option<TokenMeta> {
+        let (chain_id, address) = key.chain_id().zip(key.address())?;
+        // A failing token callback only costs the token's metadata
+        let token = self.0.token(chain_id, address.to_string()).ok()??;
+        Some(TokenMeta {
+            symbol: token.symbol,
+            decimals: token.decimals,
+            name: token.name,
+            logo_uri: None,
+        })
+    }
+}
+
+/// [`DescriptorSource`] backed by a [`DescriptorSourceCallback`].
+pub(super) struct CallbackDescriptorSource(pub(super) Arc<dyn DescriptorSourceCallback>);
+
+type ResolveFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + 'a>>;
+
+fn resolved(
+    result: Result<Option<String>, CallbackError>,
+    chain_id: u64,
+    address: String,
+) -> Result<ResolvedDescriptor, ResolveError> {
+    let json = result
+        .map_err(|e| ResolveError::Io(format!("descriptor callback failed: {e}")))?
+        .ok_or_else(|| ResolveError::NotFound {
+            chain_id,
+            address: address.clone(),
+        })?;
+    let descriptor =
+        Descriptor::from_json(&json).map_err(|e| ResolveError::Parse(e.to_string()))?;
+    Ok(ResolvedDescriptor {
+        descriptor,
+        chain_id,
+        address,
+        proxy: None,
+    })
+}
+
+impl DescriptorSource for CallbackDescriptorSource {
+    fn resolve_calldata(&self, chain_id: u64, address: &str) -> ResolveFuture<'_> {
+        let address = address.to_lowercase();
+        Box::pin(async move {
+            let result = self.0.calldata_descriptor(chain_id, address.clone()).await;
+            resolved(result, chain_id, address)
+        })
+    }
+
+    fn resolve_typed(&self, chain_id: u64, address: &str) -> ResolveFuture<'_> {
+        let address = address.to_lowercase();
+        Box::pin(async move {
+            let result = self.0.typed_descriptor(chain_id, address.clone()).await;
+            resolved(result, chain_id, address)
+        })
+    }
+}
+
+/// [`AddressNameSource`] backed by an [`AddressNameCallback`].
+pub(super) struct CallbackAddressNameSource(pub(super) Arc<dyn AddressNameCallback>);
+
+impl AddressNameSource for CallbackAddressNameSource {
+    fn resolve_name<'a>(
+        &'a self,
+        chain_id: u64,
+        address: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AddressName>, ResolveError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = self
+                .0
+                .address_name(chain_id, address.to_string())
+                .await
+                .map_err(|e| ResolveError::Io(format!("address name callback failed: {e}")))?;
+            Ok(result.map(|named| AddressName {
+                name: named.name,
+                source: match named.source {
+                    NameSource::Descriptor => NameSource::Trusted,
+                    source => source,
+                },
+                kind: None,
+            }))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedTokens;
+
+    impl TokenSourceCallback for FixedTokens {
+        fn token(
+            &self,
+            chain_id: u64,
+            address: String,
+        ) -> Result<Option<TokenMetaInput>, CallbackError> {
+            match address.as_str() {
+                "0x00000000000000000000000000000000000000aa" => Ok(Some(TokenMetaInput {
+                    chain_id,
+                    address,
+                    symbol: "AAA".to_string(),
+                    decimals: 2,
+                    name: "Token A".to_string(),
+                })),
+                "0x00000000000000000000000000000000000000ee" => Err(CallbackError::Failed {
+                    reason: "cache offline".to_string(),
+                }),
+                _ => Ok(None),
+            }
+        }
+    }
+
+    struct FailingNames;
+
+    #[async_trait::async_trait]
+    impl AddressNameCallback for FailingNames {
+        async fn address_name(
+            &self,
+            _chain_id: u64,
+            _address: String,
+        ) -> Result<Option<AddressNameResult>, CallbackError> {
+            Err(uniffi::UnexpectedUniFFICallbackError::new("IllegalStateException").into())
+        }
+    }
+
+    #[test]
+    fn test_callback_token_failures_read_as_unknown() {
+        let tokens = CallbackTokenSource(Arc::new(FixedTokens));
+        let meta = tokens
+            .lookup(&TokenLookupKey::new(
+                1,
+                "0x00000000000000000000000000000000000000AA",
+            ))
+            .unwrap();
+        assert_eq!((meta.symbol.as_str(), meta.decimals), ("AAA", 2));
+
+        for address in [
+            "0x00000000000000000000000000000000000000ee",
+            "0x00000000000000000000000000000000000000ff",
+        ] {
+            assert!(tokens.lookup(&TokenLookupKey::new(1, address)).is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_callback_unexpected_error_becomes_resolve_error() {
+        let names = CallbackAddressNameSource(Arc::new(FailingNames));
+        let err = names
+            .resolve_name(1, "0x00000000000000000000000000000000000000aa")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "io error: address name callback failed: unexpected callback error: IllegalStateException"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Logging for foreign callers: the library logs through the [`log`] facade, and
+//! [`erc7730_set_logger`] routes those records to an app-implemented [`Logger`].
+//!
+//! Nothing is logged until a logger is installed. FFI log messages never carry
+//! full addresses or amounts; see [`redact`].
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Once, RwLock};
+
+/// Log target of the FFI layer.
+pub(super) const TARGET: &str = "erc7730::ffi";
+
+/// Severity of a log record, from most to least severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => Self::Error,
+            log::Level::Warn => Self::Warn,
+            log::Level::Info => Self::Info,
+            log::Level::Debug => Self::Debug,
+            log::Level::Trace => Self::Trace,
+        }
+    }
+}
+
+impl From<LogLevel> for log::LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => Self::Error,
+            LogLevel::Warn => Self::Warn,
+            LogLevel::Info => Self::Info,
+            LogLevel::Debug => Self::Debug,
+            LogLevel::Trace => Self::Trace,
+        }
+    }
+}
+
+/// Log sink implemented by the host app, e.g. forwarding to `os_log` or `Logcat`.
+#[uniffi::export(with_foreign)]
+pub trait Logger: Send + Sync {
+    /// Handle one record; `target` is the emitting module, e.g. `erc7730::ffi`.
+    fn log(&self, level: LogLevel, target: String, message: String);
+}
+
+static LOGGER: RwLock<Option<Arc<dyn Logger>>> = RwLock::new(None);
+
+/// Forwards [`log`] records to the installed [`Logger`].
+struct Bridge;
+
+impl log::Log for Bridge {
+    /// Only the library's own records are forwarded, not those of its
+    /// dependencies (`reqwest`, `hyper`, ...) that share the global logger.
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        let target = metadata.target();
+        let ours = target == "erc7730" || target.starts_with("erc7730::");
+        ours && LOGGER.read().is_ok_and(|logger| logger.is_some())
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let Some(logger) = LOGGER.read().ok().and_then(|logger| logger.clone()) else {
+            return;
+        };
+        let level = record.level().into();
+        let target = record.target().to_string();
+        let message = record.args().to_string();
+        // A throwing foreign logger panics in the generated glue; never let that
+        // abort the formatting call that happened to log.
+        let _ = catch_unwind(AssertUnwindSafe(|| logger.log(level, target, message)));
+    }
+
+    fn flush(&self) {}
+}
+
+/// Route library logs at `max_level` and above to `logger`; `None` silences them.
+///
+/// Installs the bridge as the process-wide [`log`] logger on first use. If the host
+/// process already installed another Rust logger, records go to that one instead.
+#[uniffi::export]
+pub fn erc7730_set_logger(logger: Option<Arc<dyn Logger>>, max_level: LogLevel) {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let _ = log::set_logger(&Bridge);
+    });
+    let enabled = logger.is_some();
+    if let Ok(mut slot) = LOGGER.write() {
+        *slot = logger;
+    }
+    log::set_max_level(if enabled {
+        max_level.into()
+    } else {
+        log::LevelFilter::Off
+    });
+}
+
+/// Text after which [`redact`] keeps a number.
+const KEPT_AFTER: &[&str] = &["chain_id=", "byte "];
+
+/// Text before which [`redact`] keeps a number.
+const KEPT_BEFORE: &[&str] = &[" byte", " trailing byte"];
+
+/// Abbreviate every address-like `0x` hex run longer than a selector (e.g.
+/// `0xdac17f958d2ee523a2206206994597c13d831ec7` to `0xdac1…1ec7`) and mask decimal
+/// numbers such as amounts (`2.5` to `<number>`), so messages that quote errors or
+/// warnings leak neither counterparties nor values.
+///
+/// Only structural numbers are kept: the chain in `chain_id=1`, byte positions
+/// and lengths (`at byte 36`, `4 bytes`, `2 trailing bytes`) and the widths in
+/// type names such as `uint256`. Any other number, `amount=1000000` included,
+/// is masked.
+pub(super) fn redact(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        out.push_str(&rest[..start]);
+        let run = &rest[start..];
+        if let Some(hex) = run.strip_prefix("0x") {
+            let len = hex
+                .find(|c: char| !c.is_ascii_hexdigit())
+                .unwrap_or(hex.len());
+            if len > 8 {
+                out.push_str(&format!("0x{}…{}", &hex[..4], &hex[len - 4..len]));
+            } else {
+                out.push_str(&run[..2 + len]);
+            }
+            rest = &hex[len..];
+            continue;
+        }
+        let len = run
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .unwrap_or(run.len());
+        // Sentence punctuation after a number is not part of it
+        let len = run[..len].trim_end_matches(['.', ',']).len();
+        let keep = KEPT_AFTER.iter().any(|key| out.ends_with(key))
+            || KEPT_BEFORE.iter().any(|unit| run[len..].starts_with(unit))
+            || out.chars().last().is_some_and(|c| c.is_ascii_alphabetic());
+        if keep {
+            out.push_str(&run[..len]);
+        } else {
+            out.push_str("<number>");
+        }
+        rest = &run[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_redact_abbreviates_addresses_and_masks_amounts() {
+        assert_eq!(
+            redact("descriptor not found for chain_id=1, address=0xdac17f958d2ee523a2206206994597c13d831ec7"),
+            "descriptor not found for chain_id=1, address=0xdac1…1ec7"
+        );
+        assert_eq!(
+            redact("selector=0xa9059cbb, 0x, 0x12"),
+            "selector=0xa9059cbb, 0x, 0x12"
+        );
+        assert_eq!(
+            redact("cannot spend 2,500.75 USDC of 1000000 (uint256, ERC-20). limit exceeded: 4"),
+            "cannot spend <number> USDC of <number> (uint256, ERC-<number>). limit exceeded: <number>"
+        );
+        assert_eq!(
+            redact("invalid argument value: amount=1000000 value=25 token_id-7 fee_3"),
+            "invalid argument value: amount=<number> value=<number> token_id-<number> fee_<number>"
+        );
+        assert_eq!(
+            redact("calldata too short: expected at least 4 bytes, got 3; non-zero padding at byte 36; 2 trailing bytes after the arguments"),
+            "calldata too short: expected at least 4 bytes, got <number>; non-zero padding at byte 36; 2 trailing bytes after the arguments"
+        );
+    }
+
+    #[derive(Default)]
+    struct Recording(Mutex<Vec<(LogLevel, String)>>);
+
+    impl Logger for Recording {
+        fn log(&self, level: LogLevel, _target: String, message: String) {
+            self.0.lock().unwrap().push((level, message));
+        }
+    }
+
+    struct Throwing;
+
+    impl Logger for Throwing {
+        fn log(&self, _level: LogLevel, _target: String, _message: String) {
+            panic!("Callback interface failure: RuntimeException");
+        }
+    }
+
+    #[test]
+    fn test_logger_routing_and_silencing() {
+        let recording = Arc::new(Recording::default());
+        erc7730_set_logger(Some(recording.clone()), LogLevel::Info);
+        log::debug!(target: TARGET, "filtered out");
+        log::warn!(target: TARGET, "kept");
+        log::warn!(target: "hyper::client", "dependency");
+
+        erc7730_set_logger(Some(Arc::new(Throwing)), LogLevel::Trace);
+        log::warn!(target: TARGET, "swallowed");
+
+        erc7730_set_logger(None, LogLevel::Trace);
+        log::error!(target: TARGET, "silenced");
+
+        // Other tests may log concurrently through the same global logger
+        let records = recording.0.lock().unwrap();
+        assert!(records.contains(&(LogLevel::Warn, "kept".to_string())));
+        assert!(!records.iter().any(|(_, message)| [
+            "filtered out",
+            "dependency",
+            "swallowed",
+            "silenced"
+        ]
+        .contains(&message.as_str())));
+    }
+}
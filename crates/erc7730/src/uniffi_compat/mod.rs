@@ -1,3 +1,7 @@
+mod callbacks;
+mod logging;
+mod signer;
+
 use crate::{
     eip712::TypedData,
     error::Error,
@@ -7,29 +11,32 @@ use crate::{
     DisplayModel,
 };
 
-#[cfg(feature = "github-registry")]
-use crate::resolver::GitHubRegistrySource;
+use logging::redact;
 
-#[cfg(feature = "github-registry")]
-use crate::token::{CompositeTokenSource, WellKnownTokenSource};
+pub use callbacks::{
+    AddressNameCallback, AddressNameResult, CallbackError, DescriptorSourceCallback,
+    TokenSourceCallback,
+};
+pub use logging::{erc7730_set_logger, LogLevel, Logger};
+pub use signer::{AddressNameInput, ClearSigner, ClearSignerConfig, SignerOptions};
 
+/// Registry used by the free-standing [`erc7730_format`] and [`erc7730_format_typed`].
 #[cfg(feature = "github-registry")]
-const DEFAULT_REGISTRY_URL: &str =
+pub const DEFAULT_REGISTRY_URL: &str =
     "https://raw.githubusercontent.com/llbartekll/7730-v2-registry/main";
 
+/// Signer behind the free-standing registry functions; apps that need their own
+/// sources should construct a [`ClearSigner`] instead.
 #[cfg(feature = "github-registry")]
-static REGISTRY_SOURCE: tokio::sync::OnceCell<GitHubRegistrySource> =
-    tokio::sync::OnceCell::const_new();
-
-#[cfg(feature = "github-registry")]
-async fn get_registry_source() -> Result<&'static GitHubRegistrySource, FfiError> {
-    REGISTRY_SOURCE
-        .get_or_try_init(|| async {
-            GitHubRegistrySource::from_registry(DEFAULT_REGISTRY_URL)
-                .await
-                .map_err(|e| FfiError::Resolve(format!("failed to initialize registry: {e}")))
+fn default_signer() -> &'static ClearSigner {
+    static DEFAULT: std::sync::OnceLock<ClearSigner> = std::sync::OnceLock::new();
+    DEFAULT.get_or_init(|| {
+        ClearSigner::from_config(ClearSignerConfig {
+            registry_url: Some(DEFAULT_REGISTRY_URL.to_string()),
+            ..ClearSignerConfig::default()
         })
-        .await
+        .expect("default signer configuration is valid")
+    })
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
@@ -41,7 +48,12 @@ pub struct TokenMetaInput {
     pub name: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error, uniffi::Enum)]
+/// Errors surfaced across the FFI boundary.
+///
+/// Flat: foreign bindings receive the variant and its display message, so the
+/// Kotlin `FfiException` and Swift `FfiError` carry a readable `message`.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
 pub enum FfiError {
     #[error("invalid descriptor JSON: {0}")]
     InvalidDescriptorJson(String),
@@ -85,84 +97,36 @@ pub fn erc7730_format_calldata(
     from_address: Option<String>,
     tokens: Vec<TokenMetaInput>,
 ) -> Result<DisplayModel, FfiError> {
-    println!("[erc7730] format_calldata called");
-    println!("[erc7730]   chain_id={}", chain_id);
-    println!("[erc7730]   to={}", to);
-    println!(
-        "[erc7730]   calldata_hex={}",
-        &calldata_hex[..std::cmp::min(20, calldata_hex.len())]
-    );
-    println!("[erc7730]   value_hex={:?}", value_hex);
-    println!("[erc7730]   from_address={:?}", from_address);
-    println!("[erc7730]   tokens count={}", tokens.len());
-    println!(
-        "[erc7730]   descriptor_json length={}",
+    log::debug!(
+        target: logging::TARGET,
+        "format_calldata chain_id={chain_id} to={} tokens={} descriptor_len={}",
+        redact(&to),
+        tokens.len(),
         descriptor_json.len()
     );
-
-    let descriptor = match Descriptor::from_json(&descriptor_json) {
-        Ok(d) => {
-            println!("[erc7730]   descriptor parsed OK");
-            println!(
-                "[erc7730]   format keys: {:?}",
-                d.display.formats.keys().collect::<Vec<_>>()
-            );
-            println!(
-                "[erc7730]   context is_contract={} deployments={:?}",
-                d.context.is_contract(),
-                d.context.deployments()
-            );
-            d
-        }
-        Err(err) => {
-            println!("[erc7730]   descriptor parse FAILED: {}", err);
-            return Err(FfiError::InvalidDescriptorJson(err.to_string()));
-        }
-    };
-
-    let calldata = decode_hex(&calldata_hex, HexContext::Calldata)?;
-    println!("[erc7730]   calldata decoded, {} bytes", calldata.len());
-    if calldata.len() >= 4 {
-        println!("[erc7730]   selector=0x{}", hex::encode(&calldata[..4]));
-    }
-
-    let value = match value_hex {
-        Some(hex_value) => Some(decode_hex(&hex_value, HexContext::Value)?),
-        None => None,
-    };
-    println!(
-        "[erc7730]   value decoded, {} bytes",
-        value.as_ref().map_or(0, |v| v.len())
-    );
-
-    let token_source = build_token_source(&tokens);
-    let result = crate::format_calldata_with_from(
-        &descriptor,
-        chain_id,
-        &to,
-        &calldata,
-        value.as_deref(),
-        from_address.as_deref(),
-        &token_source,
-    );
-
-    match &result {
-        Ok(model) => {
-            println!("[erc7730]   format OK: intent={}", model.intent);
-            println!("[erc7730]   entries count={}", model.entries.len());
-            if let Some(ref interp) = model.interpolated_intent {
-                println!("[erc7730]   interpolated_intent={}", interp);
-            }
-            if !model.warnings.is_empty() {
-                println!("[erc7730]   warnings={:?}", model.warnings);
-            }
-        }
-        Err(err) => {
-            println!("[erc7730]   format FAILED: {}", err);
-        }
-    }
-
-    result.map_err(Into::into)
+    let result = (|| {
+        let descriptor = Descriptor::from_json(&descriptor_json)
+            .map_err(|err| FfiError::InvalidDescriptorJson(err.to_string()))?;
+        let calldata = decode_hex(&calldata_hex, HexContext::Calldata)?;
+        log_calldata(&calldata);
+        let value = match value_hex {
+            Some(hex_value) => Some(decode_hex(&hex_value, HexContext::Value)?),
+            None => None,
+        };
+        let token_source = build_token_source(&tokens);
+        crate::format_calldata_with_from(
+            &descriptor,
+            chain_id,
+            &to,
+            &calldata,
+            value.as_deref(),
+            from_address.as_deref(),
+            &token_source,
+        )
+        .map_err(FfiError::from)
+    })();
+    log_outcome("format_calldata", &result);
+    result
 }
 
 #[uniffi::export]
@@ -171,67 +135,24 @@ pub fn erc7730_format_typed_data(
     typed_data_json: String,
     tokens: Vec<TokenMetaInput>,
 ) -> Result<DisplayModel, FfiError> {
-    println!("[erc7730] format_typed_data called");
-    println!(
-        "[erc7730]   descriptor_json length={}",
-        descriptor_json.len()
-    );
-    println!(
-        "[erc7730]   typed_data_json length={}",
+    log::debug!(
+        target: logging::TARGET,
+        "format_typed_data tokens={} descriptor_len={} typed_data_len={}",
+        tokens.len(),
+        descriptor_json.len(),
         typed_data_json.len()
     );
-    println!("[erc7730]   tokens count={}", tokens.len());
-
-    let descriptor = match Descriptor::from_json(&descriptor_json) {
-        Ok(d) => {
-            println!("[erc7730]   descriptor parsed OK");
-            println!(
-                "[erc7730]   format keys: {:?}",
-                d.display.formats.keys().collect::<Vec<_>>()
-            );
-            d
-        }
-        Err(err) => {
-            println!("[erc7730]   descriptor parse FAILED: {}", err);
-            return Err(FfiError::InvalidDescriptorJson(err.to_string()));
-        }
-    };
-
-    let typed_data: TypedData = match serde_json::from_str::<TypedData>(&typed_data_json) {
-        Ok(td) => {
-            println!(
-                "[erc7730]   typed_data parsed OK, primaryType={}",
-                td.primary_type
-            );
-            println!(
-                "[erc7730]   domain: name={:?} chainId={:?} verifyingContract={:?}",
-                td.domain.name, td.domain.chain_id, td.domain.verifying_contract
-            );
-            td
-        }
-        Err(err) => {
-            println!("[erc7730]   typed_data parse FAILED: {}", err);
-            return Err(FfiError::InvalidTypedDataJson(err.to_string()));
-        }
-    };
-
-    let token_source = build_token_source(&tokens);
-    let result = format_typed_data(&descriptor, &typed_data, &token_source);
-
-    match &result {
-        Ok(model) => {
-            println!("[erc7730]   format OK: intent={}", model.intent);
-            println!("[erc7730]   entries count={}", model.entries.len());
-            if !model.warnings.is_empty() {
-                println!("[erc7730]   warnings={:?}", model.warnings);
-            }
-        }
-        Err(err) => {
-            println!("[erc7730]   format FAILED: {}", err);
-        }
-    }
-
-    result.map_err(Into::into)
+    let result = (|| {
+        let descriptor = Descriptor::from_json(&descriptor_json)
+            .map_err(|err| FfiError::InvalidDescriptorJson(err.to_string()))?;
+        let typed_data: TypedData = serde_json::from_str(&typed_data_json)
+            .map_err(|err| FfiError::InvalidTypedDataJson(err.to_string()))?;
+        log_typed_data(&typed_data);
+        let token_source = build_token_source(&tokens);
+        format_typed_data(&descriptor, &typed_data, &token_source).map_err(FfiError::from)
+    })();
+    log_outcome("format_typed_data", &result);
+    result
 }
 
 /// High-level: resolve descriptor from GitHub registry, then format calldata.
@@ -247,47 +168,17 @@ pub async fn erc7730_format(
     from_address: Option<String>,
     tokens: Vec<TokenMetaInput>,
 ) -> Result<DisplayModel, FfiError> {
-    println!("[erc7730] format called (high-level, registry)");
-    println!("[erc7730]   chain_id={}", chain_id);
-    println!("[erc7730]   to={}", to);
-    println!(
-        "[erc7730]   calldata_hex={}",
-        &calldata_hex[..std::cmp::min(20, calldata_hex.len())]
-    );
-
-    let source = get_registry_source().await?;
-    let calldata = decode_hex(&calldata_hex, HexContext::Calldata)?;
-    let value = match value_hex {
-        Some(hex_value) => Some(decode_hex(&hex_value, HexContext::Value)?),
-        None => None,
-    };
-
     let caller_tokens = build_token_source(&tokens);
-    let well_known = WellKnownTokenSource::new();
-    let composite = CompositeTokenSource::new(vec![Box::new(caller_tokens), Box::new(well_known)]);
-
-    let result = crate::format_with_from(
-        chain_id,
-        &to,
-        &calldata,
-        value.as_deref(),
-        from_address.as_deref(),
-        source,
-        &composite,
-    )
-    .await;
-
-    match &result {
-        Ok(model) => {
-            println!("[erc7730]   format OK: intent={}", model.intent);
-            println!("[erc7730]   entries count={}", model.entries.len());
-        }
-        Err(err) => {
-            println!("[erc7730]   format FAILED: {}", err);
-        }
-    }
-
-    result.map_err(Into::into)
+    default_signer()
+        .render_calldata(
+            chain_id,
+            &to,
+            &calldata_hex,
+            value_hex.as_deref(),
+            from_address.as_deref(),
+            Some(&caller_tokens),
+        )
+        .await
 }
 
 /// High-level: resolve descriptor from GitHub registry, then format EIP-712 typed data.
@@ -299,39 +190,49 @@ pub async fn erc7730_format_typed(
     typed_data_json: String,
     tokens: Vec<TokenMetaInput>,
 ) -> Result<DisplayModel, FfiError> {
-    println!("[erc7730] format_typed called (high-level, registry)");
-    println!(
-        "[erc7730]   typed_data_json length={}",
-        typed_data_json.len()
-    );
-
-    let typed_data: TypedData = serde_json::from_str::<TypedData>(&typed_data_json)
-        .map_err(|e| FfiError::InvalidTypedDataJson(e.to_string()))?;
+    let caller_tokens = build_token_source(&tokens);
+    default_signer()
+        .render_typed_data(&typed_data_json, Some(&caller_tokens))
+        .await
+}
 
-    println!(
-        "[erc7730]   primaryType={} verifyingContract={:?}",
-        typed_data.primary_type, typed_data.domain.verifying_contract
+/// Log the shape of decoded calldata: its selector and size, never its arguments.
+fn log_calldata(calldata: &[u8]) {
+    log::trace!(
+        target: logging::TARGET,
+        "calldata selector=0x{} len={}",
+        hex::encode(&calldata[..calldata.len().min(4)]),
+        calldata.len()
     );
+}
 
-    let source = get_registry_source().await?;
-
-    let caller_tokens = build_token_source(&tokens);
-    let well_known = WellKnownTokenSource::new();
-    let composite = CompositeTokenSource::new(vec![Box::new(caller_tokens), Box::new(well_known)]);
-
-    let result = crate::format_typed(&typed_data, source, &composite).await;
+/// Log the shape of typed data: its primary type and domain chain, never its message.
+fn log_typed_data(typed_data: &TypedData) {
+    log::trace!(
+        target: logging::TARGET,
+        "typed data primary_type={} chain_id={:?}",
+        typed_data.primary_type,
+        typed_data.domain.chain_id
+    );
+}
 
-    match &result {
-        Ok(model) => {
-            println!("[erc7730]   format OK: intent={}", model.intent);
-            println!("[erc7730]   entries count={}", model.entries.len());
-        }
-        Err(err) => {
-            println!("[erc7730]   format FAILED: {}", err);
-        }
+/// Log the outcome of an FFI call without the formatted values.
+fn log_outcome(call: &str, result: &Result<DisplayModel, FfiError>) {
+    match result {
+        Ok(model) => log::debug!(
+            target: logging::TARGET,
+            "{call} ok intent={:?} entries={} warnings={} risks={}",
+            model.intent,
+            model.entries.len(),
+            model.warnings.len(),
+            model.risks.len()
+        ),
+        Err(err) => log::warn!(
+            target: logging::TARGET,
+            "{call} failed: {}",
+            redact(&err.to_string())
+        ),
     }
-
-    result.map_err(Into::into)
 }
 
 enum HexContext {
@@ -340,22 +241,7 @@ enum HexContext {
 }
 
 fn decode_hex(input: &str, context: HexContext) -> Result<Vec<u8>, FfiError> {
-    let trimmed = input.trim();
-    let normalized = trimmed
-        .strip_prefix("0x")
-        .or_else(|| trimmed.strip_prefix("0X"))
-        .unwrap_or(trimmed);
-
-    // Pad odd-length hex strings with a leading zero (e.g. "0x0" → "00")
-    let padded;
-    let hex_str = if normalized.len() % 2 != 0 {
-        padded = format!("0{}", normalized);
-        &padded
-    } else {
-        normalized
-    };
-
-    hex::decode(hex_str).map_err(|err| match context {
+    crate::decoder::decode_hex(input).map_err(|err| match context {
         HexContext::Calldata => FfiError::InvalidCalldataHex(err.to_string()),
         HexContext::Value => FfiError::InvalidValueHex(err.to_string()),
     })
@@ -371,6 +257,7 @@ fn build_token_source(tokens: &[TokenMetaInput]) -> StaticTokenSource {
                 symbol: token.symbol.clone(),
                 decimals: token.decimals,
                 name: token.name.clone(),
+                logo_uri: None,
             },
         );
     }
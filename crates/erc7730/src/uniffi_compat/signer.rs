@@ -0,0 +1,792 @@
+//! [`ClearSigner`]: a configured, reusable clear-signing engine for foreign callers.
+//!
+//! Descriptors, tokens and address names are parsed once at construction; each
+//! instance owns its sources, so several differently configured signers can coexist.
+
+use std::sync::Arc;
+
+use crate::address_book::AddressBook;
+use crate::address_name::{
+    AddressNameSource, CompositeAddressNameSource, ContactsAddressNameSource,
+    StaticAddressNameSource,
+};
+use crate::chain::{ChainSource, CompositeChainSource, StaticChainSource, WellKnownChainSource};
+use crate::decoder::{DecodeLimits, DecodeMode};
+use crate::eip712::TypedData;
+use crate::prefetch::AddressNameBatchLookup;
+use crate::resolver::{
+    CompositeDescriptorSource, DescriptorSource, FilesystemSource, StaticSource,
+};
+use crate::token::{
+    CompositeTokenSource, TokenLookupKey, TokenMeta, TokenSource, WellKnownTokenSource,
+};
+use crate::types::descriptor::Descriptor;
+use crate::{DisplayModel, FormatOptions};
+
+use super::callbacks::{
+    AddressNameCallback, CallbackAddressNameSource, CallbackDescriptorSource, CallbackTokenSource,
+    DescriptorSourceCallback, TokenSourceCallback,
+};
+use super::logging::{self, redact};
+use super::{
+    build_token_source, decode_hex, log_calldata, log_outcome, log_typed_data, FfiError,
+    HexContext, TokenMetaInput,
+};
+
+/// A caller-supplied address name.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct AddressNameInput {
+    /// Chain the name applies to; `None` for a contact valid on every chain.
+    pub chain_id: Option<u64>,
+    pub address: String,
+    pub name: String,
+}
+
+/// Decoding and address-poisoning settings for a [`ClearSigner`]; unset values
+/// keep the library defaults.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct SignerOptions {
+    /// Whether non-canonical calldata is rejected or rendered with warnings.
+    pub decode_mode: DecodeMode,
+    /// Maximum nesting of arrays and tuples in calldata.
+    #[uniffi(default)]
+    pub max_decode_depth: Option<u32>,
+    /// Maximum number of decoded calldata values.
+    #[uniffi(default)]
+    pub max_decoded_elements: Option<u64>,
+    /// Maximum number of bytes copied into decoded calldata values.
+    #[uniffi(default)]
+    pub max_decoded_bytes: Option<u64>,
+    /// Leading and trailing hex characters an address must share with a known
+    /// address to be flagged as a look-alike.
+    #[uniffi(default)]
+    pub lookalike_chars: Option<u32>,
+}
+
+/// Configuration for a [`ClearSigner`].
+///
+/// Descriptor sources are tried in order: in-memory `descriptors`, then
+/// `descriptor_source`, then `descriptor_directory`, then the registry at `registry_url`.
+#[derive(Clone, uniffi::Record)]
+pub struct ClearSignerConfig {
+    /// Registry base URL (raw content URL of a registry repository).
+    /// Requires the `github-registry` feature.
+    #[uniffi(default)]
+    pub registry_url: Option<String>,
+    /// Local directory of descriptor JSON files, searched recursively.
+    #[uniffi(default)]
+    pub descriptor_directory: Option<String>,
+    /// Descriptor JSON documents, indexed by their deployments.
+    #[uniffi(default)]
+    pub descriptors: Vec<String>,
+    /// Token metadata, consulted before the built-in token list.
+    #[uniffi(default)]
+    pub tokens: Vec<TokenMetaInput>,
+    /// Whether to fall back to the built-in well-known token list.
+    #[uniffi(default = true)]
+    pub use_well_known_tokens: bool,
+    /// Trusted names and contacts; also used for address-poisoning detection.
+    #[uniffi(default)]
+    pub address_names: Vec<AddressNameInput>,
+    /// Chainlist-style JSON array of extra chains, consulted before the built-in list.
+    #[uniffi(default)]
+    pub chains_json: Option<String>,
+    /// App-implemented token provider, consulted after `tokens`.
+    #[uniffi(default)]
+    pub token_source: Option<Arc<dyn TokenSourceCallback>>,
+    /// App-implemented descriptor provider, consulted after `descriptors`.
+    #[uniffi(default)]
+    pub descriptor_source: Option<Arc<dyn DescriptorSourceCallback>>,
+    /// App-implemented name provider, consulted after `address_names`.
+    #[uniffi(default)]
+    pub address_name_source: Option<Arc<dyn AddressNameCallback>>,
+    /// Decoding limits and look-alike settings; `None` keeps the defaults.
+    #[uniffi(default)]
+    pub options: Option<SignerOptions>,
+}
+
+impl Default for ClearSignerConfig {
+    fn default() -> Self {
+        Self {
+            registry_url: None,
+            descriptor_directory: None,
+            descriptors: Vec::new(),
+            tokens: Vec::new(),
+            use_well_known_tokens: true,
+            address_names: Vec::new(),
+            chains_json: None,
+            token_source: None,
+            descriptor_source: None,
+            address_name_source: None,
+            options: None,
+        }
+    }
+}
+
+/// Configured clear-signing engine.
+#[derive(uniffi::Object)]
+pub struct ClearSigner {
+    source: Box<dyn DescriptorSource>,
+    tokens: Box<dyn TokenSource>,
+    names: AddressNameBatchLookup,
+    known_addresses: AddressBook,
+    chains: Box<dyn ChainSource>,
+    decode_mode: DecodeMode,
+    decode_limits: DecodeLimits,
+    lookalike_chars: Option<usize>,
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl ClearSigner {
+    /// Build a signer, parsing every configured descriptor, token and name up front.
+    #[uniffi::constructor]
+    pub fn new(config: ClearSignerConfig) -> Result<Arc<Self>, FfiError> {
+        Self::from_config(config).map(Arc::new)
+    }
+
+    /// Resolve a descriptor for `to` and format contract calldata.
+    pub async fn format_calldata(
+        &self,
+        chain_id: u64,
+        to: String,
+        calldata_hex: String,
+        value_hex: Option<String>,
+        from_address: Option<String>,
+    ) -> Result<DisplayModel, FfiError> {
+        self.render_calldata(
+            chain_id,
+            &to,
+            &calldata_hex,
+            value_hex.as_deref(),
+            from_address.as_deref(),
+            None,
+        )
+        .await
+    }
+
+    /// Resolve a descriptor for the verifying contract and format EIP-712 typed data.
+    pub async fn format_typed_data(
+        &self,
+        typed_data_json: String,
+    ) -> Result<DisplayModel, FfiError> {
+        self.render_typed_data(&typed_data_json, None).await
+    }
+}
+
+impl ClearSigner {
+    pub(super) fn from_config(config: ClearSignerConfig) -> Result<Self, FfiError> {
+        let mut sources: Vec<Box<dyn DescriptorSource>> = Vec::new();
+        if !config.descriptors.is_empty() {
+            let mut embedded = StaticSource::new();
+            for json in &config.descriptors {
+                let descriptor = Descriptor::from_json(json)
+                    .map_err(|e| FfiError::InvalidDescriptorJson(e.to_string()))?;
+                embedded.add_descriptor(descriptor);
+            }
+            sources.push(Box::new(embedded));
+        }
+        if let Some(ref callback) = config.descriptor_source {
+            sources.push(Box::new(CallbackDescriptorSource(callback.clone())));
+        }
+        if let Some(ref dir) = config.descriptor_directory {
+            let local = FilesystemSource::from_directory(std::path::Path::new(dir))
+                .map_err(|e| FfiError::Resolve(format!("failed to load {dir}: {e}")))?;
+            sources.push(Box::new(local));
+        }
+        if let Some(ref url) = config.registry_url {
+            sources.push(registry_source(url)?);
+        }
+
+        let mut tokens: Vec<Box<dyn TokenSource + Send + Sync>> =
+            vec![Box::new(build_token_source(&config.tokens))];
+        if let Some(ref callback) = config.token_source {
+            tokens.push(Box::new(CallbackTokenSource(callback.clone())));
+        }
+        if config.use_well_known_tokens {
+            tokens.push(Box::new(WellKnownTokenSource::new()));
+        }
+
+        let mut trusted = StaticAddressNameSource::new();
+        let mut contacts = ContactsAddressNameSource::new();
+        let mut known_addresses = AddressBook::empty();
+        for entry in &config.address_names {
+            match entry.chain_id {
+                Some(chain_id) => trusted.insert(chain_id, &entry.address, &entry.name),
+                None => contacts.insert(&entry.address, &entry.name),
+            }
+            known_addresses.insert(entry.address.clone(), entry.name.clone());
+        }
+
+        // Contacts are the user's own; they rank above the trusted list
+        let mut names: Vec<Box<dyn AddressNameSource>> =
+            vec![Box::new(contacts), Box::new(trusted)];
+        if let Some(ref callback) = config.address_name_source {
+            names.push(Box::new(CallbackAddressNameSource(callback.clone())));
+        }
+
+        let mut chains: Vec<Box<dyn ChainSource + Send + Sync>> = Vec::new();
+        if let Some(ref json) = config.chains_json {
+            let custom = StaticChainSource::from_json(json.as_bytes()).map_err(FfiError::from)?;
+            chains.push(Box::new(custom));
+        }
+        chains.push(Box::new(WellKnownChainSource::new()));
+
+        let defaults = DecodeLimits::default();
+        let options = config.options.as_ref();
+        let limit = |value: Option<u64>, default: usize| {
+            value.map_or(default, |v| usize::try_from(v).unwrap_or(usize::MAX))
+        };
+        let decode_limits = DecodeLimits {
+            max_depth: limit(
+                options.and_then(|o| o.max_decode_depth).map(u64::from),
+                defaults.max_depth,
+            ),
+            max_elements: limit(
+                options.and_then(|o| o.max_decoded_elements),
+                defaults.max_elements,
+            ),
+            max_bytes: limit(
+                options.and_then(|o| o.max_decoded_bytes),
+                defaults.max_bytes,
+            ),
+        };
+
+        Ok(Self {
+            source: Box::new(CompositeDescriptorSource::new(sources)),
+            tokens: Box::new(CompositeTokenSource::new(tokens)),
+            names: AddressNameBatchLookup::new(Box::new(CompositeAddressNameSource::new(names))),
+            known_addresses,
+            chains: Box::new(CompositeChainSource::new(chains)),
+            decode_mode: options.map(|o| o.decode_mode).unwrap_or_default(),
+            decode_limits,
+            lookalike_chars: options
+                .and_then(|o| o.lookalike_chars)
+                .map(|chars| chars as usize),
+        })
+    }
+
+    pub(super) async fn render_calldata(
+        &self,
+        chain_id: u64,
+        to: &str,
+        calldata_hex: &str,
+        value_hex: Option<&str>,
+        from_address: Option<&str>,
+        extra_tokens: Option<&dyn TokenSource>,
+    ) -> Result<DisplayModel, FfiError> {
+        log::debug!(
+            target: logging::TARGET,
+            "signer format_calldata chain_id={chain_id} to={}",
+            redact(to)
+        );
+        let result = self
+            .calldata_model(
+                chain_id,
+                to,
+                calldata_hex,
+                value_hex,
+                from_address,
+                extra_tokens,
+            )
+            .await;
+        log_outcome("signer format_calldata", &result);
+        result
+    }
+
+    pub(super) async fn render_typed_data(
+        &self,
+        typed_data_json: &str,
+        extra_tokens: Option<&dyn TokenSource>,
+    ) -> Result<DisplayModel, FfiError> {
+        log::debug!(
+            target: logging::TARGET,
+            "signer format_typed_data typed_data_len={}",
+            typed_data_json.len()
+        );
+        let result = self.typed_data_model(typed_data_json, extra_tokens).await;
+        log_outcome("signer format_typed_data", &result);
+        result
+    }
+
+    async fn calldata_model(
+        &self,
+        chain_id: u64,
+        to: &str,
+        calldata_hex: &str,
+        value_hex: Option<&str>,
+        from_address: Option<&str>,
+        extra_tokens: Option<&dyn TokenSource>,
+    ) -> Result<DisplayModel, FfiError> {
+        let calldata = decode_hex(calldata_hex, HexContext::Calldata)?;
+        log_calldata(&calldata);
+        let value = match value_hex {
+            Some(hex_value) => Some(decode_hex(hex_value, HexContext::Value)?),
+            None => None,
+        };
+        crate::format_with_options(
+            chain_id,
+            to,
+            &calldata,
+            value.as_deref(),
+            self.source.as_ref(),
+            &self.layered_tokens(extra_tokens),
+            &self.format_options(from_address),
+        )
+        .await
+        .map_err(Into::into)
+    }
+
+    async fn typed_data_model(
+        &self,
+        typed_data_json: &str,
+        extra_tokens: Option<&dyn TokenSource>,
+    ) -> Result<DisplayModel, FfiError> {
+        let typed_data: TypedData = serde_json::from_str(typed_data_json)
+            .map_err(|e| FfiError::InvalidTypedDataJson(e.to_string()))?;
+        log_typed_data(&typed_data);
+        crate::format_typed_with_options(
+            &typed_data,
+            self.source.as_ref(),
+            &self.layered_tokens(extra_tokens),
+            &self.format_options(None),
+        )
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Options carrying this signer's names, chains, known addresses and settings.
+    fn format_options<'a>(&'a self, from: Option<&'a str>) -> FormatOptions<'a> {
+        FormatOptions {
+            from,
+            lookups: Some(&self.names),
+            chains: Some(self.chains.as_ref()),
+            known_addresses: Some(&self.known_addresses),
+            lookalike_chars: self.lookalike_chars,
+            decode_mode: self.decode_mode,
+            decode_limits: self.decode_limits,
+            ..FormatOptions::default()
+        }
+    }
+
+    /// Per-call tokens (from the free-standing FFI functions) layered over the
+    /// configured ones.
+    fn layered_tokens<'a>(&'a self, extra: Option<&'a dyn TokenSource>) -> LayeredTokens<'a> {
+        LayeredTokens {
+            extra,
+            configured: self.tokens.as_ref(),
+        }
+    }
+}
+
+struct LayeredTokens<'a> {
+    extra: Option<&'a dyn TokenSource>,
+    configured: &'a dyn TokenSource,
+}
+
+impl TokenSource for LayeredTokens<'_> {
+    fn lookup(&self, key: &TokenLookupKey) -> Option<TokenMeta> {
+        self.extra
+            .and_then(|extra| extra.lookup(key))
+            .or_else(|| self.configured.lookup(key))
+    }
+}
+
+#[cfg(feature = "github-registry")]
+fn registry_source(url: &str) -> Result<Box<dyn DescriptorSource>, FfiError> {
+    Ok(Box::new(registry::LazyRegistrySource::new(url)))
+}
+
+#[cfg(not(feature = "github-registry"))]
+fn registry_source(_url: &str) -> Result<Box<dyn DescriptorSource>, FfiError> {
+    Err(FfiError::Resolve(
+        "registry_url requires the github-registry feature".to_string(),
+    ))
+}
+
+#[cfg(feature = "github-registry")]
+mod registry {
+    use std::future::Future;
+    use std::pin::Pin;
+
+    use crate::error::ResolveError;
+    use crate::resolver::{DescriptorSource, GitHubRegistrySource, ResolvedDescriptor};
+
+    /// Registry source whose index is fetched on first use, so constructing a
+    /// signer never blocks on the network.
+    pub(super) struct LazyRegistrySource {
+        url: String,
+        source: tokio::sync::OnceCell<GitHubRegistrySource>,
+    }
+
+    impl LazyRegistrySource {
+        pub(super) fn new(url: &str) -> Self {
+            Self {
+                url: url.to_string(),
+                source: tokio::sync::OnceCell::new(),
+            }
+        }
+
+        async fn get(&self) -> Result<&GitHubRegistrySource, ResolveError> {
+            self.source
+                .get_or_try_init(|| GitHubRegistrySource::from_registry(&self.url))
+                .await
+        }
+    }
+
+    impl DescriptorSource for LazyRegistrySource {
+        fn resolve_calldata(
+            &self,
+            chain_id: u64,
+            address: &str,
+        ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>>
+        {
+            let address = address.to_string();
+            Box::pin(async move { self.get().await?.resolve_calldata(chain_id, &address).await })
+        }
+
+        fn resolve_typed(
+            &self,
+            chain_id: u64,
+            address: &str,
+        ) -> Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + '_>>
+        {
+            let address = address.to_string();
+            Box::pin(async move { self.get().await?.resolve_typed(chain_id, &address).await })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address_name::NameSource;
+    use crate::uniffi_compat::{AddressNameResult, CallbackError};
+    use crate::DisplayEntry;
+
+    const USDT: &str = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+    const RECIPIENT: &str = "0x00000000000000000000000000000000000000b0";
+
+    fn transfer_descriptor() -> String {
+        format!(
+            r#"{{
+            "context": {{"contract": {{"deployments": [{{"chainId": 1, "address": "{USDT}"}}]}}}},
+            "metadata": {{"owner": "test", "contractName": "Tether USD"}},
+            "display": {{"formats": {{"transfer(address,uint256)": {{
+                "intent": "Send",
+                "fields": [
+                    {{"path": "@.0", "label": "To", "format": "addressName"}},
+                    {{"path": "@.1", "label": "Amount", "format": "tokenAmount", "params": {{"tokenPath": "@.to"}}}}
+                ]
+            }}}}}}
+        }}"#
+        )
+    }
+
+    fn transfer_calldata() -> String {
+        format!(
+            "0xa9059cbb{:0>64}{:064x}",
+            RECIPIENT.trim_start_matches("0x"),
+            2_500_000u64
+        )
+    }
+
+    fn items(model: &DisplayModel) -> Vec<(String, String, Option<NameSource>)> {
+        model
+            .entries
+            .iter()
+            .filter_map(|e| match e {
+                DisplayEntry::Item(i) => Some((i.label.clone(), i.value.clone(), i.name_source)),
+                DisplayEntry::Group { .. } => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_signer_formats_with_configured_state() {
+        let signer = ClearSigner::new(ClearSignerConfig {
+            descriptors: vec![transfer_descriptor()],
+            address_names: vec![AddressNameInput {
+                chain_id: None,
+                address: RECIPIENT.to_string(),
+                name: "Savings".to_string(),
+            }],
+            ..ClearSignerConfig::default()
+        })
+        .unwrap();
+
+        let model = signer
+            .format_calldata(1, USDT.to_string(), transfer_calldata(), None, None)
+            .await
+            .unwrap();
+        assert_eq!(model.intent, "Send");
+        assert_eq!(
+            items(&model),
+            vec![
+                (
+                    "To".to_string(),
+                    "Savings".to_string(),
+                    Some(NameSource::Contact)
+                ),
+                ("Amount".to_string(), "2.5 USDT".to_string(), None),
+            ]
+        );
+
+        // A second signer without descriptors or tokens is independent
+        let bare = ClearSigner::new(ClearSignerConfig {
+            use_well_known_tokens: false,
+            ..ClearSignerConfig::default()
+        })
+        .unwrap();
+        let model = bare
+            .format_calldata(1, USDT.to_string(), transfer_calldata(), None, None)
+            .await
+            .unwrap();
+        assert_eq!(model.intent, "transfer(address,uint256)");
+        assert!(!model.warnings.iter().any(|w| w.contains("ERC-20 template")));
+    }
+
+    #[tokio::test]
+    async fn test_signer_applies_names_and_options_to_every_path() {
+        let reactor = "0x00000000000000000000000000000000000000c1";
+        let signer = ClearSigner::new(ClearSignerConfig {
+            descriptors: vec![
+                transfer_descriptor(),
+                format!(
+                    r#"{{
+                    "context": {{"eip712": {{"deployments": [{{"chainId": 1, "address": "{reactor}"}}]}}}},
+                    "metadata": {{"owner": "test"}},
+                    "display": {{"formats": {{"Order": {{"intent": "Order", "fields": [
+                        {{"path": "recipient", "label": "Recipient", "format": "addressName"}}
+                    ]}}}}}}
+                }}"#
+                ),
+            ],
+            address_names: vec![AddressNameInput {
+                chain_id: None,
+                address: RECIPIENT.to_string(),
+                name: "Savings".to_string(),
+            }],
+            options: Some(SignerOptions {
+                decode_mode: DecodeMode::Strict,
+                max_decode_depth: None,
+                max_decoded_elements: None,
+                max_decoded_bytes: None,
+                lookalike_chars: None,
+            }),
+            ..ClearSignerConfig::default()
+        })
+        .unwrap();
+
+        // Typed data is named through the signer's contacts
+        let typed = format!(
+            r#"{{
+            "types": {{"Order": [{{"name": "recipient", "type": "address"}}]}},
+            "primaryType": "Order",
+            "domain": {{"chainId": 1, "verifyingContract": "{reactor}"}},
+            "message": {{"recipient": "{RECIPIENT}"}}
+        }}"#
+        );
+        let model = signer.format_typed_data(typed).await.unwrap();
+        assert_eq!(
+            items(&model),
+            vec![(
+                "Recipient".to_string(),
+                "Savings".to_string(),
+                Some(NameSource::Contact)
+            )]
+        );
+
+        // Strict decoding rejects trailing bytes
+        let err = signer
+            .format_calldata(1, USDT.to_string(), transfer_calldata() + "00", None, None)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, FfiError::Decode(_)), "{err:?}");
+    }
+
+    struct AppDescriptors;
+
+    #[async_trait::async_trait]
+    impl DescriptorSourceCallback for AppDescriptors {
+        async fn calldata_descriptor(
+            &self,
+            chain_id: u64,
+            address: String,
+        ) -> Result<Option<String>, CallbackError> {
+            Ok((chain_id == 1 && address == USDT).then(transfer_descriptor))
+        }
+
+        async fn typed_descriptor(
+            &self,
+            _chain_id: u64,
+            _address: String,
+        ) -> Result<Option<String>, CallbackError> {
+            Err(CallbackError::Failed {
+                reason: "offline".to_string(),
+            })
+        }
+    }
+
+    struct AppTokens;
+
+    impl TokenSourceCallback for AppTokens {
+        fn token(
+            &self,
+            chain_id: u64,
+            address: String,
+        ) -> Result<Option<TokenMetaInput>, CallbackError> {
+            Ok(Some(TokenMetaInput {
+                chain_id,
+                address,
+                symbol: "APP".to_string(),
+                decimals: 6,
+                name: "App token".to_string(),
+            }))
+        }
+    }
+
+    struct AppNames;
+
+    #[async_trait::async_trait]
+    impl AddressNameCallback for AppNames {
+        async fn address_name(
+            &self,
+            _chain_id: u64,
+            address: String,
+        ) -> Result<Option<AddressNameResult>, CallbackError> {
+            Ok((address == RECIPIENT).then(|| AddressNameResult {
+                name: "alice.eth".to_string(),
+                source: NameSource::Descriptor,
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signer_uses_app_callbacks() {
+        let signer = ClearSigner::new(ClearSignerConfig {
+            use_well_known_tokens: false,
+            descriptor_source: Some(Arc::new(AppDescriptors)),
+            token_source: Some(Arc::new(AppTokens)),
+            address_name_source: Some(Arc::new(AppNames)),
+            ..ClearSignerConfig::default()
+        })
+        .unwrap();
+
+        let model = signer
+            .format_calldata(1, USDT.to_string(), transfer_calldata(), None, None)
+            .await
+            .unwrap();
+        assert_eq!(model.intent, "Send");
+        assert_eq!(
+            items(&model),
+            vec![
+                (
+                    "To".to_string(),
+                    "alice.eth".to_string(),
+                    Some(NameSource::Trusted)
+                ),
+                ("Amount".to_string(), "2.5 APP".to_string(), None),
+            ]
+        );
+
+        let typed = r#"{
+            "types": {"Mail": [{"name": "contents", "type": "string"}]},
+            "primaryType": "Mail",
+            "domain": {"chainId": 1, "verifyingContract": "0x0000000000000000000000000000000000000001"},
+            "message": {"contents": "hello"}
+        }"#;
+        let err = signer
+            .format_typed_data(typed.to_string())
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            FfiError::Resolve("io error: descriptor callback failed: offline".to_string())
+        );
+    }
+
+    struct OfflineNames;
+
+    #[async_trait::async_trait]
+    impl AddressNameCallback for OfflineNames {
+        async fn address_name(
+            &self,
+            _chain_id: u64,
+            _address: String,
+        ) -> Result<Option<AddressNameResult>, CallbackError> {
+            Err(CallbackError::Failed {
+                reason: "offline".to_string(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signer_keeps_contact_names_when_callback_fails() {
+        let sender = "0x00000000000000000000000000000000000000c2";
+        let signer = ClearSigner::new(ClearSignerConfig {
+            descriptors: vec![format!(
+                r#"{{
+                "context": {{"contract": {{"deployments": [{{"chainId": 1, "address": "{USDT}"}}]}}}},
+                "metadata": {{"owner": "test"}},
+                "display": {{"formats": {{"transferFrom(address,address,uint256)": {{
+                    "intent": "Send",
+                    "fields": [
+                        {{"path": "@.0", "label": "From", "format": "addressName"}},
+                        {{"path": "@.1", "label": "To", "format": "addressName"}}
+                    ]
+                }}}}}}
+            }}"#
+            )],
+            address_names: vec![AddressNameInput {
+                chain_id: None,
+                address: RECIPIENT.to_string(),
+                name: "Savings".to_string(),
+            }],
+            address_name_source: Some(Arc::new(OfflineNames)),
+            ..ClearSignerConfig::default()
+        })
+        .unwrap();
+
+        let calldata = format!(
+            "0x23b872dd{:0>64}{:0>64}{:064x}",
+            sender.trim_start_matches("0x"),
+            RECIPIENT.trim_start_matches("0x"),
+            1u64
+        );
+        let model = signer
+            .format_calldata(1, USDT.to_string(), calldata, None, None)
+            .await
+            .unwrap();
+        let entries = items(&model);
+        assert_eq!(
+            entries[1],
+            (
+                "To".to_string(),
+                "Savings".to_string(),
+                Some(NameSource::Contact)
+            )
+        );
+        assert_eq!(entries[0].2, None);
+    }
+
+    #[test]
+    fn test_signer_rejects_invalid_config() {
+        let err = ClearSigner::new(ClearSignerConfig {
+            descriptors: vec!["{".to_string()],
+            ..ClearSignerConfig::default()
+        })
+        .err()
+        .unwrap();
+        assert!(matches!(err, FfiError::InvalidDescriptorJson(_)));
+
+        let err = ClearSigner::new(ClearSignerConfig {
+            descriptor_directory: Some("/nonexistent/descriptors".to_string()),
+            ..ClearSignerConfig::default()
+        })
+        .err()
+        .unwrap();
+        assert!(matches!(err, FfiError::Resolve(_)));
+    }
+}
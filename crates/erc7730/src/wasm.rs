@@ -0,0 +1,392 @@
+//! WebAssembly bindings for browsers and Node (`wasm` feature, `wasm32` targets).
+//!
+//! Display models cross the boundary as plain JS objects with camelCase keys.
+//! JS code can plug in its own token and descriptor sources through
+//! [`ClearSigner`].
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::eip712::TypedData;
+use crate::error::ResolveError;
+use crate::resolver::{
+    CompositeDescriptorSource, DescriptorSource, ResolvedDescriptor, StaticSource,
+};
+use crate::token::{
+    CompositeTokenSource, StaticTokenSource, TokenLookupKey, TokenMeta, TokenSource,
+    WellKnownTokenSource,
+};
+use crate::types::descriptor::Descriptor;
+use crate::DisplayModel;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &str = include_str!("wasm_types.d.ts");
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "DisplayModel")]
+    pub type JsDisplayModel;
+
+    #[wasm_bindgen(typescript_type = "TokenInput[]")]
+    pub type JsTokenInputs;
+
+    #[wasm_bindgen(typescript_type = "ClearSignerConfig")]
+    pub type JsClearSignerConfig;
+
+    #[wasm_bindgen(typescript_type = "TokenSource")]
+    pub type JsTokenSource;
+
+    #[wasm_bindgen(method, catch, js_name = token)]
+    fn token(this: &JsTokenSource, chain_id: f64, address: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(typescript_type = "DescriptorSource")]
+    pub type JsDescriptorSource;
+
+    #[wasm_bindgen(method, catch, js_name = calldataDescriptor)]
+    fn calldata_descriptor(
+        this: &JsDescriptorSource,
+        chain_id: f64,
+        address: &str,
+    ) -> Result<js_sys::Promise, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = typedDescriptor)]
+    fn typed_descriptor(
+        this: &JsDescriptorSource,
+        chain_id: f64,
+        address: &str,
+    ) -> Result<js_sys::Promise, JsValue>;
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenInput {
+    chain_id: u64,
+    address: String,
+    symbol: String,
+    decimals: u8,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenMetaOutput {
+    symbol: String,
+    decimals: u8,
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignerConfig {
+    #[serde(default)]
+    descriptors: Vec<String>,
+    #[serde(default)]
+    tokens: Vec<TokenInput>,
+    #[serde(default = "default_true")]
+    use_well_known_tokens: bool,
+    #[serde(default, with = "serde_wasm_bindgen::preserve")]
+    token_source: JsValue,
+    #[serde(default, with = "serde_wasm_bindgen::preserve")]
+    descriptor_source: JsValue,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Format contract calldata with a single descriptor.
+#[wasm_bindgen(js_name = formatCalldata)]
+pub fn format_calldata(
+    #[wasm_bindgen(js_name = "descriptorJson")] descriptor_json: &str,
+    #[wasm_bindgen(js_name = "chainId")] chain_id: f64,
+    to: &str,
+    #[wasm_bindgen(js_name = "calldataHex")] calldata_hex: &str,
+    #[wasm_bindgen(js_name = "valueHex")] value_hex: Option<String>,
+    #[wasm_bindgen(js_name = "fromAddress")] from_address: Option<String>,
+    tokens: Option<JsTokenInputs>,
+) -> Result<JsDisplayModel, JsError> {
+    let descriptor = parse_descriptor(descriptor_json)?;
+    let chain_id = chain_id_from_js(chain_id)?;
+    let calldata = decode_hex(calldata_hex, "calldata")?;
+    let value = value_hex
+        .map(|value| decode_hex(&value, "value"))
+        .transpose()?;
+    let tokens = token_source(tokens)?;
+    let model = crate::format_calldata_with_from(
+        &descriptor,
+        chain_id,
+        to,
+        &calldata,
+        value.as_deref(),
+        from_address.as_deref(),
+        &tokens,
+    )?;
+    to_js(&model)
+}
+
+/// Format EIP-712 typed data (JSON) with a single descriptor.
+#[wasm_bindgen(js_name = formatTypedData)]
+pub fn format_typed_data(
+    #[wasm_bindgen(js_name = "descriptorJson")] descriptor_json: &str,
+    #[wasm_bindgen(js_name = "typedDataJson")] typed_data_json: &str,
+    tokens: Option<JsTokenInputs>,
+) -> Result<JsDisplayModel, JsError> {
+    let descriptor = parse_descriptor(descriptor_json)?;
+    let typed_data = parse_typed_data(typed_data_json)?;
+    let tokens = token_source(tokens)?;
+    let model = crate::format_typed_data(&descriptor, &typed_data, &tokens)?;
+    to_js(&model)
+}
+
+/// Problems found in a descriptor; empty when it is usable.
+#[wasm_bindgen(js_name = validateDescriptor)]
+pub fn validate_descriptor(
+    #[wasm_bindgen(js_name = "descriptorJson")] descriptor_json: &str,
+) -> Vec<String> {
+    match Descriptor::from_json(descriptor_json) {
+        Ok(descriptor) => descriptor.validate(),
+        Err(e) => vec![format!("invalid descriptor JSON: {e}")],
+    }
+}
+
+/// Configured clear-signing engine resolving descriptors per call.
+#[wasm_bindgen]
+pub struct ClearSigner {
+    source: CompositeDescriptorSource,
+    tokens: CompositeTokenSource,
+}
+
+#[wasm_bindgen]
+impl ClearSigner {
+    #[wasm_bindgen(constructor)]
+    pub fn new(config: JsClearSignerConfig) -> Result<ClearSigner, JsError> {
+        let config: SignerConfig = serde_wasm_bindgen::from_value(config.into())?;
+
+        let mut sources: Vec<Box<dyn DescriptorSource>> = Vec::new();
+        let mut embedded = StaticSource::new();
+        for json in &config.descriptors {
+            embedded.add_descriptor(parse_descriptor(json)?);
+        }
+        sources.push(Box::new(embedded));
+        if !config.descriptor_source.is_undefined() && !config.descriptor_source.is_null() {
+            sources.push(Box::new(JsDescriptors(SingleThreaded(
+                config.descriptor_source.unchecked_into(),
+            ))));
+        }
+
+        let mut tokens: Vec<Box<dyn TokenSource + Send + Sync>> =
+            vec![Box::new(static_tokens(&config.tokens))];
+        if !config.token_source.is_undefined() && !config.token_source.is_null() {
+            tokens.push(Box::new(JsTokens(SingleThreaded(
+                config.token_source.unchecked_into(),
+            ))));
+        }
+        if config.use_well_known_tokens {
+            tokens.push(Box::new(WellKnownTokenSource::new()));
+        }
+
+        Ok(Self {
+            source: CompositeDescriptorSource::new(sources),
+            tokens: CompositeTokenSource::new(tokens),
+        })
+    }
+
+    /// Resolve a descriptor for `to` and format contract calldata.
+    #[wasm_bindgen(js_name = formatCalldata)]
+    pub async fn format_calldata(
+        &self,
+        #[wasm_bindgen(js_name = "chainId")] chain_id: f64,
+        to: String,
+        #[wasm_bindgen(js_name = "calldataHex")] calldata_hex: String,
+        #[wasm_bindgen(js_name = "valueHex")] value_hex: Option<String>,
+        #[wasm_bindgen(js_name = "fromAddress")] from_address: Option<String>,
+    ) -> Result<JsDisplayModel, JsError> {
+        let chain_id = chain_id_from_js(chain_id)?;
+        let calldata = decode_hex(&calldata_hex, "calldata")?;
+        let value = value_hex
+            .map(|value| decode_hex(&value, "value"))
+            .transpose()?;
+        let options = crate::FormatOptions {
+            from: from_address.as_deref(),
+            ..crate::FormatOptions::default()
+        };
+        let model = crate::format_with_options(
+            chain_id,
+            &to,
+            &calldata,
+            value.as_deref(),
+            &self.source,
+            &self.tokens,
+            &options,
+        )
+        .await?;
+        to_js(&model)
+    }
+
+    /// Resolve a descriptor for the verifying contract and format EIP-712 typed data.
+    #[wasm_bindgen(js_name = formatTypedData)]
+    pub async fn format_typed_data(
+        &self,
+        #[wasm_bindgen(js_name = "typedDataJson")] typed_data_json: String,
+    ) -> Result<JsDisplayModel, JsError> {
+        let typed_data = parse_typed_data(&typed_data_json)?;
+        let model = crate::format_typed(&typed_data, &self.source, &self.tokens).await?;
+        to_js(&model)
+    }
+}
+
+/// Wrapper asserting `Send`/`Sync` for JS handles.
+///
+/// The engine's source traits require both, but JS values are tied to their
+/// thread. `wasm32-unknown-unknown` without atomics has a single thread, so
+/// these values never actually move between threads.
+struct SingleThreaded<T>(T);
+
+// SAFETY: this module only builds for wasm32, where there is one thread.
+unsafe impl<T> Send for SingleThreaded<T> {}
+// SAFETY: as above.
+unsafe impl<T> Sync for SingleThreaded<T> {}
+
+impl<F: Future + Unpin> Future for SingleThreaded<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+/// [`TokenSource`] backed by a JS `TokenSource` object.
+struct JsTokens(SingleThreaded<JsTokenSource>);
+
+impl TokenSource for JsTokens {
+    fn lookup(&self, key: &TokenLookupKey) -> Option<TokenMeta> {
+        let (chain_id, address) = key.chain_id().zip(key.address())?;
+        // A throwing or malformed token source only costs the token's metadata
+        let value = self.0 .0.token(chain_id as f64, address).ok()?;
+        if value.is_null() || value.is_undefined() {
+            return None;
+        }
+        let meta: TokenMetaOutput = serde_wasm_bindgen::from_value(value).ok()?;
+        Some(TokenMeta {
+            symbol: meta.symbol,
+            decimals: meta.decimals,
+            name: meta.name,
+            logo_uri: None,
+        })
+    }
+}
+
+/// [`DescriptorSource`] backed by a JS `DescriptorSource` object.
+struct JsDescriptors(SingleThreaded<JsDescriptorSource>);
+
+type ResolveFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<ResolvedDescriptor, ResolveError>> + Send + 'a>>;
+
+impl JsDescriptors {
+    fn resolve(
+        &self,
+        promise: Result<js_sys::Promise, JsValue>,
+        chain_id: u64,
+        address: String,
+    ) -> ResolveFuture<'_> {
+        let future = promise.map(|promise| SingleThreaded(JsFuture::from(promise)));
+        let future = SingleThreaded(Box::pin(async move {
+            let value = future.map_err(js_error)?.await.map_err(js_error)?;
+            let Some(json) = value.as_string() else {
+                return Err(ResolveError::NotFound {
+                    chain_id,
+                    address: address.clone(),
+                });
+            };
+            let descriptor =
+                Descriptor::from_json(&json).map_err(|e| ResolveError::Parse(e.to_string()))?;
+            Ok(ResolvedDescriptor {
+                descriptor,
+                chain_id,
+                address,
+                proxy: None,
+            })
+        }));
+        Box::pin(future)
+    }
+}
+
+impl DescriptorSource for JsDescriptors {
+    fn resolve_calldata(&self, chain_id: u64, address: &str) -> ResolveFuture<'_> {
+        let address = address.to_lowercase();
+        let promise = self.0 .0.calldata_descriptor(chain_id as f64, &address);
+        self.resolve(promise, chain_id, address)
+    }
+
+    fn resolve_typed(&self, chain_id: u64, address: &str) -> ResolveFuture<'_> {
+        let address = address.to_lowercase();
+        let promise = self.0 .0.typed_descriptor(chain_id as f64, &address);
+        self.resolve(promise, chain_id, address)
+    }
+}
+
+fn js_error(value: JsValue) -> ResolveError {
+    let message = value
+        .dyn_ref::<js_sys::Error>()
+        .map(|e| String::from(e.message()))
+        .or_else(|| value.as_string())
+        .unwrap_or_else(|| "unknown error".to_string());
+    ResolveError::Io(format!("descriptor source failed: {message}"))
+}
+
+fn parse_descriptor(json: &str) -> Result<Descriptor, JsError> {
+    Descriptor::from_json(json).map_err(|e| JsError::new(&format!("invalid descriptor JSON: {e}")))
+}
+
+fn parse_typed_data(json: &str) -> Result<TypedData, JsError> {
+    serde_json::from_str(json).map_err(|e| JsError::new(&format!("invalid typed data JSON: {e}")))
+}
+
+/// JS numbers are exact up to 2^53, which covers every registered chain ID.
+fn chain_id_from_js(chain_id: f64) -> Result<u64, JsError> {
+    if chain_id.fract() != 0.0 || !(0.0..=9_007_199_254_740_991.0).contains(&chain_id) {
+        return Err(JsError::new(&format!("invalid chain ID: {chain_id}")));
+    }
+    Ok(chain_id as u64)
+}
+
+fn decode_hex(input: &str, what: &str) -> Result<Vec<u8>, JsError> {
+    crate::decoder::decode_hex(input).map_err(|e| JsError::new(&format!("invalid {what} hex: {e}")))
+}
+
+fn token_source(tokens: Option<JsTokenInputs>) -> Result<StaticTokenSource, JsError> {
+    let Some(tokens) = tokens else {
+        return Ok(StaticTokenSource::new());
+    };
+    let tokens: Vec<TokenInput> = serde_wasm_bindgen::from_value(tokens.into())?;
+    Ok(static_tokens(&tokens))
+}
+
+fn static_tokens(tokens: &[TokenInput]) -> StaticTokenSource {
+    let mut source = StaticTokenSource::new();
+    for token in tokens {
+        source.insert(
+            token.chain_id,
+            &token.address,
+            TokenMeta {
+                symbol: token.symbol.clone(),
+                decimals: token.decimals,
+                name: token.name.clone(),
+                logo_uri: None,
+            },
+        );
+    }
+    source
+}
+
+fn to_js(model: &DisplayModel) -> Result<JsDisplayModel, JsError> {
+    // JSON-compatible: plain objects rather than `Map`s, numbers rather than `BigInt`s
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    Ok(model.serialize(&serializer)?.unchecked_into())
+}
@@ -0,0 +1,72 @@
+export interface TokenInput {
+    chainId: number;
+    address: string;
+    symbol: string;
+    decimals: number;
+    name: string;
+}
+
+/** Token metadata provider; called synchronously while rendering. */
+export interface TokenSource {
+    token(chainId: number, address: string): Omit<TokenInput, "chainId" | "address"> | null | undefined;
+}
+
+/** Descriptor provider; resolves to descriptor JSON, or null when unknown. */
+export interface DescriptorSource {
+    calldataDescriptor(chainId: number, address: string): Promise<string | null | undefined>;
+    typedDescriptor(chainId: number, address: string): Promise<string | null | undefined>;
+}
+
+export interface ClearSignerConfig {
+    /** Descriptor JSON documents, indexed by their deployments. */
+    descriptors?: string[];
+    /** Token metadata, consulted before `tokenSource` and the built-in list. */
+    tokens?: TokenInput[];
+    /** Whether to fall back to the built-in token list (default true). */
+    useWellKnownTokens?: boolean;
+    tokenSource?: TokenSource;
+    /** Consulted after `descriptors`. */
+    descriptorSource?: DescriptorSource;
+}
+
+export type NameSource = "descriptor" | "contact" | "trusted" | "ens";
+export type WarningSeverity = "info" | "warning" | "danger";
+
+export interface FieldWarning {
+    kind:
+        | { type: "addressTypeMismatch" }
+        | { type: "lookAlikeAddress"; knownAddress: string; knownLabel: string };
+    severity: WarningSeverity;
+    message: string;
+}
+
+export interface DisplayItem {
+    label: string;
+    value: string;
+    nameSource: NameSource | null;
+    warnings: FieldWarning[];
+}
+
+export type DisplayEntry =
+    | ({ type: "item" } & DisplayItem)
+    | { type: "group"; label: string; iteration: "sequential" | "bundled"; items: DisplayItem[] };
+
+export interface RiskFinding {
+    kind:
+        | "unlimitedAllowance"
+        | "largeAllowance"
+        | "approvalForAll"
+        | "spenderIsEoa"
+        | "unknownSpender"
+        | "longLivedPermit";
+    severity: WarningSeverity;
+    message: string;
+}
+
+export interface DisplayModel {
+    intent: string;
+    interpolatedIntent: string | null;
+    entries: DisplayEntry[];
+    warnings: string[];
+    risks: RiskFinding[];
+}
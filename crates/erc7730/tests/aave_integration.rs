@@ -1,6 +1,6 @@
 //! Integration tests using real Aave v2/v3 registry descriptors.
 
-use erc7730::decoder::parse_signature;
+use erc7730::decoder::{ArgumentValue, CalldataBuilder};
 use erc7730::token::{CompositeTokenSource, StaticTokenSource, TokenMeta, WellKnownTokenSource};
 use erc7730::types::descriptor::Descriptor;
 use erc7730::{format_calldata_with_from, DisplayEntry, DisplayModel};
@@ -11,38 +11,6 @@ fn load_descriptor(fixture: &str) -> Descriptor {
     Descriptor::from_json(&json).unwrap_or_else(|e| panic!("parse {path}: {e}"))
 }
 
-fn build_calldata(selector: &[u8; 4], args: &[Vec<u8>]) -> Vec<u8> {
-    let mut data = Vec::with_capacity(4 + args.len() * 32);
-    data.extend_from_slice(selector);
-    for arg in args {
-        data.extend_from_slice(arg);
-    }
-    data
-}
-
-fn address_word(hex_addr: &str) -> Vec<u8> {
-    let hex_str = hex_addr
-        .strip_prefix("0x")
-        .or_else(|| hex_addr.strip_prefix("0X"))
-        .unwrap_or(hex_addr);
-    let addr_bytes = hex::decode(hex_str).expect("valid hex address");
-    let mut word = vec![0u8; 12];
-    word.extend_from_slice(&addr_bytes);
-    assert_eq!(word.len(), 32);
-    word
-}
-
-fn uint_word(val: u128) -> Vec<u8> {
-    let mut word = vec![0u8; 16];
-    word.extend_from_slice(&val.to_be_bytes());
-    assert_eq!(word.len(), 32);
-    word
-}
-
-fn max_uint256_word() -> Vec<u8> {
-    vec![0xff; 32]
-}
-
 fn aave_token_source() -> CompositeTokenSource {
     let mut custom = StaticTokenSource::new();
     // Aave uses these token addresses in tests
@@ -53,6 +21,7 @@ fn aave_token_source() -> CompositeTokenSource {
             symbol: "USDC".to_string(),
             decimals: 6,
             name: "USD Coin".to_string(),
+            logo_uri: None,
         },
     );
     custom.insert(
@@ -62,6 +31,7 @@ fn aave_token_source() -> CompositeTokenSource {
             symbol: "DAI".to_string(),
             decimals: 18,
             name: "Dai Stablecoin".to_string(),
+            logo_uri: None,
         },
     );
     custom.insert(
@@ -71,6 +41,7 @@ fn aave_token_source() -> CompositeTokenSource {
             symbol: "USDT".to_string(),
             decimals: 6,
             name: "Tether USD".to_string(),
+            logo_uri: None,
         },
     );
     custom.insert(
@@ -80,6 +51,7 @@ fn aave_token_source() -> CompositeTokenSource {
             symbol: "USDC".to_string(),
             decimals: 6,
             name: "USD Coin".to_string(),
+            logo_uri: None,
         },
     );
     CompositeTokenSource::new(vec![
@@ -103,21 +75,18 @@ fn get_entry_value(model: &DisplayModel, label: &str) -> String {
 #[test]
 fn aave_supply_usdc_mainnet() {
     let descriptor = load_descriptor("aave-lpv3.json");
-    let sig = parse_signature("supply(address,uint256,address,uint16)").unwrap();
     let tokens = aave_token_source();
 
     let usdc_addr = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
     let on_behalf = "1111111111111111111111111111111111111111";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(usdc_addr),
-            uint_word(1_000_000_000), // 1000 USDC (6 decimals)
-            address_word(on_behalf),
-            uint_word(0), // referralCode
-        ],
-    );
+    let calldata = CalldataBuilder::new("supply(address,uint256,address,uint16)")
+        .address(usdc_addr)
+        .uint(1_000_000_000) // 1000 USDC (6 decimals)
+        .address(on_behalf)
+        .uint(0) // referralCode
+        .build()
+        .unwrap();
 
     let result = format_calldata_with_from(
         &descriptor,
@@ -137,21 +106,18 @@ fn aave_supply_usdc_mainnet() {
 #[test]
 fn aave_supply_usdc_base() {
     let descriptor = load_descriptor("aave-lpv3.json");
-    let sig = parse_signature("supply(address,uint256,address,uint16)").unwrap();
     let tokens = aave_token_source();
 
     let usdc_base = "833589fcd6edb6e08f4c7c32d4f71b54bda02913";
     let on_behalf = "2222222222222222222222222222222222222222";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(usdc_base),
-            uint_word(500_000_000), // 500 USDC
-            address_word(on_behalf),
-            uint_word(0),
-        ],
-    );
+    let calldata = CalldataBuilder::new("supply(address,uint256,address,uint16)")
+        .address(usdc_base)
+        .uint(500_000_000) // 500 USDC
+        .address(on_behalf)
+        .uint(0)
+        .build()
+        .unwrap();
 
     let result = format_calldata_with_from(
         &descriptor,
@@ -171,21 +137,18 @@ fn aave_supply_usdc_base() {
 #[test]
 fn aave_repay_all_dai() {
     let descriptor = load_descriptor("aave-lpv3.json");
-    let sig = parse_signature("repay(address,uint256,uint256,address)").unwrap();
     let tokens = aave_token_source();
 
     let dai_addr = "6b175474e89094c44da98b954eedeac495271d0f";
     let on_behalf = "3333333333333333333333333333333333333333";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(dai_addr),
-            max_uint256_word(), // max uint256 = "All"
-            uint_word(2),       // variable rate
-            address_word(on_behalf),
-        ],
-    );
+    let calldata = CalldataBuilder::new("repay(address,uint256,uint256,address)")
+        .address(dai_addr)
+        .arg(ArgumentValue::Uint(vec![0xff; 32])) // max uint256 = "All"
+        .uint(2) // variable rate
+        .address(on_behalf)
+        .build()
+        .unwrap();
 
     let result = format_calldata_with_from(
         &descriptor,
@@ -206,20 +169,17 @@ fn aave_repay_all_dai() {
 #[test]
 fn aave_withdraw_max() {
     let descriptor = load_descriptor("aave-lpv3.json");
-    let sig = parse_signature("withdraw(address,uint256,address)").unwrap();
     let tokens = aave_token_source();
 
     let usdc_addr = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
     let to_addr = "4444444444444444444444444444444444444444";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(usdc_addr),
-            max_uint256_word(), // max = "Max"
-            address_word(to_addr),
-        ],
-    );
+    let calldata = CalldataBuilder::new("withdraw(address,uint256,address)")
+        .address(usdc_addr)
+        .arg(ArgumentValue::Uint(vec![0xff; 32])) // max = "Max"
+        .address(to_addr)
+        .build()
+        .unwrap();
 
     let result = format_calldata_with_from(
         &descriptor,
@@ -239,22 +199,19 @@ fn aave_withdraw_max() {
 #[test]
 fn aave_borrow_variable() {
     let descriptor = load_descriptor("aave-lpv3.json");
-    let sig = parse_signature("borrow(address,uint256,uint256,uint16,address)").unwrap();
     let tokens = aave_token_source();
 
     let usdt_addr = "dac17f958d2ee523a2206206994597c13d831ec7";
     let on_behalf = "5555555555555555555555555555555555555555";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(usdt_addr),
-            uint_word(5_000_000), // 5 USDT
-            uint_word(2),         // variable rate
-            uint_word(0),         // referralCode
-            address_word(on_behalf),
-        ],
-    );
+    let calldata = CalldataBuilder::new("borrow(address,uint256,uint256,uint16,address)")
+        .address(usdt_addr)
+        .uint(5_000_000) // 5 USDT
+        .uint(2) // variable rate
+        .uint(0) // referralCode
+        .address(on_behalf)
+        .build()
+        .unwrap();
 
     let result = format_calldata_with_from(
         &descriptor,
@@ -275,18 +232,15 @@ fn aave_borrow_variable() {
 #[test]
 fn aave_set_collateral() {
     let descriptor = load_descriptor("aave-lpv3.json");
-    let sig = parse_signature("setUserUseReserveAsCollateral(address,bool)").unwrap();
     let tokens = aave_token_source();
 
     let usdc_addr = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(usdc_addr),
-            uint_word(1), // true
-        ],
-    );
+    let calldata = CalldataBuilder::new("setUserUseReserveAsCollateral(address,bool)")
+        .address(usdc_addr)
+        .bool(true)
+        .build()
+        .unwrap();
 
     let result = format_calldata_with_from(
         &descriptor,
@@ -308,21 +262,18 @@ fn aave_set_collateral() {
 #[test]
 fn aave_deposit_usdc_mainnet() {
     let descriptor = load_descriptor("aave-lpv2.json");
-    let sig = parse_signature("deposit(address,uint256,address,uint16)").unwrap();
     let tokens = aave_token_source();
 
     let usdc_addr = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
     let on_behalf = "1111111111111111111111111111111111111111";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(usdc_addr),
-            uint_word(1_000_000_000), // 1000 USDC
-            address_word(on_behalf),
-            uint_word(0),
-        ],
-    );
+    let calldata = CalldataBuilder::new("deposit(address,uint256,address,uint16)")
+        .address(usdc_addr)
+        .uint(1_000_000_000) // 1000 USDC
+        .address(on_behalf)
+        .uint(0)
+        .build()
+        .unwrap();
 
     let result = format_calldata_with_from(
         &descriptor,
@@ -344,23 +295,20 @@ fn aave_deposit_usdc_mainnet() {
 #[test]
 fn gateway_deposit_eth() {
     let descriptor = load_descriptor("aave-gateway.json");
-    let sig = parse_signature("depositETH(address,address,uint16)").unwrap();
     let tokens = aave_token_source();
 
     let pool_addr = "87870bca3f3fd6335c3f4ce8392d69350b4fa4e2";
     let on_behalf = "6666666666666666666666666666666666666666";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(pool_addr),
-            address_word(on_behalf),
-            uint_word(0),
-        ],
-    );
+    let calldata = CalldataBuilder::new("depositETH(address,address,uint16)")
+        .address(pool_addr)
+        .address(on_behalf)
+        .uint(0)
+        .build()
+        .unwrap();
 
     // 1 ETH = 10^18 wei
-    let value = uint_word(1_000_000_000_000_000_000);
+    let value = 1_000_000_000_000_000_000u128.to_be_bytes();
 
     let result = format_calldata_with_from(
         &descriptor,
@@ -382,19 +330,16 @@ fn gateway_deposit_eth() {
 #[test]
 fn gateway_borrow_eth_with_from() {
     let descriptor = load_descriptor("aave-gateway.json");
-    let sig = parse_signature("borrowETH(address,uint256,uint16)").unwrap();
     let tokens = aave_token_source();
 
     let pool_addr = "87870bca3f3fd6335c3f4ce8392d69350b4fa4e2";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(pool_addr),
-            uint_word(500_000_000_000_000_000), // 0.5 ETH
-            uint_word(0),
-        ],
-    );
+    let calldata = CalldataBuilder::new("borrowETH(address,uint256,uint16)")
+        .address(pool_addr)
+        .uint(500_000_000_000_000_000) // 0.5 ETH
+        .uint(0)
+        .build()
+        .unwrap();
 
     let from_addr = "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
 
@@ -467,21 +412,18 @@ fn real_wallet_supply_eth_mainnet() {
 #[test]
 fn aave_interpolated_intent() {
     let descriptor = load_descriptor("aave-lpv3.json");
-    let sig = parse_signature("supply(address,uint256,address,uint16)").unwrap();
     let tokens = aave_token_source();
 
     let usdc_addr = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
     let on_behalf = "1111111111111111111111111111111111111111";
 
-    let calldata = build_calldata(
-        &sig.selector,
-        &[
-            address_word(usdc_addr),
-            uint_word(1_000_000_000), // 1000 USDC
-            address_word(on_behalf),
-            uint_word(0),
-        ],
-    );
+    let calldata = CalldataBuilder::new("supply(address,uint256,address,uint16)")
+        .address(usdc_addr)
+        .uint(1_000_000_000) // 1000 USDC
+        .address(on_behalf)
+        .uint(0)
+        .build()
+        .unwrap();
 
     let result = format_calldata_with_from(
         &descriptor,
@@ -0,0 +1,105 @@
+//! Property tests: every `ParamType` value survives `encode_calldata` → `decode_calldata`,
+//! and the encoding passes strict decoding.
+
+use erc7730::decoder::{
+    decode_calldata, decode_calldata_with_mode, encode_calldata, parse_signature, ArgumentValue,
+    DecodeMode, ParamType,
+};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+fn param_type() -> impl Strategy<Value = ParamType> {
+    let leaf = prop_oneof![
+        Just(ParamType::Address),
+        (1..=32usize).prop_map(|n| ParamType::Uint(n * 8)),
+        (1..=32usize).prop_map(|n| ParamType::Int(n * 8)),
+        Just(ParamType::Bool),
+        Just(ParamType::Bytes),
+        (1..=32usize).prop_map(ParamType::FixedBytes),
+        Just(ParamType::String),
+    ];
+    leaf.prop_recursive(3, 24, 4, |inner| {
+        prop_oneof![
+            inner.clone().prop_map(|t| ParamType::Array(Box::new(t))),
+            (inner.clone(), 0..4usize).prop_map(|(t, n)| ParamType::FixedArray(Box::new(t), n)),
+            vec(inner, 0..4).prop_map(ParamType::Tuple),
+        ]
+    })
+}
+
+/// `bytes` as the decoder returns them: a 32-byte word extended with `fill`.
+fn word(bytes: Vec<u8>, fill: u8) -> Vec<u8> {
+    let mut word = vec![fill; 32 - bytes.len()];
+    word.extend(bytes);
+    word
+}
+
+fn value(param: &ParamType) -> BoxedStrategy<ArgumentValue> {
+    match param {
+        ParamType::Address => any::<[u8; 20]>().prop_map(ArgumentValue::Address).boxed(),
+        ParamType::Uint(bits) => vec(any::<u8>(), bits / 8)
+            .prop_map(|bytes| ArgumentValue::Uint(word(bytes, 0)))
+            .boxed(),
+        ParamType::Int(bits) => vec(any::<u8>(), bits / 8)
+            .prop_map(|bytes| {
+                let fill = if bytes[0] & 0x80 != 0 { 0xff } else { 0 };
+                ArgumentValue::Int(word(bytes, fill))
+            })
+            .boxed(),
+        ParamType::Bool => any::<bool>().prop_map(ArgumentValue::Bool).boxed(),
+        ParamType::Bytes => vec(any::<u8>(), 0..80)
+            .prop_map(ArgumentValue::Bytes)
+            .boxed(),
+        ParamType::FixedBytes(size) => vec(any::<u8>(), *size)
+            .prop_map(ArgumentValue::FixedBytes)
+            .boxed(),
+        ParamType::String => any::<String>().prop_map(ArgumentValue::String).boxed(),
+        ParamType::Array(inner) => vec(value(inner), 0..4)
+            .prop_map(ArgumentValue::Array)
+            .boxed(),
+        ParamType::FixedArray(inner, len) => vec(value(inner), *len)
+            .prop_map(ArgumentValue::Array)
+            .boxed(),
+        ParamType::Tuple(members) => members
+            .iter()
+            .map(value)
+            .collect::<Vec<_>>()
+            .prop_map(ArgumentValue::Tuple)
+            .boxed(),
+    }
+}
+
+fn call() -> impl Strategy<Value = (Vec<ParamType>, Vec<ArgumentValue>)> {
+    vec(param_type(), 0..5).prop_flat_map(|params| {
+        let values: Vec<_> = params.iter().map(value).collect();
+        (Just(params), values)
+    })
+}
+
+proptest! {
+    #[test]
+    fn encoded_calls_decode_to_the_same_values((params, args) in call()) {
+        let canonical: Vec<String> = params.iter().map(ParamType::canonical).collect();
+        let sig = parse_signature(&format!("f({})", canonical.join(","))).unwrap();
+        prop_assert_eq!(&sig.params, &params);
+
+        let calldata = encode_calldata(&sig, &args).unwrap();
+        prop_assert_eq!((calldata.len() - 4) % 32, 0);
+        let decoded = decode_calldata_with_mode(&sig, &calldata, DecodeMode::Strict).unwrap();
+        prop_assert!(decoded.warnings.is_empty());
+        let lenient = decode_calldata(&sig, &calldata).unwrap();
+        prop_assert!(lenient.warnings.is_empty());
+        for decoded in [decoded, lenient] {
+            let values: Vec<ArgumentValue> = decoded.args.into_iter().map(|arg| arg.value).collect();
+            prop_assert_eq!(&values, &args);
+        }
+    }
+
+    #[test]
+    fn oversized_uints_are_rejected(bits in (1..32usize).prop_map(|n| n * 8), extra in 1..=255u8) {
+        let sig = parse_signature(&format!("f(uint{bits})")).unwrap();
+        let mut value = vec![0u8; 32];
+        value[31 - bits / 8] = extra;
+        prop_assert!(encode_calldata(&sig, &[ArgumentValue::Uint(value)]).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+/*
+ * Exercises the C ABI through include/erc7730.h: harness <fixtures dir>
+ * Built and run by tests/c_abi.rs.
+ */
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "erc7730.h"
+
+#define USDT "0xdac17f958d2ee523a2206206994597c13d831ec7"
+#define AAVE_POOL "0x87870Bca3F3fD6335C3F4ce8392D69350B4fA4E2"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                                \
+    do {                                                                           \
+        if (!(cond)) {                                                             \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+            failures++;                                                            \
+        }                                                                          \
+    } while (0)
+
+static void expect_contains(char *json, const char *needle) {
+    if (json == NULL) {
+        fprintf(stderr, "unexpected error: %s\n", erc7730_last_error());
+        failures++;
+        return;
+    }
+    if (strstr(json, needle) == NULL) {
+        fprintf(stderr, "expected %s in %s\n", needle, json);
+        failures++;
+    }
+}
+
+static const char *TRANSFER_DESCRIPTOR =
+    "{\"context\":{\"contract\":{\"deployments\":[{\"chainId\":1,\"address\":\"" USDT "\"}]}},"
+    "\"metadata\":{\"owner\":\"test\",\"contractName\":\"Tether USD\"},"
+    "\"display\":{\"formats\":{\"transfer(address,uint256)\":{\"intent\":\"Send\",\"fields\":["
+    "{\"path\":\"@.0\",\"label\":\"To\",\"format\":\"address\"},"
+    "{\"path\":\"@.1\",\"label\":\"Amount\",\"format\":\"tokenAmount\","
+    "\"params\":{\"tokenPath\":\"@.to\"}}]}}}}";
+
+/* transfer(0x…b0, 2.5 USDT) */
+static const char *TRANSFER_CALLDATA =
+    "0xa9059cbb"
+    "00000000000000000000000000000000000000000000000000000000000000b0"
+    "00000000000000000000000000000000000000000000000000000000002625a0";
+
+/* supply(USDC, 1000 USDC, 0x1111…, 0) */
+static const char *SUPPLY_CALLDATA =
+    "0x617ba037"
+    "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+    "000000000000000000000000000000000000000000000000000000003b9aca00"
+    "0000000000000000000000001111111111111111111111111111111111111111"
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+static const char *TYPED_DATA =
+    "{\"types\":{\"Mail\":[{\"name\":\"contents\",\"type\":\"string\"}]},"
+    "\"primaryType\":\"Mail\","
+    "\"domain\":{\"chainId\":1,\"verifyingContract\":\"0x0000000000000000000000000000000000000001\"},"
+    "\"message\":{\"contents\":\"hello\"}}";
+
+static void test_engine_from_json(void) {
+    Erc7730Engine *engine = erc7730_engine_from_json(TRANSFER_DESCRIPTOR);
+    CHECK(engine != NULL);
+    CHECK(erc7730_last_error() == NULL);
+
+    char *json = erc7730_format_calldata(engine, 1, USDT, TRANSFER_CALLDATA, NULL, NULL);
+    expect_contains(json, "\"intent\":\"Send\"");
+    expect_contains(json, "\"value\":\"2.5 USDT\"");
+    erc7730_string_free(json);
+
+    /* No descriptor for the verifying contract: raw preview */
+    json = erc7730_format_typed_data(engine, TYPED_DATA);
+    expect_contains(json, "\"entries\":[");
+    erc7730_string_free(json);
+
+    erc7730_engine_free(engine);
+}
+
+static void test_engine_from_directory(const char *fixtures) {
+    Erc7730Engine *engine = erc7730_engine_from_directory(fixtures);
+    CHECK(engine != NULL);
+
+    char *json = erc7730_format_calldata(engine, 1, AAVE_POOL, SUPPLY_CALLDATA, "0x0", NULL);
+    expect_contains(json, "\"intent\":\"Supply\"");
+    expect_contains(json, "1000 USDC");
+    erc7730_string_free(json);
+
+    erc7730_engine_free(engine);
+}
+
+static void test_errors(void) {
+    CHECK(erc7730_engine_from_json("{") == NULL);
+    CHECK(erc7730_last_error() != NULL);
+    CHECK(strncmp(erc7730_last_error(), "invalid descriptor JSON", 23) == 0);
+
+    CHECK(erc7730_engine_from_directory("/nonexistent/erc7730") == NULL);
+    CHECK(erc7730_last_error() != NULL);
+
+    Erc7730Engine *engine = erc7730_engine_from_json(TRANSFER_DESCRIPTOR);
+    CHECK(erc7730_format_calldata(engine, 1, USDT, "0xzz", NULL, NULL) == NULL);
+    CHECK(strncmp(erc7730_last_error(), "invalid calldata hex", 20) == 0);
+    CHECK(erc7730_format_typed_data(engine, NULL) == NULL);
+    CHECK(strcmp(erc7730_last_error(), "typed_data_json is NULL") == 0);
+    CHECK(erc7730_format_calldata(NULL, 1, USDT, TRANSFER_CALLDATA, NULL, NULL) == NULL);
+    CHECK(strcmp(erc7730_last_error(), "engine is NULL") == 0);
+
+    erc7730_engine_free(engine);
+    erc7730_engine_free(NULL);
+    erc7730_string_free(NULL);
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "usage: %s <fixtures dir>\n", argv[0]);
+        return 2;
+    }
+    test_engine_from_json();
+    test_engine_from_directory(argv[1]);
+    test_errors();
+    if (failures > 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+    printf("C ABI harness OK\n");
+    return 0;
+}
@@ -0,0 +1,73 @@
+//! Builds the C harness in `tests/c/` against the committed header and the
+//! `cdylib`, runs it, and checks the header is what cbindgen generates from `src/capi.rs`.
+
+#![cfg(all(feature = "capi", target_os = "linux"))]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+const HEADER: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/include/erc7730.h"));
+
+/// `target/<profile>/deps`, where cargo puts both this test and `liberc7730.so`.
+fn deps_dir() -> PathBuf {
+    let exe = std::env::current_exe().expect("test executable path");
+    exe.parent().expect("deps directory").to_path_buf()
+}
+
+#[test]
+fn c_harness_runs_against_cdylib() {
+    let deps = deps_dir();
+    assert!(
+        deps.join("liberc7730.so").exists(),
+        "liberc7730.so not found in {}",
+        deps.display()
+    );
+    let harness = deps.join("erc7730_c_harness");
+    let crate_dir = Path::new(CRATE_DIR);
+
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".to_string()))
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-o"])
+        .arg(&harness)
+        .arg(crate_dir.join("tests/c/harness.c"))
+        .arg(format!("-I{}", crate_dir.join("include").display()))
+        .arg(format!("-L{}", deps.display()))
+        .arg("-lerc7730")
+        .arg(format!("-Wl,-rpath,{}", deps.display()))
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success(), "compiling tests/c/harness.c failed");
+
+    // cargo's LD_LIBRARY_PATH also lists target/<profile>, whose uplifted copy may be
+    // from a build with other features; load the library next to this test via rpath
+    let output = Command::new(&harness)
+        .env_remove("LD_LIBRARY_PATH")
+        .arg(crate_dir.join("tests/fixtures"))
+        .output()
+        .expect("failed to run the C harness");
+    assert!(
+        output.status.success(),
+        "C harness failed:\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("C ABI harness OK"));
+}
+
+#[test]
+fn header_matches_cbindgen_output() {
+    let crate_dir = Path::new(CRATE_DIR);
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("failed to read cbindgen.toml");
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(crate_dir.join("src/capi.rs"))
+        .generate()
+        .expect("cbindgen failed on src/capi.rs")
+        .write(&mut generated);
+    assert!(
+        generated == HEADER.as_bytes(),
+        "include/erc7730.h is out of date; run scripts/generate-c-header.sh"
+    );
+}
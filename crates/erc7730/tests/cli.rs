@@ -0,0 +1,68 @@
+//! Runs `erc7730-cli compact` against the Aave fixtures.
+
+#![cfg(feature = "std")]
+
+use std::process::Command;
+
+use erc7730::compact::CompactDescriptor;
+
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/aave-lpv3.json");
+
+fn cli(args: &[&str]) -> (bool, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_erc7730-cli"))
+        .args(args)
+        .output()
+        .expect("failed to run erc7730-cli");
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn compact_reports_and_writes_one_format() {
+    let out = std::env::temp_dir().join(format!("erc7730-cli-{}.bin", std::process::id()));
+    let out_path = out.to_str().unwrap();
+    let (ok, stdout, stderr) = cli(&[
+        "compact",
+        FIXTURE,
+        "supply(address,uint256,address,uint16)",
+        "--chain",
+        "1",
+        "--token",
+        "1:0xA0b86991c6218b36c1D19D4a2e9Eb0cE3606eB48:USDC:6:USD Coin",
+        "--out",
+        out_path,
+    ]);
+    assert!(ok, "{stderr}");
+    assert!(stdout.contains("descriptor "), "{stdout}");
+    for section in ["header", "context", "format", "metadata", "tokens"] {
+        assert!(stdout.contains(section), "{stdout}");
+    }
+
+    let bytes = std::fs::read(&out).unwrap();
+    std::fs::remove_file(&out).ok();
+    let compact = CompactDescriptor::decode(&bytes).unwrap();
+    assert!(compact.format_key.starts_with("supply(address asset"));
+    assert_eq!(compact.deployments.len(), 1);
+    assert_eq!(compact.tokens.len(), 1);
+    assert!(
+        stdout.contains(&format!("compact     {} bytes", bytes.len())),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn compact_lists_every_format() {
+    let (ok, stdout, stderr) = cli(&["compact", FIXTURE]);
+    assert!(ok, "{stderr}");
+    assert_eq!(stdout.lines().filter(|line| line.contains('%')).count(), 8);
+}
+
+#[test]
+fn compact_rejects_unknown_format() {
+    let (ok, _, stderr) = cli(&["compact", FIXTURE, "nope()"]);
+    assert!(!ok);
+    assert!(stderr.contains("no display format matches"), "{stderr}");
+}
@@ -0,0 +1,266 @@
+//! JVM-free smoke tests of the UniFFI scaffolding: calls the exported C symbols
+//! with arguments lowered the way the generated Kotlin bindings lower them, and
+//! checks the committed Kotlin and Swift wrappers against the library's checksums.
+
+#![cfg(feature = "uniffi")]
+
+use std::collections::HashMap;
+
+use erc7730::uniffi_compat::TokenMetaInput;
+use erc7730::{DisplayEntry, DisplayModel, UniFfiTag};
+use uniffi::{Lift, Lower, RustBuffer, RustCallStatus, RustCallStatusCode};
+
+const KOTLIN_BINDINGS: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../bindings/kotlin/uniffi/erc7730/erc7730.kt"
+));
+
+const SWIFT_BINDINGS: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../bindings/swift/erc7730.swift"
+));
+
+extern "C" {
+    fn ffi_erc7730_uniffi_contract_version() -> u32;
+    fn uniffi_erc7730_checksum_func_erc7730_format_calldata() -> u16;
+    fn uniffi_erc7730_checksum_func_erc7730_format_typed_data() -> u16;
+    fn uniffi_erc7730_checksum_func_erc7730_set_logger() -> u16;
+    fn uniffi_erc7730_checksum_constructor_clearsigner_new() -> u16;
+    fn uniffi_erc7730_checksum_method_clearsigner_format_calldata() -> u16;
+    fn uniffi_erc7730_checksum_method_clearsigner_format_typed_data() -> u16;
+    fn uniffi_erc7730_fn_func_erc7730_format_calldata(
+        descriptor_json: RustBuffer,
+        chain_id: u64,
+        to: RustBuffer,
+        calldata_hex: RustBuffer,
+        value_hex: RustBuffer,
+        from_address: RustBuffer,
+        tokens: RustBuffer,
+        call_status: &mut RustCallStatus,
+    ) -> RustBuffer;
+    fn uniffi_erc7730_fn_func_erc7730_format_typed_data(
+        descriptor_json: RustBuffer,
+        typed_data_json: RustBuffer,
+        tokens: RustBuffer,
+        call_status: &mut RustCallStatus,
+    ) -> RustBuffer;
+}
+
+#[cfg(feature = "github-registry")]
+extern "C" {
+    fn uniffi_erc7730_checksum_func_erc7730_format() -> u16;
+    fn uniffi_erc7730_checksum_func_erc7730_format_typed() -> u16;
+}
+
+const USDT: &str = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+
+const TRANSFER_DESCRIPTOR: &str = r#"{
+    "context": {"contract": {"deployments": [{"chainId": 1, "address": "0xdac17f958d2ee523a2206206994597c13d831ec7"}]}},
+    "metadata": {"owner": "test", "contractName": "Tether USD"},
+    "display": {"formats": {"transfer(address,uint256)": {
+        "intent": "Send",
+        "fields": [
+            {"path": "@.0", "label": "To", "format": "addressName"},
+            {"path": "@.1", "label": "Amount", "format": "tokenAmount", "params": {"tokenPath": "@.to"}}
+        ]
+    }}}
+}"#;
+
+const TRANSFER_CALLDATA: &str = "0xa9059cbb000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000f4240";
+
+fn lower<T: Lower<UniFfiTag, FfiType = RustBuffer>>(value: T) -> RustBuffer {
+    T::lower(value)
+}
+
+/// Checksums a wrapper verifies at load time, keyed by symbol suffix
+/// (e.g. `func_erc7730_format_calldata`). Each check is a line starting with
+/// `prefix`, then the suffix, `() != `, and the value ending at `terminator`.
+fn checksums(bindings: &str, prefix: &str, terminator: &str) -> HashMap<String, u16> {
+    bindings
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix(prefix)?;
+            let (name, rest) = rest.split_once("() != ")?;
+            let value = rest.split_once(terminator)?.0.parse().ok()?;
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
+
+/// Assert a wrapper's contract version and checksums match the scaffolding.
+fn assert_matches_scaffolding(contract_version: u32, checksums: &HashMap<String, u16>) {
+    assert_eq!(contract_version, unsafe {
+        ffi_erc7730_uniffi_contract_version()
+    });
+    assert_eq!(checksums["func_erc7730_format_calldata"], unsafe {
+        uniffi_erc7730_checksum_func_erc7730_format_calldata()
+    });
+    assert_eq!(checksums["func_erc7730_format_typed_data"], unsafe {
+        uniffi_erc7730_checksum_func_erc7730_format_typed_data()
+    });
+    assert_eq!(checksums["func_erc7730_set_logger"], unsafe {
+        uniffi_erc7730_checksum_func_erc7730_set_logger()
+    });
+    assert_eq!(checksums["constructor_clearsigner_new"], unsafe {
+        uniffi_erc7730_checksum_constructor_clearsigner_new()
+    });
+    assert_eq!(checksums["method_clearsigner_format_calldata"], unsafe {
+        uniffi_erc7730_checksum_method_clearsigner_format_calldata()
+    });
+    assert_eq!(checksums["method_clearsigner_format_typed_data"], unsafe {
+        uniffi_erc7730_checksum_method_clearsigner_format_typed_data()
+    });
+    #[cfg(feature = "github-registry")]
+    {
+        assert_eq!(checksums["func_erc7730_format"], unsafe {
+            uniffi_erc7730_checksum_func_erc7730_format()
+        });
+        assert_eq!(checksums["func_erc7730_format_typed"], unsafe {
+            uniffi_erc7730_checksum_func_erc7730_format_typed()
+        });
+    }
+}
+
+/// Read a flat error buffer as `FfiConverterTypeFfiError.read` does:
+/// a big-endian `i32` variant index followed by the message string.
+fn read_flat_error(buf: RustBuffer) -> (i32, String) {
+    let bytes = buf.destroy_into_vec();
+    let variant = i32::from_be_bytes(bytes[..4].try_into().unwrap());
+    let len = i32::from_be_bytes(bytes[4..8].try_into().unwrap()) as usize;
+    let message = String::from_utf8(bytes[8..8 + len].to_vec()).unwrap();
+    (variant, message)
+}
+
+struct RustCallResult {
+    status: RustCallStatus,
+    buf: RustBuffer,
+}
+
+fn format_calldata(
+    descriptor: &str,
+    calldata: &str,
+    tokens: Vec<TokenMetaInput>,
+) -> RustCallResult {
+    let mut status = RustCallStatus::default();
+    let buf = unsafe {
+        uniffi_erc7730_fn_func_erc7730_format_calldata(
+            lower(descriptor.to_string()),
+            1,
+            lower(USDT.to_string()),
+            lower(calldata.to_string()),
+            lower(None::<String>),
+            lower(None::<String>),
+            lower(tokens),
+            &mut status,
+        )
+    };
+    RustCallResult { status, buf }
+}
+
+#[test]
+fn test_kotlin_bindings_match_scaffolding() {
+    let contract_version: u32 = KOTLIN_BINDINGS
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("val bindings_contract_version = "))
+        .and_then(|v| v.parse().ok())
+        .expect("contract version in Kotlin bindings");
+    let checksums = checksums(
+        KOTLIN_BINDINGS,
+        "if (lib.uniffi_erc7730_checksum_",
+        ".toShort()",
+    );
+    assert_matches_scaffolding(contract_version, &checksums);
+}
+
+#[test]
+fn test_swift_bindings_match_scaffolding() {
+    let contract_version: u32 = SWIFT_BINDINGS
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("let bindings_contract_version = "))
+        .and_then(|v| v.parse().ok())
+        .expect("contract version in Swift bindings");
+    let checksums = checksums(SWIFT_BINDINGS, "if (uniffi_erc7730_checksum_", ")");
+    assert_matches_scaffolding(contract_version, &checksums);
+}
+
+#[test]
+fn test_format_calldata_over_ffi() {
+    let tokens = vec![TokenMetaInput {
+        chain_id: 1,
+        address: USDT.to_string(),
+        symbol: "USDT".to_string(),
+        decimals: 6,
+        name: "Tether USD".to_string(),
+    }];
+    let result = format_calldata(TRANSFER_DESCRIPTOR, TRANSFER_CALLDATA, tokens);
+    assert_eq!(result.status.code, RustCallStatusCode::Success);
+
+    let model = <DisplayModel as Lift<UniFfiTag>>::try_lift(result.buf).unwrap();
+    assert_eq!(model.intent, "Send");
+    let values: Vec<&str> = model
+        .entries
+        .iter()
+        .filter_map(|e| match e {
+            DisplayEntry::Item(item) => Some(item.value.as_str()),
+            DisplayEntry::Group { .. } => None,
+        })
+        .collect();
+    assert_eq!(
+        values,
+        vec!["0x0000000000000000000000000000000000000001", "1 USDT"]
+    );
+}
+
+#[test]
+fn test_errors_cross_as_flat_messages() {
+    let result = format_calldata("{", TRANSFER_CALLDATA, Vec::new());
+    assert_eq!(result.status.code, RustCallStatusCode::Error);
+    let (variant, message) =
+        read_flat_error(std::mem::ManuallyDrop::into_inner(result.status.error_buf));
+    // FfiException.InvalidDescriptorJson
+    assert_eq!(variant, 1);
+    assert!(message.starts_with("invalid descriptor JSON:"), "{message}");
+
+    let result = format_calldata(TRANSFER_DESCRIPTOR, "0xzz", Vec::new());
+    assert_eq!(result.status.code, RustCallStatusCode::Error);
+    let (variant, message) =
+        read_flat_error(std::mem::ManuallyDrop::into_inner(result.status.error_buf));
+    // FfiException.InvalidCalldataHex
+    assert_eq!(variant, 3);
+    assert!(message.starts_with("invalid calldata hex:"), "{message}");
+}
+
+#[test]
+fn test_format_typed_data_over_ffi() {
+    let descriptor = r#"{
+        "context": {"eip712": {"deployments": [{"chainId": 1, "address": "0x0000000000000000000000000000000000000001"}]}},
+        "metadata": {"owner": "test"},
+        "display": {"formats": {"Mail": {
+            "intent": "Sign mail",
+            "fields": [{"path": "contents", "label": "Contents"}]
+        }}}
+    }"#;
+    let typed_data = r#"{
+        "types": {"Mail": [{"name": "contents", "type": "string"}]},
+        "primaryType": "Mail",
+        "domain": {"chainId": 1, "verifyingContract": "0x0000000000000000000000000000000000000001"},
+        "message": {"contents": "hello"}
+    }"#;
+
+    let mut status = RustCallStatus::default();
+    let buf = unsafe {
+        uniffi_erc7730_fn_func_erc7730_format_typed_data(
+            lower(descriptor.to_string()),
+            lower(typed_data.to_string()),
+            lower(Vec::<TokenMetaInput>::new()),
+            &mut status,
+        )
+    };
+    assert_eq!(status.code, RustCallStatusCode::Success);
+    let model = <DisplayModel as Lift<UniFfiTag>>::try_lift(buf).unwrap();
+    assert_eq!(model.intent, "Sign mail");
+    assert!(matches!(
+        &model.entries[0],
+        DisplayEntry::Item(item) if item.value == "hello"
+    ));
+}
@@ -0,0 +1,187 @@
+//! Replays the committed fuzz corpus (`fuzz/corpus/<target>/`) with the checks the
+//! `cargo fuzz` targets make, so regressions surface without nightly or libFuzzer.
+
+#![cfg(feature = "std")]
+
+use std::path::Path;
+
+use erc7730::decoder::{
+    decode_calldata, decode_calldata_with_mode, encode_arguments, parse_signature, DecodeMode,
+};
+use erc7730::error::DecodeError;
+use erc7730::Descriptor;
+
+fn corpus(target: &str) -> Vec<(String, Vec<u8>)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fuzz/corpus")
+        .join(target);
+    let mut inputs: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("{}: {e}", dir.display()))
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            (name, std::fs::read(&path).unwrap())
+        })
+        .collect();
+    inputs.sort();
+    assert!(!inputs.is_empty(), "empty corpus {}", dir.display());
+    inputs
+}
+
+#[test]
+fn parse_signature_corpus() {
+    for (name, data) in corpus("parse_signature") {
+        let Ok(sig) = std::str::from_utf8(&data) else {
+            continue;
+        };
+        let Ok(parsed) = parse_signature(sig) else {
+            continue;
+        };
+        let reparsed = parse_signature(&parsed.canonical).unwrap();
+        assert_eq!(reparsed.params, parsed.params, "{name}");
+        assert_eq!(reparsed.selector, parsed.selector, "{name}");
+    }
+}
+
+/// How a `decode_calldata` corpus entry decodes.
+enum Outcome {
+    /// Cleanly in both modes, re-encoding to the same bytes.
+    Canonical,
+    /// Leniently with warnings; strict mode fails with the first one, which the
+    /// predicate accepts.
+    NonCanonical(fn(&DecodeError) -> bool),
+    /// Not at all; the predicates accept the lenient and strict errors.
+    Rejected {
+        lenient: fn(&DecodeError) -> bool,
+        strict: fn(&DecodeError) -> bool,
+    },
+}
+
+/// Expected outcome of each entry, so one that starts decoding differently fails
+/// instead of being skipped.
+fn expected_outcome(name: &str) -> Outcome {
+    use DecodeError::*;
+    match name {
+        "nested-dynamic" | "solidity-docs" | "transfer" => Outcome::Canonical,
+        "aliased-tails" => Outcome::NonCanonical(|e| matches!(e, NonCanonicalOffset { .. })),
+        "dirty-address" => Outcome::NonCanonical(|e| matches!(e, DirtyAddress { .. })),
+        "trailing-bytes" => Outcome::NonCanonical(|e| matches!(e, TrailingBytes { len: 1 })),
+        "truncated-padding" => Outcome::NonCanonical(|e| matches!(e, CalldataTooShort { .. })),
+        "deep-arrays" => Outcome::Rejected {
+            lenient: |e| {
+                matches!(
+                    e,
+                    LimitExceeded {
+                        limit: "nesting depth",
+                        ..
+                    }
+                )
+            },
+            strict: |e| {
+                matches!(
+                    e,
+                    LimitExceeded {
+                        limit: "nesting depth",
+                        ..
+                    }
+                )
+            },
+        },
+        "zero-sized-huge-length" => Outcome::Rejected {
+            lenient: |e| {
+                matches!(
+                    e,
+                    LimitExceeded {
+                        limit: "element count",
+                        ..
+                    }
+                )
+            },
+            strict: |e| {
+                matches!(
+                    e,
+                    LimitExceeded {
+                        limit: "element count",
+                        ..
+                    }
+                )
+            },
+        },
+        "length-near-max" => Outcome::Rejected {
+            lenient: |e| matches!(e, CalldataTooShort { .. }),
+            strict: |e| matches!(e, CalldataTooShort { .. }),
+        },
+        // Strict mode notices the misplaced offset before following it
+        "offset-near-max" => Outcome::Rejected {
+            lenient: |e| matches!(e, CalldataTooShort { .. }),
+            strict: |e| matches!(e, NonCanonicalOffset { .. }),
+        },
+        "overflowing-offset" => Outcome::Rejected {
+            lenient: |e| matches!(e, InvalidEncoding(_)),
+            strict: |e| matches!(e, InvalidEncoding(_)),
+        },
+        _ => panic!("no expected outcome for decode_calldata corpus entry {name}"),
+    }
+}
+
+#[test]
+fn decode_calldata_corpus() {
+    for (name, data) in corpus("decode_calldata") {
+        let newline = data.iter().position(|b| *b == b'\n').unwrap();
+        let sig = parse_signature(std::str::from_utf8(&data[..newline]).unwrap()).unwrap();
+        let args = &data[newline + 1..];
+        let mut calldata = sig.selector.to_vec();
+        calldata.extend_from_slice(args);
+
+        let lenient = decode_calldata(&sig, &calldata);
+        let strict = decode_calldata_with_mode(&sig, &calldata, DecodeMode::Strict);
+        match expected_outcome(&name) {
+            Outcome::Canonical => {
+                let strict = strict.unwrap_or_else(|e| panic!("{name}: {e}"));
+                let lenient = lenient.unwrap_or_else(|e| panic!("{name}: {e}"));
+                assert!(strict.warnings.is_empty(), "{name}");
+                assert!(lenient.warnings.is_empty(), "{name}");
+                let values: Vec<_> = strict.args.into_iter().map(|arg| arg.value).collect();
+                let lenient_values: Vec<_> =
+                    lenient.args.into_iter().map(|arg| arg.value).collect();
+                assert_eq!(values, lenient_values, "{name}");
+                let encoded = encode_arguments(&sig.params, &values)
+                    .unwrap_or_else(|e| panic!("{name}: {e}"));
+                assert_eq!(encoded, args, "{name}");
+            }
+            Outcome::NonCanonical(expected) => {
+                let lenient = lenient.unwrap_or_else(|e| panic!("{name}: {e}"));
+                let first = lenient
+                    .warnings
+                    .first()
+                    .unwrap_or_else(|| panic!("{name}: no warnings"));
+                assert!(expected(first), "{name}: {first:?}");
+                assert_eq!(strict.err().as_ref(), Some(first), "{name}");
+            }
+            Outcome::Rejected {
+                lenient: lenient_error,
+                strict: strict_error,
+            } => {
+                let lenient = lenient.err().unwrap_or_else(|| panic!("{name} decoded"));
+                assert!(lenient_error(&lenient), "{name}: {lenient:?}");
+                let strict = strict.err().unwrap_or_else(|| panic!("{name} decoded"));
+                assert!(strict_error(&strict), "{name}: {strict:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn descriptor_from_json_corpus() {
+    for (name, data) in corpus("descriptor_from_json") {
+        let Ok(json) = std::str::from_utf8(&data) else {
+            continue;
+        };
+        let Ok(descriptor) = Descriptor::from_json(json) else {
+            continue;
+        };
+        let _ = descriptor.validate();
+        let json = descriptor.to_json().unwrap();
+        Descriptor::from_json(&json).unwrap_or_else(|e| panic!("{name}: {e}"));
+    }
+}
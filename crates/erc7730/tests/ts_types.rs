@@ -0,0 +1,160 @@
+//! Checks the hand-written TypeScript types of the `wasm` bindings against the
+//! serde shape of [`DisplayModel`], which is what JS callers actually receive.
+
+use std::collections::BTreeSet;
+
+use erc7730::engine::GroupIteration;
+use erc7730::{
+    DisplayEntry, DisplayItem, DisplayModel, FieldWarning, FieldWarningKind, NameSource,
+    RiskFinding, RiskKind, WarningSeverity,
+};
+
+/// TypeScript declarations the `wasm` bindings publish for display models.
+const TS_TYPES: &str = include_str!("../src/wasm_types.d.ts");
+
+/// Object keys and non-empty strings (enum tags; free text is left empty) in `value`.
+fn names(value: &serde_json::Value, keys: &mut BTreeSet<String>, tags: &mut BTreeSet<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                keys.insert(key.clone());
+                names(value, keys, tags);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            values.iter().for_each(|value| names(value, keys, tags));
+        }
+        serde_json::Value::String(tag) if !tag.is_empty() => {
+            tags.insert(tag.clone());
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn test_ts_types_match_display_model_shape() {
+    // The matches stop compiling when a variant is added, so the model below
+    // keeps covering every variant
+    let sources = [
+        NameSource::Descriptor,
+        NameSource::Contact,
+        NameSource::Trusted,
+        NameSource::Ens,
+    ];
+    for source in sources {
+        match source {
+            NameSource::Descriptor | NameSource::Contact | NameSource::Trusted => {}
+            NameSource::Ens => {}
+        }
+    }
+    let severities = [
+        WarningSeverity::Info,
+        WarningSeverity::Warning,
+        WarningSeverity::Danger,
+    ];
+    for severity in severities {
+        match severity {
+            WarningSeverity::Info | WarningSeverity::Warning | WarningSeverity::Danger => {}
+        }
+    }
+    let warning_kinds = [
+        FieldWarningKind::AddressTypeMismatch,
+        FieldWarningKind::LookAlikeAddress {
+            known_address: String::new(),
+            known_label: String::new(),
+        },
+    ];
+    for kind in &warning_kinds {
+        match kind {
+            FieldWarningKind::AddressTypeMismatch | FieldWarningKind::LookAlikeAddress { .. } => {}
+        }
+    }
+    let iterations = [GroupIteration::Sequential, GroupIteration::Bundled];
+    for iteration in &iterations {
+        match iteration {
+            GroupIteration::Sequential | GroupIteration::Bundled => {}
+        }
+    }
+    let risk_kinds = [
+        RiskKind::UnlimitedAllowance,
+        RiskKind::LargeAllowance,
+        RiskKind::ApprovalForAll,
+        RiskKind::SpenderIsEoa,
+        RiskKind::UnknownSpender,
+        RiskKind::LongLivedPermit,
+    ];
+    for kind in risk_kinds {
+        match kind {
+            RiskKind::UnlimitedAllowance
+            | RiskKind::LargeAllowance
+            | RiskKind::ApprovalForAll
+            | RiskKind::SpenderIsEoa
+            | RiskKind::UnknownSpender
+            | RiskKind::LongLivedPermit => {}
+        }
+    }
+
+    let item = |name_source| DisplayItem {
+        label: String::new(),
+        value: String::new(),
+        name_source,
+        warnings: warning_kinds
+            .iter()
+            .flat_map(|kind| {
+                severities.map(|severity| FieldWarning {
+                    kind: kind.clone(),
+                    severity,
+                    message: String::new(),
+                })
+            })
+            .collect(),
+    };
+    let mut entries: Vec<DisplayEntry> = sources
+        .map(|source| DisplayEntry::Item(item(Some(source))))
+        .into_iter()
+        .collect();
+    for entry in &entries {
+        match entry {
+            DisplayEntry::Item(_) | DisplayEntry::Group { .. } => {}
+        }
+    }
+    entries.extend(iterations.map(|iteration| DisplayEntry::Group {
+        label: String::new(),
+        iteration,
+        items: vec![item(None)],
+    }));
+    let model = DisplayModel {
+        intent: String::new(),
+        interpolated_intent: None,
+        entries,
+        warnings: Vec::new(),
+        risks: risk_kinds
+            .map(|kind| RiskFinding {
+                kind,
+                severity: WarningSeverity::Warning,
+                message: String::new(),
+            })
+            .into(),
+    };
+
+    let (mut keys, mut tags) = (BTreeSet::new(), BTreeSet::new());
+    names(&serde_json::to_value(&model).unwrap(), &mut keys, &mut tags);
+    let missing_keys: Vec<_> = keys
+        .iter()
+        .filter(|key| {
+            !TS_TYPES.contains(&format!(" {key}:")) && !TS_TYPES.contains(&format!(" {key}?:"))
+        })
+        .collect();
+    assert!(
+        missing_keys.is_empty(),
+        "keys missing from TS_TYPES: {missing_keys:?}"
+    );
+    let missing_tags: Vec<_> = tags
+        .iter()
+        .filter(|tag| !TS_TYPES.contains(&format!("\"{tag}\"")))
+        .collect();
+    assert!(
+        missing_tags.is_empty(),
+        "tags missing from TS_TYPES: {missing_tags:?}"
+    );
+}
@@ -0,0 +1,107 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+PROFILE="release"
+PACKAGE_NAME="erc7730"
+UNIFFI_CONFIG="$ROOT_DIR/crates/erc7730/uniffi.toml"
+STAGING_DIR="$ROOT_DIR/target/uniffi-kotlin-staging"
+ANDROID_OUT_DIR="$ROOT_DIR/target/android"
+ANDROID_PLATFORM="${ANDROID_PLATFORM:-24}"
+
+# Rust target -> Android ABI directory under jniLibs
+ANDROID_TARGETS=(
+    "aarch64-linux-android:arm64-v8a"
+    "armv7-linux-androideabi:armeabi-v7a"
+    "x86_64-linux-android:x86_64"
+)
+
+case "$(uname -s)" in
+    Darwin)
+        HOST_LIB_EXT="dylib"
+        ;;
+    Linux)
+        HOST_LIB_EXT="so"
+        ;;
+    *)
+        echo "Unsupported host OS: $(uname -s)" >&2
+        exit 1
+        ;;
+esac
+
+if ! command -v cargo-ndk >/dev/null 2>&1; then
+    echo "cargo-ndk is required: cargo install cargo-ndk" >&2
+    exit 1
+fi
+
+if [[ -z "${ANDROID_NDK_HOME:-}" ]]; then
+    echo "ANDROID_NDK_HOME must point at an Android NDK installation." >&2
+    exit 1
+fi
+
+ensure_target() {
+    local target="$1"
+    if ! rustup target list --installed | grep -q "^${target}$"; then
+        echo "Installing missing Rust target: $target"
+        rustup target add "$target"
+    fi
+}
+
+build_rust_libraries() {
+    echo "Building Rust shared libraries for Android targets..."
+    rm -rf "$ANDROID_OUT_DIR/jniLibs"
+
+    local args=()
+    for entry in "${ANDROID_TARGETS[@]}"; do
+        local target="${entry%%:*}"
+        ensure_target "$target"
+        args+=(-t "$target")
+    done
+
+    cargo ndk "${args[@]}" --platform "$ANDROID_PLATFORM" -o "$ANDROID_OUT_DIR/jniLibs" \
+        build --lib --release --features uniffi,github-registry -p erc7730
+
+    cargo build --release --features uniffi,github-registry -p erc7730
+}
+
+generate_kotlin_bindings() {
+    echo "Generating Kotlin UniFFI bindings..."
+    rm -rf "$STAGING_DIR"
+    mkdir -p "$STAGING_DIR"
+
+    local host_library="$ROOT_DIR/target/$PROFILE/lib${PACKAGE_NAME}.${HOST_LIB_EXT}"
+    if [[ ! -f "$host_library" ]]; then
+        echo "Missing host library: $host_library" >&2
+        exit 1
+    fi
+
+    cargo run -p erc7730 --features uniffi,github-registry --bin uniffi-bindgen -- generate \
+        --library "$host_library" \
+        --language kotlin \
+        --config "$UNIFFI_CONFIG" \
+        --out-dir "$STAGING_DIR"
+}
+
+verify_jni_libs() {
+    for entry in "${ANDROID_TARGETS[@]}"; do
+        local abi="${entry##*:}"
+        if [[ ! -f "$ANDROID_OUT_DIR/jniLibs/$abi/lib${PACKAGE_NAME}.so" ]]; then
+            echo "Missing Android library for $abi" >&2
+            exit 1
+        fi
+    done
+}
+
+copy_kotlin_wrapper() {
+    echo "Refreshing committed Kotlin wrapper..."
+    mkdir -p "$ROOT_DIR/bindings/kotlin/uniffi/${PACKAGE_NAME}"
+    cp "$STAGING_DIR/uniffi/${PACKAGE_NAME}/${PACKAGE_NAME}.kt" \
+        "$ROOT_DIR/bindings/kotlin/uniffi/${PACKAGE_NAME}/${PACKAGE_NAME}.kt"
+}
+
+build_rust_libraries
+verify_jni_libs
+generate_kotlin_bindings
+copy_kotlin_wrapper
+
+echo "Done. jniLibs: $ANDROID_OUT_DIR/jniLibs, Kotlin wrapper: bindings/kotlin"
@@ -0,0 +1,56 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+PACKAGE_NAME="erc7730"
+TARGET="wasm32-unknown-unknown"
+WASM_OUT_DIR="$ROOT_DIR/target/wasm"
+# wasm-bindgen targets to generate: web (ES modules for browsers/extensions), nodejs
+JS_TARGETS=(${WASM_JS_TARGETS:-web nodejs})
+
+if ! command -v wasm-bindgen >/dev/null 2>&1; then
+    echo "wasm-bindgen is required: cargo install wasm-bindgen-cli (same version as Cargo.lock)" >&2
+    exit 1
+fi
+
+ensure_target() {
+    if ! rustup target list --installed | grep -q "^${TARGET}$"; then
+        echo "Installing missing Rust target: $TARGET"
+        rustup target add "$TARGET"
+    fi
+}
+
+build_wasm() {
+    echo "Building Rust wasm module..."
+    cargo build --lib --release --target "$TARGET" --features wasm -p erc7730
+}
+
+generate_js_bindings() {
+    local wasm_file="$ROOT_DIR/target/$TARGET/release/${PACKAGE_NAME}.wasm"
+    if [[ ! -f "$wasm_file" ]]; then
+        echo "Missing wasm module: $wasm_file" >&2
+        exit 1
+    fi
+
+    for js_target in "${JS_TARGETS[@]}"; do
+        echo "Generating JS bindings ($js_target)..."
+        rm -rf "$WASM_OUT_DIR/$js_target"
+        wasm-bindgen "$wasm_file" --target "$js_target" --out-dir "$WASM_OUT_DIR/$js_target"
+    done
+}
+
+smoke_test() {
+    if [[ ! -d "$WASM_OUT_DIR/nodejs" ]] || ! command -v node >/dev/null 2>&1; then
+        echo "Skipping Node smoke test (needs node and the nodejs target)."
+        return
+    fi
+    echo "Running Node smoke test..."
+    node "$ROOT_DIR/scripts/wasm-smoke.cjs" "$WASM_OUT_DIR/nodejs/${PACKAGE_NAME}.js"
+}
+
+ensure_target
+build_wasm
+generate_js_bindings
+smoke_test
+
+echo "Done. JS packages: $WASM_OUT_DIR/{$(IFS=,; echo "${JS_TARGETS[*]}")}"
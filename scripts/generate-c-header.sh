@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+CRATE_DIR="$ROOT_DIR/crates/erc7730"
+
+if ! command -v cbindgen >/dev/null 2>&1; then
+    echo "cbindgen is required: cargo install cbindgen" >&2
+    exit 1
+fi
+
+echo "Generating C header..."
+cbindgen \
+    --config "$CRATE_DIR/cbindgen.toml" \
+    --output "$CRATE_DIR/include/erc7730.h" \
+    "$CRATE_DIR/src/capi.rs"
+
+echo "Done. Header: $CRATE_DIR/include/erc7730.h"
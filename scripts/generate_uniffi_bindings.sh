@@ -5,6 +5,7 @@ ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
 TARGET_DIR="$ROOT_DIR/target/debug"
 KOTLIN_OUT="$ROOT_DIR/bindings/kotlin"
 SWIFT_OUT="$ROOT_DIR/bindings/swift"
+UNIFFI_CONFIG="$ROOT_DIR/crates/erc7730/uniffi.toml"
 
 case "$(uname -s)" in
     Darwin)
@@ -35,9 +36,9 @@ if [[ ! -f "$LIB_PATH" ]]; then
 fi
 
 echo "Generating Kotlin bindings to $KOTLIN_OUT"
-cargo run -p erc7730 --features uniffi,github-registry --bin uniffi-bindgen -- generate --library "$LIB_PATH" --language kotlin --out-dir "$KOTLIN_OUT"
+cargo run -p erc7730 --features uniffi,github-registry --bin uniffi-bindgen -- generate --library "$LIB_PATH" --language kotlin --config "$UNIFFI_CONFIG" --out-dir "$KOTLIN_OUT"
 
 echo "Generating Swift bindings to $SWIFT_OUT"
-cargo run -p erc7730 --features uniffi,github-registry --bin uniffi-bindgen -- generate --library "$LIB_PATH" --language swift --out-dir "$SWIFT_OUT"
+cargo run -p erc7730 --features uniffi,github-registry --bin uniffi-bindgen -- generate --library "$LIB_PATH" --language swift --config "$UNIFFI_CONFIG" --out-dir "$SWIFT_OUT"
 
 echo "Done. Bindings generated in $ROOT_DIR/bindings"
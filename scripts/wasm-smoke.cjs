@@ -0,0 +1,76 @@
+// Smoke test of the nodejs wasm bindings: node scripts/wasm-smoke.cjs <path to erc7730.js>
+const assert = require("node:assert/strict");
+
+const erc7730 = require(process.argv[2]);
+
+const USDT = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+const RECIPIENT = "0x00000000000000000000000000000000000000b0";
+
+const descriptor = JSON.stringify({
+    context: { contract: { deployments: [{ chainId: 1, address: USDT }] } },
+    metadata: { owner: "test", contractName: "Tether USD" },
+    display: {
+        formats: {
+            "transfer(address,uint256)": {
+                intent: "Send",
+                fields: [
+                    { path: "@.0", label: "To", format: "address" },
+                    { path: "@.1", label: "Amount", format: "tokenAmount", params: { tokenPath: "@.to" } },
+                ],
+            },
+        },
+    },
+});
+const calldata =
+    "0xa9059cbb" + RECIPIENT.slice(2).padStart(64, "0") + (2500000).toString(16).padStart(64, "0");
+
+async function main() {
+    // Free function: plain object out, token list in
+    const model = erc7730.formatCalldata(descriptor, 1, USDT, calldata, undefined, undefined, [
+        { chainId: 1, address: USDT, symbol: "USDT", decimals: 6, name: "Tether USD" },
+    ]);
+    assert.equal(model.intent, "Send");
+    assert.deepEqual(
+        model.entries.map((e) => [e.type, e.label, e.value]),
+        [
+            ["item", "To", "0x00000000000000000000000000000000000000B0"],
+            ["item", "Amount", "2.5 USDT"],
+        ],
+    );
+
+    assert.deepEqual(erc7730.validateDescriptor(descriptor), []);
+    assert.match(erc7730.validateDescriptor("{")[0], /^invalid descriptor JSON/);
+    assert.throws(() => erc7730.formatCalldata(descriptor, 1, USDT, "0xzz"), /invalid calldata hex/);
+
+    // JS-implemented sources
+    const signer = new erc7730.ClearSigner({
+        useWellKnownTokens: false,
+        tokenSource: {
+            token: (chainId, address) =>
+                address === USDT ? { symbol: "APP", decimals: 6, name: "App token" } : null,
+        },
+        descriptorSource: {
+            calldataDescriptor: async (chainId, address) => (address === USDT ? descriptor : null),
+            typedDescriptor: async () => {
+                throw new Error("offline");
+            },
+        },
+    });
+    const viaSigner = await signer.formatCalldata(1, USDT, calldata);
+    assert.equal(viaSigner.entries[1].value, "2.5 APP");
+
+    const typed = JSON.stringify({
+        types: { Mail: [{ name: "contents", type: "string" }] },
+        primaryType: "Mail",
+        domain: { chainId: 1, verifyingContract: "0x0000000000000000000000000000000000000001" },
+        message: { contents: "hello" },
+    });
+    await assert.rejects(signer.formatTypedData(typed), /descriptor source failed: offline/);
+
+    console.log("wasm smoke test OK");
+}
+
+main().catch((e) => {
+    console.error(e);
+    process.exit(1);
+});